@@ -0,0 +1,75 @@
+use std::path::PathBuf;
+use bevy::prelude::Resource;
+
+const USAGE: &str = "\
+Usage: fusee_controle [OPTIONS]
+
+Options:
+  --planet <SPEC>        earth | moon | mars | random | liste (earth,mars)
+                         | custom:<nom>:<g m/s²>[:<densité>:<hauteur d'échelle>]
+  --terrain <SPEC>       flat | procedural[:graine] | <fichier de profil 'x hauteur'>
+  --pads <LISTE>         Plateformes x:largeur[:ship] séparées par des virgules (ex. -300:80,0:80:ship)
+  --sea-state <SPEC>     Houle des barges : calm | moderate | rough
+                         | custom:<dérive px>:<pilonnement px>:<roulis °>
+  --target <CIBLE>       Index de la plateforme visée, ou random (tirée à chaque épisode)
+  --controller <NOM>     Pilote au démarrage : rl (agent) | pid | lqr | mpc | hoverslam (touche C)
+  --config <FICHIER>     Fichier de configuration (sections [landing], ...)
+  --checkpoint <FICHIER> Reprend l'entraînement depuis un checkpoint
+  --eval <GRAINES>       Évalue le pilote sur une liste de graines (ex. 0..100 ou 3,7,11), puis quitte
+  --suite <SCÉNARIOS>    Évalue le pilote sur la suite de référence : all | liste (nominal,moon)
+  -h, --help             Affiche cette aide";
+
+/// Options de la ligne de commande, accessibles aux plugins comme ressource.
+#[derive(Resource, Clone, Debug, Default)]
+pub struct CliOptions {
+    pub planet: Option<String>,
+    pub terrain: Option<String>,
+    pub pads: Option<String>,
+    pub target: Option<String>,
+    pub sea_state: Option<String>,
+    pub controller: Option<String>,
+    pub config: Option<PathBuf>,
+    pub checkpoint: Option<PathBuf>,
+    pub eval: Option<String>,
+    pub suite: Option<String>,
+}
+
+impl CliOptions {
+    pub fn parse() -> Self {
+        match Self::from_args(std::env::args().skip(1)) {
+            Ok(options) => options,
+            Err(message) => {
+                eprintln!("❌ {}\n\n{}", message, USAGE);
+                std::process::exit(2);
+            }
+        }
+    }
+
+    pub fn from_args(args: impl IntoIterator<Item = String>) -> Result<Self, String> {
+        let mut options = Self::default();
+        let mut args = args.into_iter();
+
+        while let Some(arg) = args.next() {
+            let mut value = || args.next().ok_or_else(|| format!("valeur manquante pour {}", arg));
+            match arg.as_str() {
+                "--planet" => options.planet = Some(value()?),
+                "--terrain" => options.terrain = Some(value()?),
+                "--pads" => options.pads = Some(value()?),
+                "--target" => options.target = Some(value()?),
+                "--sea-state" => options.sea_state = Some(value()?),
+                "--controller" => options.controller = Some(value()?),
+                "--config" => options.config = Some(PathBuf::from(value()?)),
+                "--checkpoint" => options.checkpoint = Some(PathBuf::from(value()?)),
+                "--eval" => options.eval = Some(value()?),
+                "--suite" => options.suite = Some(value()?),
+                "-h" | "--help" => {
+                    println!("{}", USAGE);
+                    std::process::exit(0);
+                }
+                _ => return Err(format!("argument inconnu: {}", arg)),
+            }
+        }
+
+        Ok(options)
+    }
+}
@@ -0,0 +1,465 @@
+use bevy::math::DVec2;
+use bevy::prelude::*;
+use crate::control::linalg::Matrix;
+use crate::control::qp::{AdmmSettings, QuadraticProgram};
+use crate::game::rocket::LEG_REACH;
+use crate::game::environment::ActivePad;
+use crate::game::estimator::{run_estimator, StateEstimator};
+use crate::game::planet::CelestialBody;
+use crate::game::rocket::{restart_system, Rocket, RocketMainBody, RocketStats};
+use crate::game::sensors::Sensors;
+use crate::game::simulation::FUEL_BURN_RATE;
+use crate::game::GameState;
+use crate::rl_agent::policy_view;
+use crate::settings::{parse_value, SettingsFile};
+
+// Points de la grille de durées explorée avant l'affinage
+const DURATION_GRID: usize = 6;
+// Itérations d'affinage de la durée (section dorée ou dichotomie)
+const REFINE_ITERATIONS: usize = 3;
+// Itérations ADMM par durée : une durée qui ne converge pas est traitée comme infaisable
+const SOLVER_ITERATIONS: usize = 1500;
+// Faces du polygone approchant la borne de poussée dans le cône d'inclinaison
+const THRUST_FACES: usize = 5;
+// Hauteur au-dessus de la plateforme en dessous de laquelle on ne replanifie plus (px)
+const FINAL_APPROACH_HEIGHT: f32 = 5.0;
+
+/// Critère optimisé par le planificateur.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum GuidanceObjective {
+    /// Durée de vol minimisant le carburant.
+    #[default]
+    Fuel,
+    /// Plus courte durée de vol faisable.
+    Time,
+}
+
+impl GuidanceObjective {
+    pub fn from_spec(spec: &str) -> Result<Self, String> {
+        match spec.to_lowercase().as_str() {
+            "fuel" => Ok(GuidanceObjective::Fuel),
+            "time" => Ok(GuidanceObjective::Time),
+            _ => Err(format!("objectif de guidage inconnu: '{}' (fuel, time)", spec)),
+        }
+    }
+}
+
+/// Réglages du guidage de descente propulsée, section `[guidance]`.
+#[derive(Resource, Clone, Copy, Debug, PartialEq)]
+pub struct GuidanceSettings {
+    pub enabled: bool,
+    pub objective: GuidanceObjective,
+    /// Nombre d'intervalles de poussée constante de la trajectoire.
+    pub nodes: usize,
+    /// Durées de vol explorées (s).
+    pub min_duration: f32,
+    pub max_duration: f32,
+    /// Fraction de la poussée maximale disponible pour le plan, le reste sert à la poursuite.
+    pub max_throttle: f32,
+    /// Inclinaison maximale de la poussée par rapport à la verticale (degrés).
+    pub max_tilt: f32,
+    /// Angle minimal au-dessus de l'horizontale vu depuis la cible (degrés), 0 pour désactiver.
+    pub glide_slope: f32,
+    /// Vitesse de descente au contact (px/s).
+    pub touchdown_speed: f32,
+    /// Période de replanification (s).
+    pub replan_interval: f32,
+    /// Affiche la trajectoire planifiée.
+    pub show: bool,
+}
+
+impl Default for GuidanceSettings {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            objective: GuidanceObjective::Fuel,
+            nodes: 15,
+            min_duration: 1.0,
+            max_duration: 20.0,
+            max_throttle: 0.8,
+            max_tilt: 25.0,
+            glide_slope: 10.0,
+            touchdown_speed: 1.5,
+            replan_interval: 1.0,
+            show: true,
+        }
+    }
+}
+
+impl GuidanceSettings {
+    pub fn from_settings(settings: &SettingsFile) -> Result<Self, String> {
+        let mut guidance = Self::default();
+        settings.apply_section("guidance", |key, value| {
+            match key {
+                "enabled" => guidance.enabled = parse_value(key, value)?,
+                "objective" => guidance.objective = GuidanceObjective::from_spec(value)?,
+                "nodes" => guidance.nodes = parse_value(key, value)?,
+                "show" => guidance.show = parse_value(key, value)?,
+                _ => {
+                    let field = match key {
+                        "min_duration" => &mut guidance.min_duration,
+                        "max_duration" => &mut guidance.max_duration,
+                        "max_throttle" => &mut guidance.max_throttle,
+                        "max_tilt" => &mut guidance.max_tilt,
+                        "glide_slope" => &mut guidance.glide_slope,
+                        "touchdown_speed" => &mut guidance.touchdown_speed,
+                        "replan_interval" => &mut guidance.replan_interval,
+                        _ => return Ok(false),
+                    };
+                    *field = parse_value(key, value)?;
+                }
+            }
+            Ok(true)
+        })?;
+        if guidance.nodes < 2 || guidance.min_duration <= 0.0 || guidance.max_duration < guidance.min_duration {
+            return Err("[guidance]: nodes >= 2 et 0 < min_duration <= max_duration".to_string());
+        }
+        Ok(guidance)
+    }
+}
+
+/// État de départ du planificateur, dans le repère de la plateforme.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct DescentProblem {
+    pub position: Vec2,
+    pub velocity: Vec2,
+    /// Carburant disponible, dans l'unité de `Rocket::fuel`.
+    pub fuel: f32,
+    /// Gravité (px/s², négative).
+    pub gravity: f32,
+    /// Accélération à pleine poussée (px/s²).
+    pub engine_power: f32,
+}
+
+impl DescentProblem {
+    pub fn from_stats(stats: &RocketStats, gravity: f32, rocket: &Rocket) -> Self {
+        Self {
+            position: stats.pad_offset,
+            velocity: Vec2::new(stats.horizontal_speed, stats.vertical_speed) - stats.pad_velocity,
+            fuel: stats.fuel_percentage * rocket.max_fuel,
+            gravity,
+            engine_power: rocket.engine_power,
+        }
+    }
+}
+
+/// Point de la trajectoire de référence, relatif à la plateforme.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct TrajectoryPoint {
+    pub time: f32,
+    pub position: Vec2,
+    pub velocity: Vec2,
+    /// Accélération de poussée demandée (px/s²).
+    pub thrust: Vec2,
+}
+
+/// Trajectoire à poussée constante par morceaux, du point de départ au contact.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Trajectory {
+    /// Nœuds de début de chaque intervalle, puis le point de contact.
+    pub points: Vec<TrajectoryPoint>,
+    pub gravity: f32,
+    pub duration: f32,
+    /// Carburant prévu (% du réservoir).
+    pub fuel: f32,
+}
+
+impl Trajectory {
+    /// Référence à l'instant `time` depuis le début du plan ; au-delà, le point de contact.
+    pub fn sample(&self, time: f32) -> TrajectoryPoint {
+        let Some(last) = self.points.last() else {
+            return TrajectoryPoint::default();
+        };
+        if time >= self.duration {
+            return TrajectoryPoint { time, ..*last };
+        }
+        let index = self.points.partition_point(|point| point.time <= time).saturating_sub(1);
+        let node = &self.points[index];
+        let tau = time - node.time;
+        let acceleration = node.thrust + Vec2::new(0.0, self.gravity);
+        TrajectoryPoint {
+            time,
+            position: node.position + node.velocity * tau + 0.5 * acceleration * tau * tau,
+            velocity: node.velocity + acceleration * tau,
+            thrust: node.thrust,
+        }
+    }
+}
+
+/// Planifie une descente propulsée vers le centre de la plateforme, à la manière de G-FOLD.
+///
+/// Pour une durée donnée, la poussée constante par intervalle est la solution d'un programme
+/// quadratique : dynamique de point matériel sous gravité (sans traînée), état final imposé,
+/// poussée bornée dans un cône d'inclinaison, cône d'approche au-dessus de la cible et carburant
+/// disponible. L'énergie de poussée y remplace la norme L1 du carburant ; la durée est ensuite
+/// choisie pour minimiser le carburant consommé, ou la plus courte faisable. `None` si aucune
+/// durée n'est faisable.
+pub fn plan_descent(problem: &DescentProblem, settings: &GuidanceSettings) -> Option<Trajectory> {
+    let durations: Vec<f32> = (0..DURATION_GRID)
+        .map(|i| settings.min_duration + (settings.max_duration - settings.min_duration) * i as f32 / (DURATION_GRID - 1) as f32)
+        .collect();
+    let candidates: Vec<Option<Trajectory>> = durations.iter().map(|&duration| solve_for_duration(problem, settings, duration)).collect();
+
+    match settings.objective {
+        GuidanceObjective::Time => {
+            let first = candidates.iter().position(Option::is_some)?;
+            let mut best = candidates[first].clone();
+            if first > 0 {
+                let (mut infeasible, mut feasible) = (durations[first - 1], durations[first]);
+                for _ in 0..REFINE_ITERATIONS {
+                    let middle = 0.5 * (infeasible + feasible);
+                    match solve_for_duration(problem, settings, middle) {
+                        Some(trajectory) => {
+                            feasible = middle;
+                            best = Some(trajectory);
+                        }
+                        None => infeasible = middle,
+                    }
+                }
+            }
+            best
+        }
+        GuidanceObjective::Fuel => {
+            let fuel = |candidate: &Option<Trajectory>| candidate.as_ref().map_or(f32::INFINITY, |t| t.fuel);
+            let index = (0..candidates.len())
+                .filter(|&i| candidates[i].is_some())
+                .min_by(|&a, &b| fuel(&candidates[a]).total_cmp(&fuel(&candidates[b])))?;
+            let mut best = candidates[index].clone();
+
+            // Section dorée entre les voisins de la meilleure durée de la grille
+            let ratio = 0.5 * (5f32.sqrt() - 1.0);
+            let (mut low, mut high) = (durations[index.saturating_sub(1)], durations[(index + 1).min(durations.len() - 1)]);
+            for _ in 0..REFINE_ITERATIONS {
+                let (left, right) = (high - ratio * (high - low), low + ratio * (high - low));
+                let (a, b) = (solve_for_duration(problem, settings, left), solve_for_duration(problem, settings, right));
+                for candidate in [&a, &b] {
+                    if fuel(candidate) < fuel(&best) {
+                        best = candidate.clone();
+                    }
+                }
+                if fuel(&a) <= fuel(&b) {
+                    high = right;
+                } else {
+                    low = left;
+                }
+            }
+            best
+        }
+    }
+}
+
+/// Programme quadratique à durée fixe. Les variables sont les poussées normalisées par la poussée
+/// maximale, la position et la vitesse aux nœuds étant affines en ces variables.
+fn solve_for_duration(problem: &DescentProblem, settings: &GuidanceSettings, duration: f32) -> Option<Trajectory> {
+    let n = settings.nodes;
+    let dt = (duration / n as f32) as f64;
+    let scale = (problem.engine_power * settings.max_throttle) as f64;
+    let gravity = problem.gravity as f64;
+    let (r0, v0) = (problem.position.as_dvec2(), problem.velocity.as_dvec2());
+    let target = DVec2::new(0.0, LEG_REACH as f64);
+    let target_velocity = DVec2::new(0.0, -settings.touchdown_speed as f64);
+
+    // Position et vitesse au nœud k : partie libre et coefficients de chaque poussée w_j
+    let free_position = |k: usize| {
+        let t = k as f64 * dt;
+        r0 + v0 * t + DVec2::new(0.0, 0.5 * gravity * t * t)
+    };
+    let free_velocity = |k: usize| v0 + DVec2::new(0.0, gravity * k as f64 * dt);
+    let position_gain = |k: usize, j: usize| if j < k { scale * dt * dt * ((k - j) as f64 - 0.5) } else { 0.0 };
+    let velocity_gain = |k: usize, j: usize| if j < k { scale * dt } else { 0.0 };
+
+    let mut rows: Vec<(Vec<f64>, f64, f64)> = Vec::new();
+    let mut push = |coefficients: Vec<f64>, lower: f64, upper: f64| {
+        // Lignes normalisées : l'ADMM converge mieux sur un problème bien équilibré
+        let norm = coefficients.iter().fold(0.0f64, |max, c| max.max(c.abs())).max(f64::EPSILON);
+        rows.push((coefficients.iter().map(|c| c / norm).collect(), lower / norm, upper / norm));
+    };
+
+    // État final imposé
+    for axis in 0..2 {
+        let mut position = vec![0.0; 2 * n];
+        let mut velocity = vec![0.0; 2 * n];
+        for j in 0..n {
+            position[2 * j + axis] = position_gain(n, j);
+            velocity[2 * j + axis] = velocity_gain(n, j);
+        }
+        let position_target = target[axis] - free_position(n)[axis];
+        let velocity_target = target_velocity[axis] - free_velocity(n)[axis];
+        push(position, position_target, position_target);
+        push(velocity, velocity_target, velocity_target);
+    }
+
+    // Cône d'approche : hauteur au-dessus de la cible >= tan(pente) * |écart horizontal|
+    if settings.glide_slope > 0.0 {
+        let slope = (settings.glide_slope as f64).to_radians().tan();
+        for k in 1..n {
+            for side in [1.0, -1.0] {
+                let mut coefficients = vec![0.0; 2 * n];
+                for j in 0..k {
+                    coefficients[2 * j] = side * slope * position_gain(k, j);
+                    coefficients[2 * j + 1] = -position_gain(k, j);
+                }
+                let free = free_position(k);
+                push(coefficients, f64::NEG_INFINITY, (free.y - target.y) - side * slope * free.x);
+            }
+        }
+    }
+
+    // Poussée : cône d'inclinaison et borne de module approchée par des faces
+    let max_tilt = (settings.max_tilt as f64).to_radians();
+    for j in 0..n {
+        for side in [1.0, -1.0] {
+            let mut coefficients = vec![0.0; 2 * n];
+            coefficients[2 * j] = side;
+            coefficients[2 * j + 1] = -max_tilt.tan();
+            push(coefficients, f64::NEG_INFINITY, 0.0);
+        }
+        for face in 0..THRUST_FACES {
+            let angle = -max_tilt + 2.0 * max_tilt * face as f64 / (THRUST_FACES - 1) as f64;
+            let mut coefficients = vec![0.0; 2 * n];
+            coefficients[2 * j] = -angle.sin();
+            coefficients[2 * j + 1] = angle.cos();
+            push(coefficients, f64::NEG_INFINITY, 1.0);
+        }
+    }
+
+    // Carburant : dans le cône, le module de la poussée vaut au plus la composante verticale
+    // divisée par cos(inclinaison max), d'où une borne prudente
+    let burn = scale * dt / problem.engine_power as f64 * FUEL_BURN_RATE as f64 / max_tilt.cos();
+    let mut coefficients = vec![0.0; 2 * n];
+    for j in 0..n {
+        coefficients[2 * j + 1] = burn;
+    }
+    push(coefficients, f64::NEG_INFINITY, problem.fuel as f64);
+
+    let mut a = Matrix::zeros(rows.len(), 2 * n);
+    for (i, (coefficients, _, _)) in rows.iter().enumerate() {
+        for (j, c) in coefficients.iter().enumerate() {
+            a[(i, j)] = *c;
+        }
+    }
+    let program = QuadraticProgram {
+        p: Matrix::identity(2 * n),
+        q: vec![0.0; 2 * n],
+        lower: rows.iter().map(|row| row.1).collect(),
+        upper: rows.iter().map(|row| row.2).collect(),
+        a,
+    };
+    let solution = program
+        .solve(&AdmmSettings { max_iterations: SOLVER_ITERATIONS, ..default() })
+        .ok()?;
+
+    // Nœuds de la trajectoire, intégrés exactement sur chaque intervalle
+    let dt = dt as f32;
+    let mut points = Vec::with_capacity(n + 1);
+    let (mut position, mut velocity) = (problem.position, problem.velocity);
+    let mut fuel = 0.0;
+    for j in 0..n {
+        let thrust = Vec2::new(solution[2 * j] as f32, solution[2 * j + 1] as f32) * scale as f32;
+        points.push(TrajectoryPoint { time: j as f32 * dt, position, velocity, thrust });
+        let acceleration = thrust + Vec2::new(0.0, problem.gravity);
+        position += velocity * dt + 0.5 * acceleration * dt * dt;
+        velocity += acceleration * dt;
+        fuel += thrust.length() / problem.engine_power * FUEL_BURN_RATE * dt;
+    }
+    points.push(TrajectoryPoint { time: duration, position, velocity, thrust: Vec2::ZERO });
+
+    Some(Trajectory { points, gravity: problem.gravity, duration, fuel })
+}
+
+/// Trajectoire planifiée en cours et temps écoulé depuis son début.
+#[derive(Resource, Default)]
+pub struct Guidance {
+    pub settings: GuidanceSettings,
+    pub trajectory: Option<Trajectory>,
+    pub elapsed: f32,
+    /// La dernière planification n'a trouvé aucune trajectoire.
+    pub infeasible: bool,
+}
+
+impl Guidance {
+    /// Point de référence courant, pour la poursuite par un contrôleur ou la récompense.
+    pub fn reference(&self) -> Option<TrajectoryPoint> {
+        self.trajectory.as_ref().map(|trajectory| trajectory.sample(self.elapsed))
+    }
+}
+
+pub struct GuidancePlugin;
+
+impl Plugin for GuidancePlugin {
+    fn build(&self, app: &mut App) {
+        let settings = app.world.get_resource::<GuidanceSettings>().copied().unwrap_or_default();
+        app.insert_resource(Guidance { settings, ..default() })
+           .add_systems(Update, (
+               update_guidance.after(run_estimator),
+               draw_guidance.after(update_guidance),
+               reset_guidance.run_if(resource_equals(GameState::Restarting)).before(restart_system),
+           ));
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+fn update_guidance(
+    mut guidance: ResMut<Guidance>,
+    stats: Res<RocketStats>,
+    sensors: Res<Sensors>,
+    estimator: Res<StateEstimator>,
+    body: Res<CelestialBody>,
+    rocket_query: Query<&Rocket, With<RocketMainBody>>,
+    time: Res<Time>,
+    game_state: Res<GameState>,
+) {
+    if !guidance.settings.enabled || *game_state != GameState::Playing {
+        return;
+    }
+    let Ok(rocket) = rocket_query.get_single() else {
+        return;
+    };
+
+    guidance.elapsed += time.delta_seconds();
+    let view = policy_view(&stats, &sensors, &estimator);
+    let due = guidance.trajectory.is_none() || guidance.elapsed >= guidance.settings.replan_interval;
+    if !due || view.legs_in_contact > 0 || view.pad_offset.y - LEG_REACH < FINAL_APPROACH_HEIGHT {
+        return;
+    }
+
+    let problem = DescentProblem::from_stats(view, body.gravity(), rocket);
+    match plan_descent(&problem, &guidance.settings) {
+        Some(trajectory) => {
+            guidance.trajectory = Some(trajectory);
+            guidance.elapsed = 0.0;
+            guidance.infeasible = false;
+        }
+        None => {
+            // L'ancienne trajectoire reste la référence ; nouvel essai à la période suivante
+            if !guidance.infeasible {
+                println!("🧭 Guidage: aucune trajectoire faisable depuis l'état courant");
+            }
+            guidance.infeasible = true;
+            if let Some(trajectory) = &guidance.trajectory {
+                guidance.elapsed = guidance.elapsed.min(trajectory.duration);
+            }
+        }
+    }
+}
+
+fn draw_guidance(guidance: Res<Guidance>, active_pad: Res<ActivePad>, mut gizmos: Gizmos) {
+    let Some(trajectory) = guidance.trajectory.as_ref().filter(|_| guidance.settings.show) else {
+        return;
+    };
+    let origin = active_pad.position;
+    let steps = 4 * trajectory.points.len();
+    gizmos.linestrip_2d(
+        (0..=steps).map(|i| origin + trajectory.sample(trajectory.duration * i as f32 / steps as f32).position),
+        Color::rgb(0.3, 0.8, 1.0),
+    );
+    if let Some(reference) = guidance.reference() {
+        gizmos.circle_2d(origin + reference.position, 4.0, Color::rgb(1.0, 0.9, 0.2));
+    }
+}
+
+fn reset_guidance(mut guidance: ResMut<Guidance>) {
+    guidance.trajectory = None;
+    guidance.elapsed = 0.0;
+    guidance.infeasible = false;
+}
@@ -0,0 +1,429 @@
+use bevy::prelude::*;
+use crate::control::{ControlCommand, ControlInput, Controller};
+use crate::game::contact::{contact_step, ContactSettings, GroundContact, Surface};
+use crate::game::criteria::LandingCriteria;
+use crate::game::drone_ship::DeckMotion;
+use crate::game::environment::{ActivePad, Wind, GROUND_LEVEL};
+use crate::game::outcome::{judge_touchdown, ContactReading, TouchdownOutcome};
+use crate::game::physics::{body_axis, Aerodynamics, PhysicsBody};
+use crate::game::planet::CelestialBody;
+use crate::game::randomization::EpisodeParameters;
+use crate::game::rocket::{hull_shape, leg_feet, restart_system, Rocket, RocketMainBody, LEG_REACH};
+use crate::game::simulation::{step, BodyState, Medium, RocketState, Vehicle, FUEL_BURN_RATE};
+use crate::game::GameState;
+use crate::rl_agent::metrics::MetricsSettings;
+use crate::settings::{parse_value, SettingsFile};
+
+// Pas et durée maximale de la simulation de faisabilité
+const CHECK_STEP: f32 = 1.0 / 60.0;
+const CHECK_DURATION: f32 = 120.0;
+// Hauteur minimale prise en compte dans le calcul de la décélération (px), évite la division par zéro
+const MIN_BURN_HEIGHT: f32 = 0.05;
+// Temps restant minimal de la correction horizontale pendant le freinage (s)
+const MIN_TIME_TO_GO: f32 = 0.5;
+// Constante de temps de la poursuite de la vitesse horizontale de consigne (s)
+const DRIFT_TIME_CONSTANT: f32 = 0.5;
+// Part du poids que la poussée des corrections de dérive peut compenser : la chute continue
+const DRIFT_LIFT: f32 = 0.7;
+// Durée laissée aux propulseurs d'attitude pour redresser la fusée avant l'allumage et le contact (s)
+const LEVEL_TIME: f32 = 0.5;
+// Inclinaison laissée pendant le redressement (degrés) pour amortir la vitesse horizontale restante
+const LEVEL_TILT: f32 = 2.0;
+// Constante de temps de cet amortissement (s)
+const LEVEL_DAMPING_TIME: f32 = 0.2;
+// Hauteur au-dessus du contact où la vitesse de posé est atteinte puis tenue (px) : légèrement
+// inclinée, la fusée touche d'un pied avant que le centre n'arrive à `LEG_REACH`
+const FINAL_HEIGHT: f32 = 3.0;
+// Constante de temps de la tenue de la vitesse de posé (s)
+const FINAL_TIME_CONSTANT: f32 = 0.1;
+
+/// Réglages de l'autopilote à allumage unique, section `[hoverslam]`.
+#[derive(Resource, Clone, Copy, Debug, PartialEq)]
+pub struct HoverslamSettings {
+    /// Poussée supposée par le calcul de l'allumage ; le reste absorbe inclinaison et traînée.
+    pub burn_throttle: f32,
+    /// Hauteur ajoutée au point d'allumage calculé (px).
+    pub ignition_margin: f32,
+    /// Vitesse de descente visée au contact (px/s).
+    pub touchdown_speed: f32,
+    /// Poussée maximale des corrections de dérive avant l'allumage, la fusée devant continuer à tomber.
+    pub drift_throttle: f32,
+    /// Inclinaison maximale (degrés).
+    pub max_tilt: f32,
+    /// Vitesse angulaire demandée (rad/s) par rad d'écart d'inclinaison.
+    pub attitude_kp: f32,
+    pub legs_height: f32,
+    /// Vérifie à chaque départ que l'autopilote peut se poser.
+    pub check_spawn: bool,
+}
+
+impl Default for HoverslamSettings {
+    fn default() -> Self {
+        Self {
+            burn_throttle: 0.9,
+            ignition_margin: 5.0,
+            touchdown_speed: 1.5,
+            drift_throttle: 0.5,
+            max_tilt: 20.0,
+            attitude_kp: 4.0,
+            legs_height: 120.0,
+            check_spawn: true,
+        }
+    }
+}
+
+impl HoverslamSettings {
+    pub fn from_settings(settings: &SettingsFile) -> Result<Self, String> {
+        let mut hoverslam = Self::default();
+        settings.apply_section("hoverslam", |key, value| {
+            if key == "check_spawn" {
+                hoverslam.check_spawn = parse_value(key, value)?;
+                return Ok(true);
+            }
+            let field = match key {
+                "burn_throttle" => &mut hoverslam.burn_throttle,
+                "ignition_margin" => &mut hoverslam.ignition_margin,
+                "touchdown_speed" => &mut hoverslam.touchdown_speed,
+                "drift_throttle" => &mut hoverslam.drift_throttle,
+                "max_tilt" => &mut hoverslam.max_tilt,
+                "attitude_kp" => &mut hoverslam.attitude_kp,
+                "legs_height" => &mut hoverslam.legs_height,
+                _ => return Ok(false),
+            };
+            *field = parse_value(key, value)?;
+            Ok(true)
+        })?;
+        Ok(hoverslam)
+    }
+}
+
+/// Freinage vertical à poussée constante qui annule la vitesse au contact.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct BurnPlan {
+    /// Hauteur des pieds à l'allumage (px).
+    pub ignition_height: f32,
+    /// Vitesse de descente à l'allumage (px/s).
+    pub ignition_speed: f32,
+    /// Durée de la chute avant l'allumage (s).
+    pub coast_time: f32,
+    pub burn_time: f32,
+    /// Carburant consommé par le freinage, dans l'unité de `Rocket::fuel`.
+    pub fuel_needed: f32,
+}
+
+/// Point d'allumage d'une chute libre depuis `height` à la vitesse verticale `vertical_speed`
+/// (positive en montée), freinée ensuite par la poussée `throttle` jusqu'à `touchdown_speed`.
+/// La conservation de l'énergie donne `v_i² = v₀² + 2|g|(h - h_i)` et `v_i² - v_t² = 2(a - |g|)h_i`.
+/// Aucun plan si la poussée ne compense pas la gravité ; si l'allumage est déjà dépassé, il a
+/// lieu tout de suite.
+pub fn burn_plan(height: f32, vertical_speed: f32, gravity: f32, engine_power: f32, throttle: f32, touchdown_speed: f32) -> Option<BurnPlan> {
+    let thrust = engine_power * throttle;
+    let deceleration = thrust + gravity;
+    if deceleration <= 0.0 {
+        return None;
+    }
+    let height = height.max(0.0);
+    let free_fall = (vertical_speed.powi(2) - 2.0 * gravity * height - touchdown_speed.powi(2)) / (2.0 * thrust);
+    let ignition_height = free_fall.clamp(0.0, height);
+    let ignition_speed = if free_fall > height {
+        (-vertical_speed).max(0.0)
+    } else {
+        (touchdown_speed.powi(2) + 2.0 * deceleration * ignition_height).sqrt()
+    };
+    let coast_time = ((ignition_speed + vertical_speed) / -gravity).max(0.0);
+    let burn_time = ((ignition_speed - touchdown_speed) / deceleration).max(0.0);
+    Some(BurnPlan {
+        ignition_height,
+        ignition_speed,
+        coast_time,
+        burn_time,
+        fuel_needed: throttle * FUEL_BURN_RATE * burn_time,
+    })
+}
+
+/// Autopilote à allumage unique (« hoverslam ») : chute moteur coupé puis allumage au point
+/// calculé par `burn_plan`, la poussée étant ensuite modulée pour que la décélération restante
+/// `(v² - v_t²) / 2h` amène la vitesse de contact au niveau du pont. Pendant la chute, de petites
+/// impulsions inclinées annulent la dérive horizontale en suivant la courbe de freinage `√(2a|x|)`
+/// vers la plateforme ; pendant le freinage, l'inclinaison vise `-6x/t² - 4v/t` jusqu'à la fenêtre
+/// de redressement, où la fusée se redresse en n'amortissant plus que la vitesse horizontale restante.
+pub struct Hoverslam {
+    pub settings: HoverslamSettings,
+    ignited: bool,
+}
+
+impl Hoverslam {
+    pub fn new(settings: HoverslamSettings) -> Self {
+        Self { settings, ignited: false }
+    }
+
+    /// Posée : moteur coupé, la fusée est couchée sur ses deux pieds selon la pente du pont,
+    /// `tilt` étant l'écart de son inclinaison à celle du pont.
+    pub fn ground_command(&self, tilt: f32, vehicle: &Vehicle) -> ControlCommand {
+        let rotation = self.settings.attitude_kp * tilt.sin().atan2(tilt.cos()) / vehicle.rotation_speed;
+        ControlCommand { throttle: 0.0, rotation: rotation.clamp(-1.0, 1.0), deploy_legs: true }
+    }
+
+    /// Commande pour un état dans le repère de la plateforme.
+    pub fn law(&mut self, state: &RocketState, gravity: f32, vehicle: &Vehicle) -> ControlCommand {
+        let settings = &self.settings;
+        let body = &state.body;
+        let height = body.position.y - LEG_REACH;
+        let deploy_legs = height < settings.legs_height;
+        let descent_speed = -body.velocity.y;
+
+        let approach_height = height - FINAL_HEIGHT;
+
+        let plan = burn_plan(approach_height, body.velocity.y, gravity, vehicle.engine_power, settings.burn_throttle, settings.touchdown_speed);
+        if !self.ignited {
+            self.ignited = plan.is_none_or(|plan| approach_height <= plan.ignition_height + settings.ignition_margin);
+        }
+
+        let max_tilt = settings.max_tilt.to_radians();
+        let (target_angle, throttle) = if self.ignited {
+            // Décélération restante pour atteindre `touchdown_speed` à `FINAL_HEIGHT` ; en montée ou
+            // trop lent, la poussée diminue pour laisser descendre. Plus bas, cette vitesse est tenue
+            let braking = if approach_height > MIN_BURN_HEIGHT {
+                (descent_speed * descent_speed.abs() - settings.touchdown_speed.powi(2)) / (2.0 * approach_height)
+            } else {
+                (descent_speed - settings.touchdown_speed) / FINAL_TIME_CONSTANT
+            };
+            let time_to_go = 2.0 * approach_height.max(0.0) / (descent_speed + settings.touchdown_speed).max(f32::EPSILON);
+            let level_tilt = LEVEL_TILT.to_radians().min(max_tilt);
+            let tilt_limit = (max_tilt * ((time_to_go - LEVEL_TIME) / LEVEL_TIME).clamp(0.0, 1.0)).max(level_tilt);
+            // Écart et vitesse horizontale annulés avant la fenêtre de redressement (2 × LEVEL_TIME) ;
+            // ensuite, seule la vitesse horizontale restante est amortie tant que l'inclinaison le permet
+            let divert_time = time_to_go - 2.0 * LEVEL_TIME;
+            let lateral = if divert_time > MIN_TIME_TO_GO {
+                -6.0 * body.position.x / divert_time.powi(2) - 4.0 * body.velocity.x / divert_time
+            } else {
+                -body.velocity.x / LEVEL_DAMPING_TIME
+            };
+            let acceleration = Vec2::new(lateral, braking - gravity);
+            let target_angle = (-acceleration.x).atan2(acceleration.y.max(f32::EPSILON)).clamp(-tilt_limit, tilt_limit);
+            (target_angle, (acceleration.y / body_axis(body.angle).y) / vehicle.engine_power)
+        } else if let Some(coast_time) = plan.map(|plan| plan.coast_time - LEVEL_TIME).filter(|time| *time > 0.0) {
+            // Moteur coupé sauf pour les corrections de dérive : nez incliné vers la correction,
+            // poussée dosée selon la demande et l'alignement déjà atteint
+            let drift_throttle = settings.drift_throttle.min(DRIFT_LIFT * -gravity / (vehicle.engine_power * max_tilt.cos()));
+            let max_lateral = vehicle.engine_power * drift_throttle * max_tilt.sin();
+            // Vitesse vers la plateforme qu'une demi-autorité suffit à annuler avant la fin de la chute
+            let braking = 0.5 * max_lateral;
+            let target_speed = -body.position.x.signum() * (2.0 * braking * body.position.x.abs()).sqrt().min(braking * coast_time);
+            let lateral = (target_speed - body.velocity.x) / DRIFT_TIME_CONSTANT;
+            let demand = (lateral / max_lateral).clamp(-1.0, 1.0);
+            let alignment = (body_axis(body.angle).x * demand.signum() / max_tilt.sin()).clamp(0.0, 1.0);
+            (-max_tilt * demand, drift_throttle * demand.abs() * alignment)
+        } else {
+            // Redressement avant l'allumage
+            (0.0, 0.0)
+        };
+
+        let angle_error = (target_angle - body.angle).sin().atan2((target_angle - body.angle).cos());
+        let rotation = settings.attitude_kp * angle_error / vehicle.rotation_speed;
+        ControlCommand { throttle: throttle.clamp(0.0, 1.0), rotation: rotation.clamp(-1.0, 1.0), deploy_legs }
+    }
+}
+
+impl Controller for Hoverslam {
+    fn name(&self) -> &'static str {
+        "hoverslam"
+    }
+
+    fn reset(&mut self) {
+        self.ignited = false;
+    }
+
+    fn command(&mut self, input: &ControlInput) -> ControlCommand {
+        let stats = input.stats;
+        if stats.legs_in_contact > 0 {
+            return self.ground_command(stats.pad_tilt - stats.angle, &input.vehicle);
+        }
+        let (state, _) = input.pad_frame();
+        self.law(&state, input.medium.gravity, &input.vehicle)
+    }
+}
+
+/// Issue simulée de l'autopilote depuis un état de départ.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct LandingCheck {
+    /// Le contact respecte les critères d'atterrissage.
+    pub landable: bool,
+    /// Plan de freinage calculé au départ, absent si la poussée ne compense pas la gravité.
+    pub plan: Option<BurnPlan>,
+    /// Vitesse relative au pont au premier contact, ou à la fin de la simulation.
+    pub touchdown_velocity: Vec2,
+    pub touchdown_offset: f32,
+    pub touchdown_angle: f32,
+    pub fuel_left: f32,
+    pub time: f32,
+}
+
+/// Simule l'autopilote comme le jeu le fait voler, pas de vol `simulation::step` et contact
+/// `contact::contact_step` sur le pont figé dans sa position du départ, puis juge le posé avec
+/// `outcome::judge_touchdown`, pour savoir si une configuration de départ peut être posée par cet
+/// autopilote. Le retard des actionneurs, les rafales et les capteurs ne sont pas simulés.
+#[allow(clippy::too_many_arguments)]
+pub fn check_landing(
+    start: &RocketState,
+    medium: &Medium,
+    vehicle: &Vehicle,
+    settings: &HoverslamSettings,
+    criteria: &LandingCriteria,
+    contact_settings: &ContactSettings,
+    deck_tilt: f32,
+    zone_half_width: f32,
+) -> LandingCheck {
+    let height = start.body.position.y - LEG_REACH - FINAL_HEIGHT;
+    let plan = burn_plan(height, start.body.velocity.y, medium.gravity, vehicle.engine_power, settings.burn_throttle, settings.touchdown_speed);
+    let mut autopilot = Hoverslam::new(*settings);
+    let mut vehicle = *vehicle;
+    let mut state = *start;
+    let mut contact = GroundContact::default();
+    let mut legs_deployed = false;
+    let mut time = 0.0;
+    // Pont immobile dans le repère de la plateforme, passant par son centre
+    let deck = Surface::Deck(DeckMotion { tilt: deck_tilt, ..default() });
+    let hull = hull_shape();
+
+    let touchdown = loop {
+        if let Some(first_contact) = contact.touchdown {
+            let tilt = state.body.angle - contact.surface.tilt();
+            let reading = ContactReading {
+                impact_velocity: first_contact.velocity,
+                tilt: tilt.sin().atan2(tilt.cos()),
+                pad_offset: Vec2::from_angle(-deck_tilt).rotate(state.body.position).x,
+                legs_deployed,
+                hull_contact: contact.hull_contact,
+                splashdown: contact.splashdown,
+                settled_time: contact.settled_time,
+                fuel: state.fuel,
+                max_fuel: vehicle.max_fuel,
+            };
+            if let Some(touchdown) = judge_touchdown(&reading, criteria, zone_half_width, contact_settings.tip_over_angle, default()) {
+                break Some(touchdown);
+            }
+        }
+        if time >= CHECK_DURATION {
+            break None;
+        }
+
+        let command = if contact.legs_in_contact > 0 {
+            autopilot.ground_command(deck_tilt - state.body.angle, &vehicle)
+        } else {
+            autopilot.law(&state, medium.gravity, &vehicle)
+        };
+        // Déploiement unique, qui ajoute la traînée du train
+        if command.deploy_legs && !legs_deployed {
+            legs_deployed = true;
+            vehicle.aerodynamics.gear_deployed = true;
+        }
+        let feet = if legs_deployed { leg_feet().to_vec() } else { Vec::new() };
+        contact_step(contact_settings, &mut state.body, vehicle.radius_of_gyration, &hull, &feet, &mut contact,
+                     |x| (x * deck_tilt.tan(), deck), CHECK_STEP);
+        state = step(&state, medium, &vehicle, command.throttle, command.rotation, CHECK_STEP);
+        time += CHECK_STEP;
+    };
+
+    let impact_velocity = contact.touchdown.map_or(state.body.velocity, |first_contact| first_contact.velocity);
+    LandingCheck {
+        landable: touchdown.is_some_and(|touchdown| touchdown.outcome == TouchdownOutcome::Landed),
+        plan,
+        touchdown_velocity: impact_velocity,
+        touchdown_offset: Vec2::from_angle(-deck_tilt).rotate(state.body.position).x,
+        touchdown_angle: state.body.angle.sin().atan2(state.body.angle.cos()),
+        fuel_left: state.fuel,
+        time,
+    }
+}
+
+/// Faisabilité du départ courant, vérifiée une fois par épisode avec les paramètres réels.
+#[derive(Resource, Default)]
+pub struct SpawnFeasibility {
+    pub check: Option<LandingCheck>,
+}
+
+pub struct HoverslamPlugin;
+
+impl Plugin for HoverslamPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<SpawnFeasibility>()
+           .add_systems(Update, (
+               check_spawn_feasibility,
+               reset_spawn_feasibility.run_if(resource_equals(GameState::Restarting)).before(restart_system),
+           ));
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+fn check_spawn_feasibility(
+    mut feasibility: ResMut<SpawnFeasibility>,
+    settings: Res<HoverslamSettings>,
+    criteria: Res<LandingCriteria>,
+    contact_settings: Res<ContactSettings>,
+    body: Res<CelestialBody>,
+    parameters: Res<EpisodeParameters>,
+    wind: Res<Wind>,
+    active_pad: Res<ActivePad>,
+    rocket_query: Query<(&Transform, &Rocket, &PhysicsBody, &Aerodynamics), With<RocketMainBody>>,
+    metrics: Res<MetricsSettings>,
+    game_state: Res<GameState>,
+) {
+    if !settings.check_spawn || feasibility.check.is_some() || *game_state != GameState::Playing {
+        return;
+    }
+    let Ok((transform, rocket, physics, aerodynamics)) = rocket_query.get_single() else {
+        return;
+    };
+
+    // État exact dans le repère de la plateforme, gravité et véhicule de l'épisode
+    let start = RocketState {
+        body: BodyState {
+            position: transform.translation.truncate() - active_pad.position,
+            velocity: physics.velocity - active_pad.velocity,
+            angle: transform.rotation.to_euler(EulerRot::XYZ).2,
+            angular_velocity: physics.angular_velocity,
+        },
+        fuel: rocket.fuel,
+    };
+    let medium = Medium {
+        gravity: body.gravity() * parameters.gravity_scale,
+        atmosphere: body.atmosphere,
+        wind: wind.velocity - active_pad.velocity,
+        ground_level: GROUND_LEVEL - active_pad.position.y,
+    };
+    let vehicle = Vehicle::of(rocket, physics, aerodynamics);
+    let check = check_landing(
+        &start,
+        &medium,
+        &vehicle,
+        &settings,
+        &criteria,
+        &contact_settings,
+        active_pad.tilt,
+        criteria.zone_half_width(active_pad.width),
+    );
+
+    if metrics.shows_flight_events() {
+        match (check.landable, check.plan) {
+            (true, Some(plan)) => println!(
+                "🧮 Hoverslam: départ posable (allumage à {:.0} px, {:.0}% de carburant restant)",
+                plan.ignition_height,
+                100.0 * check.fuel_left / rocket.max_fuel
+            ),
+            (true, None) => println!("🧮 Hoverslam: départ posable"),
+            (false, None) => println!("⚠️ Hoverslam: poussée insuffisante face à la gravité, départ non posable"),
+            (false, Some(_)) => println!(
+                "⚠️ Hoverslam: départ non posable (contact à {:.1} px/s vertical, {:.1} px/s horizontal, écart {:.0} px)",
+                -check.touchdown_velocity.y,
+                check.touchdown_velocity.x,
+                check.touchdown_offset
+            ),
+        }
+    }
+    feasibility.check = Some(check);
+}
+
+fn reset_spawn_feasibility(mut feasibility: ResMut<SpawnFeasibility>) {
+    feasibility.check = None;
+}
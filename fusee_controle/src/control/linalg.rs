@@ -0,0 +1,214 @@
+use std::ops::{Add, Index, IndexMut, Mul, Sub};
+
+/// Matrice dense en double précision, stockée ligne par ligne. Suffisante pour les petits
+/// systèmes des contrôleurs (quelques états, quelques commandes).
+#[derive(Clone, Debug, PartialEq)]
+pub struct Matrix {
+    pub rows: usize,
+    pub cols: usize,
+    data: Vec<f64>,
+}
+
+impl Matrix {
+    pub fn zeros(rows: usize, cols: usize) -> Self {
+        Self { rows, cols, data: vec![0.0; rows * cols] }
+    }
+
+    pub fn identity(size: usize) -> Self {
+        let mut m = Self::zeros(size, size);
+        for i in 0..size {
+            m[(i, i)] = 1.0;
+        }
+        m
+    }
+
+    pub fn diagonal(values: &[f64]) -> Self {
+        let mut m = Self::zeros(values.len(), values.len());
+        for (i, value) in values.iter().enumerate() {
+            m[(i, i)] = *value;
+        }
+        m
+    }
+
+    pub fn from_rows(rows: &[&[f64]]) -> Self {
+        let cols = rows.first().map_or(0, |row| row.len());
+        let mut m = Self::zeros(rows.len(), cols);
+        for (i, row) in rows.iter().enumerate() {
+            assert_eq!(row.len(), cols, "lignes de longueurs différentes");
+            m.data[i * cols..(i + 1) * cols].copy_from_slice(row);
+        }
+        m
+    }
+
+    /// Vecteur colonne.
+    pub fn column(values: &[f64]) -> Self {
+        Self { rows: values.len(), cols: 1, data: values.to_vec() }
+    }
+
+    pub fn transpose(&self) -> Self {
+        let mut m = Self::zeros(self.cols, self.rows);
+        for i in 0..self.rows {
+            for j in 0..self.cols {
+                m[(j, i)] = self[(i, j)];
+            }
+        }
+        m
+    }
+
+    pub fn scale(&self, factor: f64) -> Self {
+        Self { data: self.data.iter().map(|v| v * factor).collect(), ..self.clone() }
+    }
+
+    /// Inverse par élimination de Gauss-Jordan avec pivot partiel ; `None` si singulière.
+    pub fn inverse(&self) -> Option<Self> {
+        assert_eq!(self.rows, self.cols, "inverse d'une matrice non carrée");
+        let n = self.rows;
+        let mut a = self.clone();
+        let mut inv = Self::identity(n);
+
+        for col in 0..n {
+            let pivot = (col..n).max_by(|&i, &j| a[(i, col)].abs().total_cmp(&a[(j, col)].abs()))?;
+            if a[(pivot, col)].abs() < 1e-12 {
+                return None;
+            }
+            a.swap_rows(col, pivot);
+            inv.swap_rows(col, pivot);
+
+            let p = a[(col, col)];
+            for j in 0..n {
+                a[(col, j)] /= p;
+                inv[(col, j)] /= p;
+            }
+            for i in (0..n).filter(|&i| i != col) {
+                let factor = a[(i, col)];
+                if factor != 0.0 {
+                    for j in 0..n {
+                        a[(i, j)] -= factor * a[(col, j)];
+                        inv[(i, j)] -= factor * inv[(col, j)];
+                    }
+                }
+            }
+        }
+        Some(inv)
+    }
+
+    /// Produit matrice-vecteur `M v`.
+    pub fn apply(&self, v: &[f64]) -> Vec<f64> {
+        assert_eq!(self.cols, v.len(), "dimensions incompatibles pour le produit");
+        self.data.chunks(self.cols).map(|row| row.iter().zip(v).map(|(a, b)| a * b).sum()).collect()
+    }
+
+    /// Produit `Mᵀ v` sans former la transposée.
+    pub fn apply_transpose(&self, v: &[f64]) -> Vec<f64> {
+        assert_eq!(self.rows, v.len(), "dimensions incompatibles pour le produit");
+        let mut result = vec![0.0; self.cols];
+        for (row, factor) in self.data.chunks(self.cols).zip(v) {
+            for (r, a) in result.iter_mut().zip(row) {
+                *r += a * factor;
+            }
+        }
+        result
+    }
+
+    pub fn max_abs(&self) -> f64 {
+        self.data.iter().fold(0.0, |max, v| max.max(v.abs()))
+    }
+
+    pub fn as_slice(&self) -> &[f64] {
+        &self.data
+    }
+
+    fn swap_rows(&mut self, a: usize, b: usize) {
+        if a != b {
+            for j in 0..self.cols {
+                self.data.swap(a * self.cols + j, b * self.cols + j);
+            }
+        }
+    }
+}
+
+impl Index<(usize, usize)> for Matrix {
+    type Output = f64;
+
+    fn index(&self, (i, j): (usize, usize)) -> &f64 {
+        &self.data[i * self.cols + j]
+    }
+}
+
+impl IndexMut<(usize, usize)> for Matrix {
+    fn index_mut(&mut self, (i, j): (usize, usize)) -> &mut f64 {
+        &mut self.data[i * self.cols + j]
+    }
+}
+
+impl Mul for &Matrix {
+    type Output = Matrix;
+
+    fn mul(self, rhs: &Matrix) -> Matrix {
+        assert_eq!(self.cols, rhs.rows, "dimensions incompatibles pour le produit");
+        let mut m = Matrix::zeros(self.rows, rhs.cols);
+        for i in 0..self.rows {
+            for k in 0..self.cols {
+                let a = self[(i, k)];
+                if a != 0.0 {
+                    for j in 0..rhs.cols {
+                        m[(i, j)] += a * rhs[(k, j)];
+                    }
+                }
+            }
+        }
+        m
+    }
+}
+
+impl Add for &Matrix {
+    type Output = Matrix;
+
+    fn add(self, rhs: &Matrix) -> Matrix {
+        assert_eq!((self.rows, self.cols), (rhs.rows, rhs.cols), "dimensions incompatibles pour la somme");
+        Matrix { data: self.data.iter().zip(&rhs.data).map(|(a, b)| a + b).collect(), ..self.clone() }
+    }
+}
+
+impl Sub for &Matrix {
+    type Output = Matrix;
+
+    fn sub(self, rhs: &Matrix) -> Matrix {
+        assert_eq!((self.rows, self.cols), (rhs.rows, rhs.cols), "dimensions incompatibles pour la différence");
+        Matrix { data: self.data.iter().zip(&rhs.data).map(|(a, b)| a - b).collect(), ..self.clone() }
+    }
+}
+
+/// Résout l'équation de Riccati algébrique discrète par itération et renvoie le gain
+/// `K = (R + BᵀPB)⁻¹ BᵀPA` de la commande `u = -K x`. `None` si l'itération ne converge pas.
+pub fn solve_dare(a: &Matrix, b: &Matrix, q: &Matrix, r: &Matrix) -> Option<Matrix> {
+    const MAX_ITERATIONS: usize = 100_000;
+    const TOLERANCE: f64 = 1e-9;
+
+    let at = a.transpose();
+    let bt = b.transpose();
+    let mut p = q.clone();
+
+    for _ in 0..MAX_ITERATIONS {
+        let pa = &p * a;
+        let pb = &p * b;
+        let gain = (r + &(&bt * &pb)).inverse()? * &(&bt * &pa);
+        let next = &(q + &(&at * &pa)) - &(&(&at * &pb) * &gain);
+
+        let change = (&next - &p).max_abs();
+        p = next;
+        if change <= TOLERANCE * p.max_abs().max(1.0) {
+            let pb = &p * b;
+            return Some(&(r + &(&bt * &pb)).inverse()? * &(&bt * &(&p * a)));
+        }
+    }
+    None
+}
+
+impl Mul<&Matrix> for Matrix {
+    type Output = Matrix;
+
+    fn mul(self, rhs: &Matrix) -> Matrix {
+        &self * rhs
+    }
+}
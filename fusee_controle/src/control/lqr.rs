@@ -0,0 +1,233 @@
+use bevy::prelude::*;
+use crate::control::linalg::{solve_dare, Matrix};
+use crate::control::{ControlCommand, ControlInput, Controller};
+use crate::game::physics::{aerodynamic_loads, body_axis, Aerodynamics};
+use crate::settings::{parse_value, SettingsFile};
+
+// Pas de discrétisation du modèle linéaire (une image à 60 Hz)
+const DESIGN_STEP: f64 = 1.0 / 60.0;
+// Pas des différences finies de la linéarisation
+const PERTURBATION: f32 = 1e-3;
+
+// État de l'écart au point d'équilibre : écart horizontal, vitesses, inclinaison, vitesse angulaire
+const STATES: usize = 5;
+// Commandes : poussée, rotation
+const INPUTS: usize = 2;
+
+/// Réglages du LQR, section `[lqr]`. Les poids sont ceux des matrices diagonales Q et R.
+#[derive(Resource, Clone, Copy, Debug, PartialEq)]
+pub struct LqrSettings {
+    /// Vitesse de descente du point d'équilibre de la linéarisation (px/s).
+    pub trim_descent_speed: f32,
+    /// Consigne de descente : `touchdown_speed + descent_gain * hauteur`, bornée.
+    pub touchdown_speed: f32,
+    pub descent_gain: f32,
+    pub max_descent_speed: f32,
+    pub q_position: f32,
+    pub q_velocity: f32,
+    pub q_angle: f32,
+    pub q_rate: f32,
+    pub r_throttle: f32,
+    pub r_rotation: f32,
+    pub legs_height: f32,
+}
+
+impl Default for LqrSettings {
+    fn default() -> Self {
+        Self {
+            trim_descent_speed: 10.0,
+            touchdown_speed: 1.5,
+            descent_gain: 0.4,
+            max_descent_speed: 60.0,
+            q_position: 1.0e-3,
+            q_velocity: 1.0e-2,
+            q_angle: 10.0,
+            q_rate: 1.0,
+            r_throttle: 1.0,
+            r_rotation: 1.0,
+            legs_height: 120.0,
+        }
+    }
+}
+
+impl LqrSettings {
+    pub fn from_settings(settings: &SettingsFile) -> Result<Self, String> {
+        let mut lqr = Self::default();
+        settings.apply_section("lqr", |key, value| {
+            let field = match key {
+                "trim_descent_speed" => &mut lqr.trim_descent_speed,
+                "touchdown_speed" => &mut lqr.touchdown_speed,
+                "descent_gain" => &mut lqr.descent_gain,
+                "max_descent_speed" => &mut lqr.max_descent_speed,
+                "q_position" => &mut lqr.q_position,
+                "q_velocity" => &mut lqr.q_velocity,
+                "q_angle" => &mut lqr.q_angle,
+                "q_rate" => &mut lqr.q_rate,
+                "r_throttle" => &mut lqr.r_throttle,
+                "r_rotation" => &mut lqr.r_rotation,
+                "legs_height" => &mut lqr.legs_height,
+                _ => return Ok(false),
+            };
+            *field = parse_value(key, value)?;
+            Ok(true)
+        })?;
+        Ok(lqr)
+    }
+}
+
+/// Paramètres du véhicule dont dépend la linéarisation.
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct VehicleModel {
+    gravity: f32,
+    engine_power: f32,
+    rotation_speed: f32,
+    rcs_authority: f32,
+    air_density: f32,
+    aerodynamics: Aerodynamics,
+}
+
+impl VehicleModel {
+    fn from_input(input: &ControlInput) -> Self {
+        Self {
+            gravity: input.medium.gravity,
+            engine_power: input.vehicle.engine_power,
+            rotation_speed: input.vehicle.rotation_speed,
+            rcs_authority: input.vehicle.rcs_authority,
+            // Densité au niveau du sol : le gain ne dépend pas de l'altitude
+            air_density: input.medium.atmosphere.density_at(0.0),
+            aerodynamics: input.vehicle.aerodynamics,
+        }
+    }
+
+    /// Dérivées de `[x, vx, vy, angle, vitesse angulaire]` : poussée de `apply_rocket_thrust`,
+    /// gravité et traînée de `apply_physics`, propulseurs d'attitude de `apply_controls` vus
+    /// comme un premier ordre de constante de temps `rotation_speed / rcs_authority`. Le couple
+    /// aérodynamique, faible à basse vitesse, est négligé.
+    fn derivatives(&self, state: [f32; STATES], input: [f32; INPUTS]) -> [f32; STATES] {
+        let [_, vx, vy, angle, rate] = state;
+        let [throttle, rotation] = input;
+        let velocity = Vec2::new(vx, vy);
+        let loads = aerodynamic_loads(&self.aerodynamics, self.air_density, velocity, angle);
+        let acceleration = body_axis(angle) * self.engine_power * throttle + Vec2::new(0.0, self.gravity) + loads.acceleration;
+        let rate_response = self.rcs_authority / self.rotation_speed;
+        [vx, acceleration.x, acceleration.y, rate, rate_response * (rotation * self.rotation_speed - rate)]
+    }
+
+    /// Poussée d'équilibre en descente verticale à `descent_speed`.
+    fn trim_throttle(&self, descent_speed: f32) -> f32 {
+        let loads = aerodynamic_loads(&self.aerodynamics, self.air_density, Vec2::new(0.0, -descent_speed), 0.0);
+        (-(self.gravity + loads.acceleration.y) / self.engine_power).clamp(0.0, 1.0)
+    }
+}
+
+/// Gain calculé pour un véhicule donné.
+struct LqrDesign {
+    model: VehicleModel,
+    gain: Matrix,
+    trim_throttle: f32,
+}
+
+/// Régulateur linéaire quadratique autour d'une descente verticale à vitesse constante.
+///
+/// La dynamique est linéarisée par différences finies autour de l'équilibre, discrétisée au pas
+/// d'une image, et le gain est obtenu par l'équation de Riccati discrète. Il est recalculé quand
+/// le véhicule change (corps céleste, poussée). La consigne de vitesse verticale suit la hauteur,
+/// la commande est saturée sur la poussée et la rotation.
+pub struct Lqr {
+    pub settings: LqrSettings,
+    /// Gain du dernier véhicule, ou ce véhicule si l'équation de Riccati n'a pas convergé : le
+    /// calcul n'est pas relancé à chaque image pour un même échec.
+    design: Option<Result<LqrDesign, VehicleModel>>,
+}
+
+impl Lqr {
+    pub fn new(settings: LqrSettings) -> Self {
+        Self { settings, design: None }
+    }
+
+    fn design(&self, model: VehicleModel) -> Option<LqrDesign> {
+        let trim_throttle = model.trim_throttle(self.settings.trim_descent_speed);
+        let trim_state = [0.0, 0.0, -self.settings.trim_descent_speed, 0.0, 0.0];
+        let trim_input = [trim_throttle, 0.0];
+
+        // Jacobiennes par différences centrées
+        let mut a = Matrix::identity(STATES);
+        let mut b = Matrix::zeros(STATES, INPUTS);
+        for j in 0..STATES {
+            let (mut plus, mut minus) = (trim_state, trim_state);
+            plus[j] += PERTURBATION;
+            minus[j] -= PERTURBATION;
+            let (fp, fm) = (model.derivatives(plus, trim_input), model.derivatives(minus, trim_input));
+            for i in 0..STATES {
+                a[(i, j)] += ((fp[i] - fm[i]) / (2.0 * PERTURBATION)) as f64 * DESIGN_STEP;
+            }
+        }
+        for j in 0..INPUTS {
+            let (mut plus, mut minus) = (trim_input, trim_input);
+            plus[j] += PERTURBATION;
+            minus[j] -= PERTURBATION;
+            let (fp, fm) = (model.derivatives(trim_state, plus), model.derivatives(trim_state, minus));
+            for i in 0..STATES {
+                b[(i, j)] = ((fp[i] - fm[i]) / (2.0 * PERTURBATION)) as f64 * DESIGN_STEP;
+            }
+        }
+
+        let s = &self.settings;
+        let q = Matrix::diagonal(&[s.q_position, s.q_velocity, s.q_velocity, s.q_angle, s.q_rate].map(f64::from));
+        let r = Matrix::diagonal(&[s.r_throttle, s.r_rotation].map(f64::from));
+        let gain = solve_dare(&a, &b, &q, &r)?;
+        Some(LqrDesign { model, gain, trim_throttle })
+    }
+}
+
+impl Controller for Lqr {
+    fn name(&self) -> &'static str {
+        "lqr"
+    }
+
+    fn reset(&mut self) {}
+
+    fn command(&mut self, input: &ControlInput) -> ControlCommand {
+        let settings = self.settings;
+        let stats = input.stats;
+        let height = input.height_above_pad();
+        let deploy_legs = height < settings.legs_height;
+
+        if stats.legs_in_contact > 0 {
+            return ControlCommand { throttle: 0.0, rotation: 0.0, deploy_legs };
+        }
+
+        let model = VehicleModel::from_input(input);
+        let designed_for = self.design.as_ref().map(|design| match design {
+            Ok(design) => design.model,
+            Err(model) => *model,
+        });
+        if designed_for != Some(model) {
+            let design = self.design(model).ok_or(model);
+            if design.is_err() {
+                eprintln!("⚠️ LQR: l'équation de Riccati ne converge pas pour ce véhicule, moteur coupé");
+            }
+            self.design = Some(design);
+        }
+        let Some(Ok(design)) = &self.design else {
+            return ControlCommand { deploy_legs, ..default() };
+        };
+
+        let target_descent = -(settings.touchdown_speed + settings.descent_gain * height.max(0.0)).min(settings.max_descent_speed);
+        let velocity = input.relative_velocity();
+        let error = Matrix::column(&[
+            stats.pad_offset.x as f64,
+            velocity.x as f64,
+            (velocity.y - target_descent) as f64,
+            stats.angle.sin().atan2(stats.angle.cos()) as f64,
+            stats.angular_velocity as f64,
+        ]);
+        let correction = &design.gain * &error;
+
+        ControlCommand {
+            throttle: (design.trim_throttle - correction[(0, 0)] as f32).clamp(0.0, 1.0),
+            rotation: (-correction[(1, 0)] as f32).clamp(-1.0, 1.0),
+            deploy_legs,
+        }
+    }
+}
@@ -0,0 +1,241 @@
+pub mod guidance;
+pub mod hoverslam;
+pub mod linalg;
+pub mod lqr;
+pub mod mpc;
+pub mod pid;
+pub mod qp;
+
+use bevy::prelude::*;
+use crate::game::estimator::{run_estimator, StateEstimator};
+use crate::game::environment::{Wind, GROUND_LEVEL};
+use crate::game::physics::{Aerodynamics, PhysicsBody};
+use crate::game::planet::CelestialBody;
+use crate::game::rocket::{restart_system, Rocket, RocketMainBody, RocketStats, LEG_REACH};
+use crate::game::sensors::Sensors;
+use crate::game::simulation::{BodyState, Medium, RocketState, Vehicle};
+use crate::game::GameState;
+use crate::rl_agent::{policy_view, RocketControls};
+use guidance::{Guidance, TrajectoryPoint};
+use hoverslam::{Hoverslam, HoverslamSettings};
+use lqr::{Lqr, LqrSettings};
+use mpc::{Mpc, MpcSettings};
+use pid::{CascadedPid, CascadedPidSettings};
+
+/// Ce que voit un contrôleur à chaque pas.
+pub struct ControlInput<'a> {
+    /// État vu par le contrôleur : estimation ou mesure des capteurs, comme pour l'agent.
+    pub stats: &'a RocketStats,
+    /// Milieu nominal du corps céleste, dans le repère du monde.
+    pub medium: Medium,
+    pub vehicle: Vehicle,
+    /// Point courant de la trajectoire de guidage, si elle est active.
+    pub reference: Option<TrajectoryPoint>,
+    pub dt: f32,
+}
+
+impl ControlInput<'_> {
+    /// Hauteur des pieds au-dessus de la plateforme cible.
+    pub fn height_above_pad(&self) -> f32 {
+        self.stats.pad_offset.y - LEG_REACH
+    }
+
+    /// Vitesse relative au pont de la plateforme cible.
+    pub fn relative_velocity(&self) -> Vec2 {
+        Vec2::new(self.stats.horizontal_speed, self.stats.vertical_speed) - self.stats.pad_velocity
+    }
+
+    /// Copie de l'état de la fusée dans le repère de la plateforme (origine au centre du pont,
+    /// vitesses relatives) et milieu correspondant, pour simuler hors du monde Bevy.
+    pub fn pad_frame(&self) -> (RocketState, Medium) {
+        let stats = self.stats;
+        let state = RocketState {
+            body: BodyState {
+                position: stats.pad_offset,
+                velocity: self.relative_velocity(),
+                angle: stats.angle,
+                angular_velocity: stats.angular_velocity,
+            },
+            fuel: stats.fuel_percentage * self.vehicle.max_fuel,
+        };
+        let medium = Medium {
+            wind: self.medium.wind - stats.pad_velocity,
+            ground_level: self.medium.ground_level + stats.pad_offset.y - stats.altitude,
+            ..self.medium
+        };
+        (state, medium)
+    }
+}
+
+/// Commande produite par un contrôleur, dans les unités de `RocketControls`.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct ControlCommand {
+    /// Poussée, 0 à 1.
+    pub throttle: f32,
+    /// Rotation demandée, -1 à 1.
+    pub rotation: f32,
+    pub deploy_legs: bool,
+}
+
+/// Pilote classique écrivant dans `RocketControls` à la place de l'agent.
+pub trait Controller: Send + Sync {
+    fn name(&self) -> &'static str;
+    /// Début d'épisode : efface les états internes (intégrales, dérivées).
+    fn reset(&mut self);
+    fn command(&mut self, input: &ControlInput) -> ControlCommand;
+}
+
+/// Pilote sélectionnable depuis la ligne de commande (`--controller`) ou la touche C.
+#[derive(Resource, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum PilotKind {
+    /// Politique apprise par renforcement.
+    #[default]
+    Agent,
+    Pid,
+    Lqr,
+    /// Contrôle prédictif, le simulateur servant de modèle.
+    Mpc,
+    /// Allumage unique à pleine poussée, calculé analytiquement.
+    Hoverslam,
+}
+
+impl PilotKind {
+    pub const ALL: [PilotKind; 5] = [PilotKind::Agent, PilotKind::Pid, PilotKind::Lqr, PilotKind::Mpc, PilotKind::Hoverslam];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            PilotKind::Agent => "rl",
+            PilotKind::Pid => "pid",
+            PilotKind::Lqr => "lqr",
+            PilotKind::Mpc => "mpc",
+            PilotKind::Hoverslam => "hoverslam",
+        }
+    }
+
+    pub fn from_spec(spec: &str) -> Result<Self, String> {
+        Self::ALL
+            .into_iter()
+            .find(|kind| kind.name() == spec.to_lowercase())
+            .ok_or_else(|| format!("contrôleur inconnu: '{}' (rl, pid, lqr, mpc, hoverslam)", spec))
+    }
+}
+
+/// Pilote actif et contrôleurs classiques disponibles.
+#[derive(Resource)]
+pub struct Pilot {
+    pub kind: PilotKind,
+    controllers: Vec<(PilotKind, Box<dyn Controller>)>,
+}
+
+impl Pilot {
+    pub fn is_agent(&self) -> bool {
+        self.kind == PilotKind::Agent
+    }
+
+    pub fn controller_mut(&mut self) -> Option<&mut dyn Controller> {
+        let kind = self.kind;
+        self.controllers
+            .iter_mut()
+            .find(|(candidate, _)| *candidate == kind)
+            .map(|(_, controller)| controller.as_mut() as &mut dyn Controller)
+    }
+
+    /// Passe au pilote suivant de `PilotKind::ALL`.
+    pub fn cycle(&mut self) {
+        let index = PilotKind::ALL.iter().position(|kind| *kind == self.kind).unwrap_or(0);
+        self.kind = PilotKind::ALL[(index + 1) % PilotKind::ALL.len()];
+        if let Some(controller) = self.controller_mut() {
+            controller.reset();
+        }
+    }
+}
+
+pub struct ControlPlugin;
+
+impl Plugin for ControlPlugin {
+    fn build(&self, app: &mut App) {
+        let kind = app.world.get_resource::<PilotKind>().copied().unwrap_or_default();
+        let pid = app.world.get_resource::<CascadedPidSettings>().copied().unwrap_or_default();
+        let lqr = app.world.get_resource::<LqrSettings>().copied().unwrap_or_default();
+        let mpc = app.world.get_resource::<MpcSettings>().copied().unwrap_or_default();
+        let hoverslam = app.world.get_resource::<HoverslamSettings>().copied().unwrap_or_default();
+        let controllers: Vec<(PilotKind, Box<dyn Controller>)> = vec![
+            (PilotKind::Pid, Box::new(CascadedPid::new(pid))),
+            (PilotKind::Lqr, Box::new(Lqr::new(lqr))),
+            (PilotKind::Mpc, Box::new(Mpc::new(mpc))),
+            (PilotKind::Hoverslam, Box::new(Hoverslam::new(hoverslam))),
+        ];
+
+        app.insert_resource(Pilot { kind, controllers })
+           .add_systems(Startup, announce_pilot)
+           .add_systems(Update, (
+               select_pilot,
+               classical_control_system.after(run_estimator).after(select_pilot),
+               reset_controller.run_if(resource_equals(GameState::Restarting)).before(restart_system),
+           ));
+    }
+}
+
+fn announce_pilot(pilot: Res<Pilot>) {
+    println!("🕹️ Pilote: {} (touche C pour changer)", pilot.kind.name());
+}
+
+fn select_pilot(keyboard_input: Res<Input<KeyCode>>, mut pilot: ResMut<Pilot>) {
+    if keyboard_input.just_pressed(KeyCode::C) {
+        pilot.cycle();
+        println!("🕹️ Pilote: {}", pilot.kind.name());
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+fn classical_control_system(
+    mut pilot: ResMut<Pilot>,
+    stats: Res<RocketStats>,
+    sensors: Res<Sensors>,
+    estimator: Res<StateEstimator>,
+    body: Res<CelestialBody>,
+    wind: Res<Wind>,
+    guidance: Res<Guidance>,
+    rocket_query: Query<(&Rocket, &PhysicsBody, &Aerodynamics), With<RocketMainBody>>,
+    mut controls_query: Query<&mut RocketControls>,
+    time: Res<Time>,
+    game_state: Res<GameState>,
+) {
+    if *game_state != GameState::Playing {
+        return;
+    }
+    let (Some(controller), Ok((rocket, physics, aerodynamics))) = (pilot.controller_mut(), rocket_query.get_single()) else {
+        return;
+    };
+
+    let input = ControlInput {
+        stats: policy_view(&stats, &sensors, &estimator),
+        medium: Medium {
+            gravity: body.gravity(),
+            atmosphere: body.atmosphere,
+            wind: wind.velocity,
+            ground_level: GROUND_LEVEL,
+        },
+        vehicle: Vehicle::of(rocket, physics, aerodynamics),
+        reference: guidance.reference(),
+        dt: time.delta_seconds(),
+    };
+    let command = controller.command(&input);
+
+    // Comme pour l'agent, les commandes manuelles restent prioritaires
+    if let Ok(mut controls) = controls_query.get_single_mut()
+        && controls.controlled_by_ai
+    {
+        controls.throttle = command.throttle;
+        controls.rotation = command.rotation;
+        if command.deploy_legs {
+            controls.deploy_legs = true;
+        }
+    }
+}
+
+fn reset_controller(mut pilot: ResMut<Pilot>) {
+    if let Some(controller) = pilot.controller_mut() {
+        controller.reset();
+    }
+}
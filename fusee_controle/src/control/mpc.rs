@@ -0,0 +1,342 @@
+use bevy::prelude::*;
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+use crate::control::{ControlCommand, ControlInput, Controller};
+use crate::game::randomization::standard_normal;
+use crate::game::rocket::LEG_REACH;
+use crate::game::simulation::{step, Medium, RocketState, Vehicle};
+use crate::settings::{parse_value, SettingsFile};
+
+// Écart-type minimal des candidats du CEM, pour ne pas figer l'exploration
+const MIN_CEM_STD: f32 = 0.02;
+// Pas d'intégration maximal des déroulés : un pas de planification entier sous-estime
+// la distance parcourue pendant un freinage
+const MAX_ROLLOUT_DT: f32 = 1.0 / 30.0;
+
+/// Optimiseur de la séquence de commandes.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Optimizer {
+    /// Model Predictive Path Integral : moyenne des perturbations pondérée par `exp(-coût / T)`.
+    #[default]
+    Mppi,
+    /// Cross-Entropy Method : la gaussienne des candidats est réajustée sur les meilleurs.
+    Cem,
+}
+
+impl Optimizer {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Optimizer::Mppi => "mppi",
+            Optimizer::Cem => "cem",
+        }
+    }
+
+    pub fn from_spec(spec: &str) -> Result<Self, String> {
+        match spec.to_lowercase().as_str() {
+            "mppi" => Ok(Optimizer::Mppi),
+            "cem" => Ok(Optimizer::Cem),
+            _ => Err(format!("optimiseur inconnu: '{}' (mppi, cem)", spec)),
+        }
+    }
+}
+
+/// Réglages du contrôle prédictif, section `[mpc]`.
+#[derive(Resource, Clone, Copy, Debug, PartialEq)]
+pub struct MpcSettings {
+    pub optimizer: Optimizer,
+    /// Nombre de pas de l'horizon glissant.
+    pub horizon: usize,
+    /// Durée d'un pas de planification (s), commande tenue pendant ce pas.
+    pub step: f32,
+    /// Séquences candidates simulées par itération.
+    pub samples: usize,
+    /// Itérations par replanification (CEM) ; MPPI en fait une.
+    pub iterations: usize,
+    /// Candidats retenus pour réajuster la gaussienne (CEM).
+    pub elites: usize,
+    /// Température de la pondération (MPPI), dans l'unité du coût.
+    pub temperature: f32,
+    /// Écarts-types des perturbations des commandes.
+    pub throttle_noise: f32,
+    pub rotation_noise: f32,
+    pub seed: u64,
+    /// Vitesse de consigne : horizontale `-position_gain * écart`, bornée ; descente
+    /// `touchdown_speed + descent_gain * hauteur`, bornée.
+    pub position_gain: f32,
+    pub max_horizontal_speed: f32,
+    pub touchdown_speed: f32,
+    pub descent_gain: f32,
+    pub max_descent_speed: f32,
+    /// Poids du coût par seconde de vol.
+    pub velocity_weight: f32,
+    pub angle_weight: f32,
+    pub rate_weight: f32,
+    pub fuel_weight: f32,
+    /// Coût de chaque seconde de vol, pour ne pas repousser indéfiniment le contact.
+    pub time_weight: f32,
+    /// Poids des vitesses résiduelles au contact de la plateforme.
+    pub touchdown_weight: f32,
+    pub legs_height: f32,
+}
+
+impl Default for MpcSettings {
+    fn default() -> Self {
+        Self {
+            optimizer: Optimizer::Mppi,
+            horizon: 40,
+            step: 0.05,
+            samples: 64,
+            iterations: 3,
+            elites: 12,
+            temperature: 10.0,
+            throttle_noise: 0.15,
+            rotation_noise: 0.3,
+            seed: 0,
+            position_gain: 0.5,
+            max_horizontal_speed: 30.0,
+            touchdown_speed: 1.5,
+            descent_gain: 0.4,
+            max_descent_speed: 60.0,
+            velocity_weight: 1.0,
+            angle_weight: 50.0,
+            rate_weight: 5.0,
+            fuel_weight: 1.0,
+            time_weight: 200.0,
+            touchdown_weight: 300.0,
+            legs_height: 120.0,
+        }
+    }
+}
+
+impl MpcSettings {
+    pub fn from_settings(settings: &SettingsFile) -> Result<Self, String> {
+        let mut mpc = Self::default();
+        settings.apply_section("mpc", |key, value| {
+            match key {
+                "optimizer" => mpc.optimizer = Optimizer::from_spec(value)?,
+                "horizon" => mpc.horizon = parse_value(key, value)?,
+                "samples" => mpc.samples = parse_value(key, value)?,
+                "iterations" => mpc.iterations = parse_value(key, value)?,
+                "elites" => mpc.elites = parse_value(key, value)?,
+                "seed" => mpc.seed = parse_value(key, value)?,
+                _ => {
+                    let field = match key {
+                        "step" => &mut mpc.step,
+                        "temperature" => &mut mpc.temperature,
+                        "throttle_noise" => &mut mpc.throttle_noise,
+                        "rotation_noise" => &mut mpc.rotation_noise,
+                        "position_gain" => &mut mpc.position_gain,
+                        "max_horizontal_speed" => &mut mpc.max_horizontal_speed,
+                        "touchdown_speed" => &mut mpc.touchdown_speed,
+                        "descent_gain" => &mut mpc.descent_gain,
+                        "max_descent_speed" => &mut mpc.max_descent_speed,
+                        "velocity_weight" => &mut mpc.velocity_weight,
+                        "angle_weight" => &mut mpc.angle_weight,
+                        "rate_weight" => &mut mpc.rate_weight,
+                        "fuel_weight" => &mut mpc.fuel_weight,
+                        "time_weight" => &mut mpc.time_weight,
+                        "touchdown_weight" => &mut mpc.touchdown_weight,
+                        "legs_height" => &mut mpc.legs_height,
+                        _ => return Ok(false),
+                    };
+                    *field = parse_value(key, value)?;
+                }
+            }
+            Ok(true)
+        })?;
+        if mpc.horizon == 0 || mpc.samples == 0 || mpc.step <= 0.0 {
+            return Err("[mpc]: horizon, samples et step doivent être positifs".to_string());
+        }
+        mpc.elites = mpc.elites.clamp(1, mpc.samples);
+        Ok(mpc)
+    }
+}
+
+/// Commande d'un pas de planification : (poussée, rotation).
+type Action = [f32; 2];
+
+/// Contrôle prédictif par échantillonnage, le simulateur servant de modèle.
+///
+/// À chaque pas de planification, l'état de la fusée est copié dans le repère de la plateforme et
+/// des séquences de commandes candidates sont déroulées avec `simulation::step`, le pas de vol du
+/// jeu. Seule la première commande du plan optimisé est appliquée ; le plan, décalé d'un pas,
+/// initialise l'optimisation suivante.
+pub struct Mpc {
+    pub settings: MpcSettings,
+    rng: StdRng,
+    plan: Vec<Action>,
+    // Temps écoulé depuis la dernière replanification
+    elapsed: f32,
+}
+
+impl Mpc {
+    pub fn new(settings: MpcSettings) -> Self {
+        Self {
+            settings,
+            rng: StdRng::seed_from_u64(settings.seed),
+            plan: Vec::new(),
+            elapsed: 0.0,
+        }
+    }
+
+    /// Coût d'une séquence de commandes depuis `start`. Le déroulé s'arrête au contact des pieds
+    /// avec le pont, où les vitesses résiduelles sont pénalisées.
+    fn rollout_cost(&self, start: &RocketState, medium: &Medium, vehicle: &Vehicle, actions: &[Action]) -> f32 {
+        let s = &self.settings;
+        let substeps = (s.step / MAX_ROLLOUT_DT).ceil().max(1.0);
+        let dt = s.step / substeps;
+        let mut state = *start;
+        let mut cost = 0.0;
+
+        for &[throttle, rotation] in actions {
+            for _ in 0..substeps as usize {
+                state = step(&state, medium, vehicle, throttle, rotation, dt);
+                if state.body.position.y <= LEG_REACH {
+                    break;
+                }
+            }
+            let body = &state.body;
+            let height = body.position.y - LEG_REACH;
+
+            if height <= 0.0 {
+                let excess_descent = (-body.velocity.y - s.touchdown_speed).max(0.0);
+                return cost
+                    + s.touchdown_weight * (excess_descent.powi(2) + body.velocity.x.powi(2))
+                    + s.angle_weight * body.angle.powi(2);
+            }
+
+            let target_velocity = Vec2::new(
+                (-s.position_gain * body.position.x).clamp(-s.max_horizontal_speed, s.max_horizontal_speed),
+                -(s.touchdown_speed + s.descent_gain * height).min(s.max_descent_speed),
+            );
+            cost += s.step * (s.velocity_weight * (body.velocity - target_velocity).length_squared()
+                + s.angle_weight * body.angle.powi(2)
+                + s.rate_weight * body.angular_velocity.powi(2)
+                + s.fuel_weight * throttle
+                + s.time_weight);
+        }
+        cost
+    }
+
+    /// Candidat tiré autour de `mean` ; le premier candidat est `mean` lui-même.
+    fn sample(&mut self, index: usize, mean: &[Action], std: &[Action]) -> Vec<Action> {
+        mean.iter()
+            .zip(std)
+            .map(|(&[throttle, rotation], &[throttle_std, rotation_std])| {
+                if index == 0 {
+                    return [throttle, rotation];
+                }
+                [
+                    (throttle + throttle_std * standard_normal(&mut self.rng)).clamp(0.0, 1.0),
+                    (rotation + rotation_std * standard_normal(&mut self.rng)).clamp(-1.0, 1.0),
+                ]
+            })
+            .collect()
+    }
+
+    fn optimize_mppi(&mut self, start: &RocketState, medium: &Medium, vehicle: &Vehicle) {
+        let noise = vec![[self.settings.throttle_noise, self.settings.rotation_noise]; self.plan.len()];
+        let mean = self.plan.clone();
+        let candidates: Vec<(Vec<Action>, f32)> = (0..self.settings.samples)
+            .map(|index| {
+                let actions = self.sample(index, &mean, &noise);
+                let cost = self.rollout_cost(start, medium, vehicle, &actions);
+                (actions, cost)
+            })
+            .collect();
+
+        let best = candidates.iter().map(|(_, cost)| *cost).fold(f32::INFINITY, f32::min);
+        let weights: Vec<f32> = candidates
+            .iter()
+            .map(|(_, cost)| (-(cost - best) / self.settings.temperature.max(f32::EPSILON)).exp())
+            .collect();
+        let total: f32 = weights.iter().sum();
+
+        for (t, action) in self.plan.iter_mut().enumerate() {
+            *action = candidates.iter().zip(&weights).fold([0.0, 0.0], |sum, ((actions, _), weight)| {
+                [sum[0] + weight * actions[t][0] / total, sum[1] + weight * actions[t][1] / total]
+            });
+        }
+    }
+
+    /// Le plan retenu est le meilleur candidat rencontré : la moyenne des élites peut être
+    /// moins bonne que chacun d'eux près du contact.
+    fn optimize_cem(&mut self, start: &RocketState, medium: &Medium, vehicle: &Vehicle) {
+        let mut std = vec![[self.settings.throttle_noise, self.settings.rotation_noise]; self.plan.len()];
+        let mut mean = self.plan.clone();
+        let mut best_cost = f32::INFINITY;
+
+        for _ in 0..self.settings.iterations.max(1) {
+            let mut candidates: Vec<(Vec<Action>, f32)> = (0..self.settings.samples)
+                .map(|index| {
+                    let actions = self.sample(index, &mean, &std);
+                    let cost = self.rollout_cost(start, medium, vehicle, &actions);
+                    (actions, cost)
+                })
+                .collect();
+            candidates.sort_by(|a, b| a.1.total_cmp(&b.1));
+            if candidates[0].1 < best_cost {
+                best_cost = candidates[0].1;
+                self.plan.clone_from(&candidates[0].0);
+            }
+
+            let elites = &candidates[..self.settings.elites];
+            let count = elites.len() as f32;
+            for (t, (action, deviation)) in mean.iter_mut().zip(std.iter_mut()).enumerate() {
+                for channel in 0..2 {
+                    let average = elites.iter().map(|(actions, _)| actions[t][channel]).sum::<f32>() / count;
+                    let variance = elites.iter().map(|(actions, _)| (actions[t][channel] - average).powi(2)).sum::<f32>() / count;
+                    action[channel] = average;
+                    deviation[channel] = variance.sqrt().max(MIN_CEM_STD);
+                }
+            }
+        }
+    }
+}
+
+impl Controller for Mpc {
+    fn name(&self) -> &'static str {
+        "mpc"
+    }
+
+    fn reset(&mut self) {
+        // Chaque épisode rejoue la même suite de tirages : un départ donné donne le même vol
+        self.rng = StdRng::seed_from_u64(self.settings.seed);
+        self.plan.clear();
+        self.elapsed = 0.0;
+    }
+
+    fn command(&mut self, input: &ControlInput) -> ControlCommand {
+        let settings = self.settings;
+        let stats = input.stats;
+        let deploy_legs = input.height_above_pad() < settings.legs_height;
+
+        if stats.legs_in_contact > 0 {
+            return ControlCommand { throttle: 0.0, rotation: 0.0, deploy_legs };
+        }
+
+        // Replanification à chaque pas de planification ; entre deux, la première commande est
+        // tenue, comme dans les déroulés. Les commandes ajoutées en bout d'horizon et le plan
+        // initial sont le vol stationnaire.
+        let hover = [(-input.medium.gravity / input.vehicle.engine_power).clamp(0.0, 1.0), 0.0];
+        self.elapsed += input.dt;
+        if self.plan.len() != settings.horizon || self.elapsed >= settings.step {
+            if self.plan.len() != settings.horizon {
+                self.plan = vec![hover; settings.horizon];
+            } else {
+                self.plan.remove(0);
+                self.plan.push(hover);
+            }
+            self.elapsed = 0.0;
+
+            let (start, medium) = input.pad_frame();
+            match settings.optimizer {
+                Optimizer::Mppi => self.optimize_mppi(&start, &medium, &input.vehicle),
+                Optimizer::Cem => self.optimize_cem(&start, &medium, &input.vehicle),
+            }
+        }
+
+        let [throttle, rotation] = self.plan[0];
+        ControlCommand { throttle, rotation, deploy_legs }
+    }
+}
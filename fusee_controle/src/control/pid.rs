@@ -0,0 +1,201 @@
+use bevy::prelude::*;
+use crate::control::{ControlCommand, ControlInput, Controller};
+use crate::game::physics::body_axis;
+use crate::settings::{parse_value, SettingsFile};
+
+// Borne du terme intégral, dans l'unité de la sortie
+const INTEGRAL_LIMIT: f32 = 50.0;
+
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct PidGains {
+    pub kp: f32,
+    pub ki: f32,
+    pub kd: f32,
+}
+
+/// Régulateur PID avec anti-emballement de l'intégrale.
+#[derive(Clone, Debug, Default)]
+pub struct Pid {
+    pub gains: PidGains,
+    integral: f32,
+    previous_error: Option<f32>,
+}
+
+impl Pid {
+    pub fn new(gains: PidGains) -> Self {
+        Self { gains, ..default() }
+    }
+
+    pub fn reset(&mut self) {
+        self.integral = 0.0;
+        self.previous_error = None;
+    }
+
+    pub fn update(&mut self, error: f32, dt: f32) -> f32 {
+        if self.gains.ki != 0.0 {
+            let limit = INTEGRAL_LIMIT / self.gains.ki.abs();
+            self.integral = (self.integral + error * dt).clamp(-limit, limit);
+        }
+        // Pas de terme dérivé au premier pas : l'erreur précédente est inconnue
+        let derivative = match self.previous_error {
+            Some(previous) if dt > 0.0 => (error - previous) / dt,
+            _ => 0.0,
+        };
+        self.previous_error = Some(error);
+        self.gains.kp * error + self.gains.ki * self.integral + self.gains.kd * derivative
+    }
+}
+
+/// Réglages du PID en cascade, section `[pid]`.
+#[derive(Resource, Clone, Copy, Debug, PartialEq)]
+pub struct CascadedPidSettings {
+    /// Boucle externe : vitesse horizontale demandée par px d'écart à la plateforme.
+    pub position_kp: f32,
+    pub max_horizontal_speed: f32,
+    /// Vitesse de descente demandée par px de hauteur, en plus de `touchdown_speed`.
+    pub descent_gain: f32,
+    pub max_descent_speed: f32,
+    /// Vitesse de descente visée au contact.
+    pub touchdown_speed: f32,
+    /// Boucle de vitesse : accélération demandée (px/s²) par px/s d'erreur.
+    pub velocity: PidGains,
+    /// Boucle interne : vitesse angulaire demandée (rad/s) par rad d'erreur d'attitude.
+    pub attitude: PidGains,
+    /// Inclinaison maximale demandée à la boucle interne (degrés).
+    pub max_tilt: f32,
+    /// Hauteur de déploiement des jambes au-dessus de la plateforme (px).
+    pub legs_height: f32,
+    /// Poursuit la trajectoire de guidage quand elle est active.
+    pub track_guidance: bool,
+}
+
+impl Default for CascadedPidSettings {
+    fn default() -> Self {
+        Self {
+            position_kp: 0.5,
+            max_horizontal_speed: 30.0,
+            descent_gain: 0.4,
+            max_descent_speed: 60.0,
+            touchdown_speed: 1.5,
+            velocity: PidGains { kp: 2.0, ki: 0.2, kd: 0.0 },
+            attitude: PidGains { kp: 4.0, ki: 0.0, kd: 0.3 },
+            max_tilt: 20.0,
+            legs_height: 120.0,
+            track_guidance: true,
+        }
+    }
+}
+
+impl CascadedPidSettings {
+    pub fn from_settings(settings: &SettingsFile) -> Result<Self, String> {
+        let mut pid = Self::default();
+        settings.apply_section("pid", |key, value| {
+            if key == "track_guidance" {
+                pid.track_guidance = parse_value(key, value)?;
+                return Ok(true);
+            }
+            let field = match key {
+                "position_kp" => &mut pid.position_kp,
+                "max_horizontal_speed" => &mut pid.max_horizontal_speed,
+                "descent_gain" => &mut pid.descent_gain,
+                "max_descent_speed" => &mut pid.max_descent_speed,
+                "touchdown_speed" => &mut pid.touchdown_speed,
+                "velocity_kp" => &mut pid.velocity.kp,
+                "velocity_ki" => &mut pid.velocity.ki,
+                "velocity_kd" => &mut pid.velocity.kd,
+                "attitude_kp" => &mut pid.attitude.kp,
+                "attitude_ki" => &mut pid.attitude.ki,
+                "attitude_kd" => &mut pid.attitude.kd,
+                "max_tilt" => &mut pid.max_tilt,
+                "legs_height" => &mut pid.legs_height,
+                _ => return Ok(false),
+            };
+            *field = parse_value(key, value)?;
+            Ok(true)
+        })?;
+        Ok(pid)
+    }
+}
+
+/// PID en cascade : position → vitesse → accélération → attitude et poussée.
+///
+/// La boucle externe fixe une vitesse relative au pont (vers le centre de la plateforme,
+/// descente ralentie près du sol). La boucle de vitesse en déduit une accélération, compensée de
+/// la gravité, dont la direction donne l'inclinaison visée et la projection sur l'axe la poussée.
+/// La boucle interne suit l'inclinaison avec les propulseurs d'attitude.
+pub struct CascadedPid {
+    pub settings: CascadedPidSettings,
+    horizontal_velocity: Pid,
+    vertical_velocity: Pid,
+    attitude: Pid,
+}
+
+impl CascadedPid {
+    pub fn new(settings: CascadedPidSettings) -> Self {
+        Self {
+            settings,
+            horizontal_velocity: Pid::new(settings.velocity),
+            vertical_velocity: Pid::new(settings.velocity),
+            attitude: Pid::new(settings.attitude),
+        }
+    }
+}
+
+impl Controller for CascadedPid {
+    fn name(&self) -> &'static str {
+        "pid"
+    }
+
+    fn reset(&mut self) {
+        self.horizontal_velocity.reset();
+        self.vertical_velocity.reset();
+        self.attitude.reset();
+    }
+
+    fn command(&mut self, input: &ControlInput) -> ControlCommand {
+        let settings = &self.settings;
+        let stats = input.stats;
+        let height = input.height_above_pad();
+        let deploy_legs = height < settings.legs_height;
+
+        // Posée : moteur coupé, on laisse les jambes se stabiliser
+        if stats.legs_in_contact > 0 {
+            return ControlCommand { throttle: 0.0, rotation: 0.0, deploy_legs };
+        }
+
+        // Boucle externe : vitesses de consigne, et accélération anticipée. Avec une trajectoire
+        // de guidage, la consigne est le point de référence et sa poussée.
+        let (target_velocity, feedforward) = match input.reference.filter(|_| settings.track_guidance) {
+            Some(reference) => (
+                reference.velocity + settings.position_kp * (reference.position - stats.pad_offset),
+                reference.thrust,
+            ),
+            None => (
+                Vec2::new(
+                    (-settings.position_kp * stats.pad_offset.x).clamp(-settings.max_horizontal_speed, settings.max_horizontal_speed),
+                    -(settings.touchdown_speed + settings.descent_gain * height.max(0.0)).min(settings.max_descent_speed),
+                ),
+                Vec2::new(0.0, -input.medium.gravity),
+            ),
+        };
+        let velocity_error = target_velocity - input.relative_velocity();
+
+        // Boucle de vitesse : accélération demandée, gravité compensée
+        let acceleration = feedforward + Vec2::new(
+            self.horizontal_velocity.update(velocity_error.x, input.dt),
+            self.vertical_velocity.update(velocity_error.y, input.dt),
+        );
+
+        // Le nez pointe dans la direction de l'accélération demandée, dans la limite d'inclinaison
+        let max_tilt = settings.max_tilt.to_radians();
+        let target_angle = (-acceleration.x).atan2(acceleration.y.max(f32::EPSILON)).clamp(-max_tilt, max_tilt);
+        let throttle = (acceleration.dot(body_axis(stats.angle)) / input.vehicle.engine_power).clamp(0.0, 1.0);
+
+        // Boucle interne : vitesse angulaire de consigne des propulseurs d'attitude
+        let angle_error = (target_angle - stats.angle).sin().atan2((target_angle - stats.angle).cos());
+        let target_rate = self.attitude.update(angle_error, input.dt);
+        let rotation = (target_rate / input.vehicle.rotation_speed).clamp(-1.0, 1.0);
+
+        ControlCommand { throttle, rotation, deploy_legs }
+    }
+}
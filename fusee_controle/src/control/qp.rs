@@ -0,0 +1,166 @@
+use std::fmt;
+use crate::control::linalg::Matrix;
+
+/// Programme quadratique `min ½ xᵀPx + qᵀx` sous `lower ≤ Ax ≤ upper`. Une égalité a
+/// `lower == upper`, une borne absente est infinie.
+pub struct QuadraticProgram {
+    pub p: Matrix,
+    pub q: Vec<f64>,
+    pub a: Matrix,
+    pub lower: Vec<f64>,
+    pub upper: Vec<f64>,
+}
+
+/// Réglages de l'ADMM (schéma d'OSQP).
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct AdmmSettings {
+    /// Pénalité des contraintes d'inégalité ; multipliée par 1000 pour les égalités.
+    pub rho: f64,
+    /// Régularisation du système linéaire.
+    pub sigma: f64,
+    /// Relaxation, entre 0 et 2.
+    pub alpha: f64,
+    pub max_iterations: usize,
+    /// Tolérance absolue et relative sur les résidus primal et dual.
+    pub tolerance: f64,
+}
+
+impl Default for AdmmSettings {
+    fn default() -> Self {
+        Self {
+            rho: 0.1,
+            sigma: 1e-6,
+            alpha: 1.6,
+            max_iterations: 4000,
+            tolerance: 1e-4,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum QpError {
+    /// Certificat d'infaisabilité primale : aucune solution ne respecte les contraintes.
+    Infeasible,
+    NotConverged,
+    /// Système linéaire singulier.
+    Singular,
+}
+
+impl fmt::Display for QpError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            QpError::Infeasible => write!(f, "contraintes infaisables"),
+            QpError::NotConverged => write!(f, "pas de convergence"),
+            QpError::Singular => write!(f, "système singulier"),
+        }
+    }
+}
+
+// Période de vérification des critères d'arrêt
+const CHECK_INTERVAL: usize = 10;
+
+impl QuadraticProgram {
+    /// Résout par ADMM : le système `P + σI + Aᵀ diag(ρ) A` est inversé une fois, chaque
+    /// itération projette ensuite `Ax` sur les bornes et met à jour les multiplicateurs.
+    pub fn solve(&self, settings: &AdmmSettings) -> Result<Vec<f64>, QpError> {
+        let (n, m) = (self.a.cols, self.a.rows);
+        let rho: Vec<f64> = self
+            .lower
+            .iter()
+            .zip(&self.upper)
+            .map(|(l, u)| if l == u { 1e3 * settings.rho } else { settings.rho })
+            .collect();
+
+        let mut weighted = self.a.clone();
+        for (i, r) in rho.iter().enumerate() {
+            for j in 0..n {
+                weighted[(i, j)] *= r;
+            }
+        }
+        let system = &(&self.p + &Matrix::identity(n).scale(settings.sigma)) + &(&self.a.transpose() * &weighted);
+        let inverse = system.inverse().ok_or(QpError::Singular)?;
+
+        let mut x = vec![0.0; n];
+        let mut z = self.project(&vec![0.0; m]);
+        let mut y = vec![0.0; m];
+        let alpha = settings.alpha;
+
+        for iteration in 1..=settings.max_iterations {
+            let scaled: Vec<f64> = z.iter().zip(&y).zip(&rho).map(|((z, y), r)| r * z - y).collect();
+            let rhs: Vec<f64> = self
+                .a
+                .apply_transpose(&scaled)
+                .iter()
+                .zip(&x)
+                .zip(&self.q)
+                .map(|((a, x), q)| a + settings.sigma * x - q)
+                .collect();
+            let x_tilde = inverse.apply(&rhs);
+            let z_tilde = self.a.apply(&x_tilde);
+
+            let relaxed: Vec<f64> = z_tilde.iter().zip(&z).map(|(zt, z)| alpha * zt + (1.0 - alpha) * z).collect();
+            let shifted: Vec<f64> = relaxed.iter().zip(&y).zip(&rho).map(|((v, y), r)| v + y / r).collect();
+            let z_next = self.project(&shifted);
+            let delta_y: Vec<f64> = relaxed.iter().zip(&z_next).zip(&rho).map(|((v, z), r)| r * (v - z)).collect();
+
+            for (x, xt) in x.iter_mut().zip(&x_tilde) {
+                *x = alpha * xt + (1.0 - alpha) * *x;
+            }
+            for (y, dy) in y.iter_mut().zip(&delta_y) {
+                *y += dy;
+            }
+            z = z_next;
+
+            if iteration % CHECK_INTERVAL != 0 {
+                continue;
+            }
+            if self.converged(&x, &z, &y, settings.tolerance) {
+                return Ok(x);
+            }
+            if self.infeasibility_certificate(&delta_y, settings.tolerance) {
+                return Err(QpError::Infeasible);
+            }
+        }
+        Err(QpError::NotConverged)
+    }
+
+    fn project(&self, v: &[f64]) -> Vec<f64> {
+        v.iter().zip(&self.lower).zip(&self.upper).map(|((v, l), u)| v.max(*l).min(*u)).collect()
+    }
+
+    fn converged(&self, x: &[f64], z: &[f64], y: &[f64], tolerance: f64) -> bool {
+        let ax = self.a.apply(x);
+        let px = self.p.apply(x);
+        let aty = self.a.apply_transpose(y);
+
+        let primal = ax.iter().zip(z).map(|(a, z)| (a - z).abs()).fold(0.0, f64::max);
+        let dual = px.iter().zip(&self.q).zip(&aty).map(|((p, q), a)| (p + q + a).abs()).fold(0.0, f64::max);
+        let primal_scale = max_abs(&ax).max(max_abs(z));
+        let dual_scale = max_abs(&px).max(max_abs(&aty)).max(max_abs(&self.q));
+
+        primal <= tolerance * (1.0 + primal_scale) && dual <= tolerance * (1.0 + dual_scale)
+    }
+
+    /// `δy` certifie l'infaisabilité si `Aᵀδy ≈ 0` et `uᵀδy⁺ + lᵀδy⁻ < 0`.
+    fn infeasibility_certificate(&self, delta_y: &[f64], tolerance: f64) -> bool {
+        let norm = max_abs(delta_y);
+        if norm <= f64::EPSILON || max_abs(&self.a.apply_transpose(delta_y)) > tolerance * norm {
+            return false;
+        }
+        let mut support = 0.0;
+        for ((dy, l), u) in delta_y.iter().zip(&self.lower).zip(&self.upper) {
+            let bound = if *dy > 0.0 { u } else { l };
+            if dy.abs() > tolerance * norm {
+                if !bound.is_finite() {
+                    return false;
+                }
+                support += dy * bound;
+            }
+        }
+        support < -tolerance * norm
+    }
+}
+
+fn max_abs(v: &[f64]) -> f64 {
+    v.iter().fold(0.0, |max, v| max.max(v.abs()))
+}
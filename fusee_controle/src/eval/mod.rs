@@ -0,0 +1,306 @@
+#[cfg(test)]
+mod regression;
+pub mod report;
+pub mod scenario;
+pub mod stats;
+
+use std::fs;
+use std::path::PathBuf;
+use std::time::Duration;
+use bevy::app::AppExit;
+use bevy::ecs::schedule::ExecutorKind;
+use bevy::prelude::*;
+use bevy::time::TimeUpdateStrategy;
+use bevy::window::{PresentMode, PrimaryWindow};
+use crate::control::Pilot;
+use crate::game::curriculum::Curriculum;
+use crate::game::episode::{EpisodeClock, EpisodeRng};
+use crate::game::outcome::Touchdown;
+use crate::game::planet::{BodySelection, CelestialBody};
+use crate::game::randomization::{DomainRandomization, EpisodeParameters};
+use crate::game::rocket::StartOverride;
+use crate::game::sensors::{SensorModel, Sensors};
+use crate::game::GameState;
+use crate::rl_agent::checkpoint::RunDirectory;
+use crate::rl_agent::RLTraining;
+use crate::settings::{parse_value, SettingsFile};
+use report::{EpisodeRecord, EvalReport, Scorecard};
+use scenario::Scenario;
+
+/// Réglages de l'évaluation, section `[eval]`.
+#[derive(Resource, Clone, Debug, PartialEq)]
+pub struct EvalSettings {
+    /// Niveau du programme d'apprentissage, figé pendant l'évaluation (nom ou numéro).
+    pub stage: String,
+    /// Pas de temps fixe (s) : les vols ne dépendent plus de la cadence d'affichage.
+    pub step: f32,
+    /// Niveau de confiance des intervalles du taux de succès.
+    pub confidence: f64,
+    /// Fichier du rapport JSON ; par défaut `eval.json` (`suite.json` pour la suite) dans le dossier de la session.
+    pub output: Option<PathBuf>,
+}
+
+impl Default for EvalSettings {
+    fn default() -> Self {
+        Self {
+            stage: "standard".to_string(),
+            step: 1.0 / 60.0,
+            confidence: 0.95,
+            output: None,
+        }
+    }
+}
+
+impl EvalSettings {
+    pub fn from_settings(settings: &SettingsFile) -> Result<Self, String> {
+        let mut eval = Self::default();
+        settings.apply_section("eval", |key, value| {
+            match key {
+                "stage" => eval.stage = value.to_string(),
+                "step" => eval.step = parse_value(key, value)?,
+                "confidence" => eval.confidence = parse_value(key, value)?,
+                "output" => eval.output = Some(PathBuf::from(value)),
+                _ => return Ok(false),
+            }
+            Ok(true)
+        })?;
+        if eval.step <= 0.0 {
+            return Err(format!("step doit être positif: {}", eval.step));
+        }
+        if !(0.0..1.0).contains(&eval.confidence) {
+            return Err(format!("confidence doit être entre 0 et 1: {}", eval.confidence));
+        }
+        Ok(eval)
+    }
+}
+
+/// Série d'épisodes évalués : une liste de graines, dans un scénario de la suite ou dans les
+/// conditions de la configuration.
+#[derive(Clone, Debug, Default)]
+pub struct EvalRun {
+    pub scenario: Option<Scenario>,
+    pub seeds: Vec<u64>,
+    pub records: Vec<EpisodeRecord>,
+}
+
+/// Évaluation en cours (`--eval` ou `--suite`) : une graine par épisode, série après série.
+/// Sans série, le jeu tourne normalement.
+#[derive(Resource, Clone, Debug, Default)]
+pub struct Evaluation {
+    pub runs: Vec<EvalRun>,
+    // Série et épisode en vol
+    run: usize,
+    next: usize,
+    /// Rapports des séries terminées.
+    pub reports: Vec<EvalReport>,
+}
+
+impl Evaluation {
+    /// Graines séparées par des virgules, ou intervalles `début..fin` (fin exclue) : `0..100`, `3,7,0..5`.
+    pub fn from_spec(spec: &str) -> Result<Self, String> {
+        let number = |s: &str| s.trim().parse::<u64>().map_err(|_| format!("graine invalide '{}' dans '{}'", s, spec));
+        let mut seeds = Vec::new();
+
+        for item in spec.split(',') {
+            match item.split_once("..") {
+                Some((start, end)) => seeds.extend(number(start)?..number(end)?),
+                None => seeds.push(number(item)?),
+            }
+        }
+        if seeds.is_empty() {
+            return Err(format!("aucune graine dans '{}'", spec));
+        }
+        Ok(Self {
+            runs: vec![EvalRun { seeds, ..default() }],
+            ..default()
+        })
+    }
+
+    /// Scénarios de la suite de référence, voir `Scenario::select`.
+    pub fn suite(spec: &str) -> Result<Self, String> {
+        let runs = Scenario::select(spec)?
+            .into_iter()
+            .map(|scenario| EvalRun { seeds: scenario.seeds.clone(), scenario: Some(scenario), ..default() })
+            .collect();
+        Ok(Self { runs, ..default() })
+    }
+
+    pub fn is_active(&self) -> bool {
+        !self.runs.is_empty()
+    }
+
+    pub fn is_suite(&self) -> bool {
+        self.runs.iter().any(|run| run.scenario.is_some())
+    }
+
+    pub fn is_finished(&self) -> bool {
+        self.run >= self.runs.len()
+    }
+
+    pub fn current_run(&self) -> Option<&EvalRun> {
+        self.runs.get(self.run)
+    }
+
+    pub fn current_seed(&self) -> Option<u64> {
+        self.current_run().and_then(|run| run.seeds.get(self.next)).copied()
+    }
+
+    pub fn episode_count(&self) -> usize {
+        self.runs.iter().map(|run| run.seeds.len()).sum()
+    }
+}
+
+/// Évaluation déterministe d'un pilote : pas de temps fixe, exécution séquentielle des systèmes,
+/// tirages de chaque épisode issus de sa graine, politique figée. Le rapport est écrit à la fin.
+pub struct EvalPlugin;
+
+impl Plugin for EvalPlugin {
+    fn build(&self, app: &mut App) {
+        if !app.world.get_resource::<Evaluation>().is_some_and(Evaluation::is_active) {
+            return;
+        }
+        let step = app.world.get_resource::<EvalSettings>().cloned().unwrap_or_default().step;
+
+        // L'agent pilote sans explorer ni apprendre
+        if let Some(mut training) = app.world.get_resource_mut::<RLTraining>() {
+            training.training = false;
+        }
+        // L'ordre des systèmes ne varie plus d'une image à l'autre
+        app.edit_schedule(Update, |schedule| {
+            schedule.set_executor_kind(ExecutorKind::SingleThreaded);
+        });
+
+        app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_secs_f32(step)))
+           .add_systems(Startup, begin_evaluation)
+           .add_systems(PreUpdate, seed_episode.run_if(resource_equals(GameState::Restarting)))
+           .add_systems(Update, record_episode);
+    }
+}
+
+fn begin_evaluation(
+    mut game_state: ResMut<GameState>,
+    evaluation: Res<Evaluation>,
+    settings: Res<EvalSettings>,
+    pilot: Res<Pilot>,
+    curriculum: Res<Curriculum>,
+    mut windows: Query<&mut Window, With<PrimaryWindow>>,
+) {
+    if evaluation.is_suite() {
+        let names: Vec<&str> = evaluation.runs.iter().filter_map(|run| run.scenario.as_ref()).map(|s| s.name).collect();
+        println!("🧪 Suite de {} scénarios ({}), {} épisodes: pilote {}, niveau {}, pas de {:.1} ms",
+                 names.len(), names.join(", "), evaluation.episode_count(), pilot.kind.name(),
+                 curriculum.stage().name, settings.step * 1000.0);
+    } else {
+        println!("🧪 Évaluation sur {} graines: pilote {}, niveau {}, pas de {:.1} ms",
+                 evaluation.episode_count(), pilot.kind.name(), curriculum.stage().name, settings.step * 1000.0);
+    }
+
+    // Le temps est simulé : inutile d'attendre la synchronisation de l'écran
+    if let Ok(mut window) = windows.get_single_mut() {
+        window.present_mode = PresentMode::AutoNoVsync;
+    }
+    // La fusée du démarrage n'a pas de graine : le premier épisode repart de zéro
+    *game_state = GameState::Restarting;
+}
+
+// Avant les tirages de l'épisode (corps, paramètres, cible, départ, rafales)
+#[allow(clippy::too_many_arguments)]
+fn seed_episode(
+    evaluation: Res<Evaluation>,
+    mut rng: ResMut<EpisodeRng>,
+    model: Res<SensorModel>,
+    mut sensors: ResMut<Sensors>,
+    mut start: ResMut<StartOverride>,
+    mut bodies: ResMut<BodySelection>,
+    mut body: ResMut<CelestialBody>,
+    mut randomization: ResMut<DomainRandomization>,
+    mut parameters: ResMut<EpisodeParameters>,
+) {
+    let (Some(run), Some(seed)) = (evaluation.current_run(), evaluation.current_seed()) else {
+        return;
+    };
+    *rng = EpisodeRng::seeded(seed);
+    sensors.reseed(model.seed.wrapping_add(seed));
+
+    // Le scénario remplace le départ tiré, le corps céleste et les paramètres physiques
+    let Some(scenario) = &run.scenario else {
+        return;
+    };
+    if evaluation.next == 0 {
+        println!("🧪 Scénario {}: {} ({} graines)", scenario.name, scenario.description, run.seeds.len());
+    }
+    start.set_if_neq(StartOverride { conditions: Some(scenario.start), spread: scenario.spread });
+    *bodies = BodySelection::Fixed(scenario.body.clone());
+    body.set_if_neq(scenario.body.clone());
+    randomization.set_if_neq(DomainRandomization::fixed(&scenario.parameters));
+    parameters.set_if_neq(scenario.parameters);
+}
+
+#[allow(clippy::too_many_arguments)]
+fn record_episode(
+    mut evaluation: ResMut<Evaluation>,
+    mut touchdowns: EventReader<Touchdown>,
+    mut game_state: ResMut<GameState>,
+    clock: Res<EpisodeClock>,
+    settings: Res<EvalSettings>,
+    pilot: Res<Pilot>,
+    curriculum: Res<Curriculum>,
+    bodies: Res<BodySelection>,
+    run_directory: Res<RunDirectory>,
+    mut exit: EventWriter<AppExit>,
+) {
+    let mut recorded = false;
+    for touchdown in touchdowns.read() {
+        let Some(seed) = evaluation.current_seed() else {
+            continue;
+        };
+        let record = EpisodeRecord::new(seed, touchdown, clock.elapsed);
+        let evaluation = &mut *evaluation;
+        let run = &mut evaluation.runs[evaluation.run];
+        println!("🧪 Épisode {}/{} (graine {}): {}{}", evaluation.next + 1, run.seeds.len(), seed,
+                 record.outcome.key(), if record.reasons.is_empty() { String::new() } else { format!(" ({})", record.reasons.join(", ")) });
+        run.records.push(record);
+        evaluation.next += 1;
+        recorded = true;
+
+        // Série terminée : son rapport, puis la suivante
+        if evaluation.next >= run.seeds.len() {
+            let report = EvalReport {
+                scenario: run.scenario.as_ref().map(|scenario| scenario.name.to_string()),
+                pilot: pilot.kind.name().to_string(),
+                stage: curriculum.stage().name.clone(),
+                body: run.scenario.as_ref().map_or_else(|| bodies.describe(), |scenario| scenario.body.name.clone()),
+                confidence: settings.confidence,
+                records: std::mem::take(&mut run.records),
+            };
+            print!("{}", report.table());
+            evaluation.reports.push(report);
+            evaluation.run += 1;
+            evaluation.next = 0;
+        }
+    }
+    if !recorded {
+        return;
+    }
+    if !evaluation.is_finished() {
+        // Épisode suivant sans attendre le redémarrage automatique
+        *game_state = GameState::Restarting;
+        return;
+    }
+
+    let (file, json) = if evaluation.is_suite() {
+        let scorecard = Scorecard { reports: std::mem::take(&mut evaluation.reports) };
+        print!("{}", scorecard.table());
+        ("suite.json", scorecard.to_json())
+    } else {
+        ("eval.json", evaluation.reports.first().map(EvalReport::to_json).unwrap_or_default())
+    };
+
+    let path = settings.output.clone().unwrap_or_else(|| run_directory.path.join(file));
+    let written = path.parent().map_or(Ok(()), fs::create_dir_all).and_then(|()| fs::write(&path, json));
+    match written {
+        Ok(()) => println!("📄 Rapport d'évaluation: {}", path.display()),
+        Err(e) => eprintln!("⚠️ Échec d'écriture du rapport {}: {}", path.display(), e),
+    }
+    exit.send(AppExit);
+}
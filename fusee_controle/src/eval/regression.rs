@@ -0,0 +1,300 @@
+// Tests de non-régression des pilotes, sans fenêtre ni monde Bevy : des contrôleurs déterministes
+// volent les scénarios de référence avec `simulation::step`, le pas de vol du jeu, puis se posent
+// avec `contact::contact_step` et sont jugés par `outcome::judge_touchdown`, comme dans le jeu.
+// Le retard des actionneurs, les rafales et les capteurs ne sont pas simulés.
+// Les instantanés de trajectoire se régénèrent avec `UPDATE_SNAPSHOTS=1 cargo test`.
+
+use std::fmt::Write as _;
+use std::fs;
+use std::path::PathBuf;
+use bevy::prelude::*;
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+use crate::control::hoverslam::{check_landing, Hoverslam, HoverslamSettings};
+use crate::control::pid::{CascadedPid, CascadedPidSettings};
+use crate::control::{ControlInput, Controller};
+use crate::eval::report::{EpisodeRecord, EvalReport};
+use crate::eval::scenario::Scenario;
+use crate::game::contact::{contact_step, ContactSettings, GroundContact, Surface};
+use crate::game::criteria::LandingCriteria;
+use crate::game::environment::{PadLayout, GROUND_LEVEL};
+use crate::game::episode::EpisodeLimits;
+use crate::game::outcome::{judge_touchdown, ContactReading, CrashReason, Touchdown, TouchdownMetrics, TouchdownOutcome};
+use crate::game::rocket::{episode_aerodynamics, hull_shape, leg_feet, rocket_body, Rocket, RocketStats};
+use crate::game::simulation::{step, BodyState, Medium, RocketState, Vehicle};
+
+const STEP: f32 = 1.0 / 60.0;
+// Un point d'instantané toutes les 0,5 s
+const SNAPSHOT_INTERVAL: usize = 30;
+
+struct Flight {
+    touchdown: Touchdown,
+    duration: f32,
+    /// États à chaque pas, dans le repère de la plateforme.
+    trajectory: Vec<RocketState>,
+}
+
+fn scenario(name: &str) -> Scenario {
+    Scenario::select(name).unwrap().remove(0)
+}
+
+// Vol d'un épisode du scénario, départ perturbé par la graine comme dans le jeu
+fn fly(controller: &mut dyn Controller, scenario: &Scenario, seed: u64) -> Flight {
+    let criteria = LandingCriteria::default();
+    let contact_settings = ContactSettings::default();
+    let limits = EpisodeLimits::default();
+    let pad = Vec2::new(0.0, GROUND_LEVEL);
+    let half_width = criteria.zone_half_width(PadLayout::default().pads[0].width);
+    let parameters = scenario.parameters;
+
+    let start = scenario.start.perturbed(&scenario.spread, &mut StdRng::seed_from_u64(seed));
+    let rocket = Rocket::for_episode(&parameters);
+    let physics = rocket_body(start.velocity, start.angular_velocity);
+    let mut vehicle = Vehicle::of(&rocket, &physics, &episode_aerodynamics(&parameters));
+    let hull = hull_shape();
+    // Les pilotes connaissent le milieu nominal ; la physique applique la gravité de l'épisode
+    let nominal = Medium {
+        gravity: scenario.body.gravity(),
+        atmosphere: scenario.body.atmosphere,
+        wind: Vec2::new(scenario.body.wind.mean_speed, 0.0),
+        ground_level: GROUND_LEVEL,
+    };
+    let medium = Medium { gravity: nominal.gravity * parameters.gravity_scale, ..nominal };
+
+    let mut state = RocketState {
+        body: BodyState {
+            position: pad + start.offset,
+            velocity: start.velocity,
+            angle: start.rotation,
+            angular_velocity: start.angular_velocity,
+        },
+        fuel: rocket.fuel,
+    };
+    let mut contact = GroundContact::default();
+    let mut legs_deployed = false;
+    let mut trajectory = Vec::new();
+    let mut time = 0.0;
+    controller.reset();
+
+    let touchdown = loop {
+        let offset = state.body.position - pad;
+        trajectory.push(RocketState { body: BodyState { position: offset, ..state.body }, ..state });
+
+        if let Some(first_contact) = contact.touchdown {
+            let reading = ContactReading {
+                impact_velocity: first_contact.velocity,
+                tilt: state.body.angle.sin().atan2(state.body.angle.cos()),
+                pad_offset: offset.x,
+                legs_deployed,
+                hull_contact: contact.hull_contact,
+                splashdown: contact.splashdown,
+                settled_time: contact.settled_time,
+                fuel: state.fuel,
+                max_fuel: rocket.max_fuel,
+            };
+            if let Some(touchdown) = judge_touchdown(&reading, &criteria, half_width, contact_settings.tip_over_angle, parameters) {
+                break touchdown;
+            }
+        }
+        if time >= limits.max_duration || !limits.contains(state.body.position) {
+            let metrics = TouchdownMetrics { fuel_remaining: state.fuel / rocket.max_fuel, ..default() };
+            let (outcome, mut reasons) = if time >= limits.max_duration {
+                (TouchdownOutcome::Truncated, vec![CrashReason::Timeout])
+            } else {
+                (TouchdownOutcome::Crashed, vec![CrashReason::OutOfBounds])
+            };
+            if outcome == TouchdownOutcome::Crashed && state.fuel <= 0.0 {
+                reasons.push(CrashReason::FuelExhausted);
+            }
+            break Touchdown { outcome, reasons, metrics, parameters };
+        }
+
+        let stats = flight_stats(&state, pad, &rocket, half_width, legs_deployed, contact.legs_in_contact);
+        let command = controller.command(&ControlInput { stats: &stats, medium: nominal, vehicle, reference: None, dt: STEP });
+        // Déploiement unique, qui ajoute la traînée du train
+        if command.deploy_legs && !legs_deployed {
+            legs_deployed = true;
+            vehicle.aerodynamics.gear_deployed = true;
+        }
+        // Réaction du sol puis pas de vol, dans l'ordre des systèmes du jeu
+        let feet = if legs_deployed { leg_feet().to_vec() } else { Vec::new() };
+        contact_step(&contact_settings, &mut state.body, vehicle.radius_of_gyration, &hull, &feet, &mut contact,
+                     |_| (GROUND_LEVEL, Surface::Ground), STEP);
+        state = step(&state, &medium, &vehicle, command.throttle, command.rotation, STEP);
+        time += STEP;
+    };
+    Flight { touchdown, duration: time, trajectory }
+}
+
+// Mesures vues par les pilotes, comme `update_rocket_stats` les remplit
+fn flight_stats(state: &RocketState, pad: Vec2, rocket: &Rocket, half_width: f32, legs_deployed: bool, legs_in_contact: usize) -> RocketStats {
+    let body = state.body;
+    let pad_offset = body.position - pad;
+    RocketStats {
+        altitude: body.position.y,
+        vertical_speed: body.velocity.y,
+        horizontal_speed: body.velocity.x,
+        angle: body.angle.sin().atan2(body.angle.cos()),
+        angular_velocity: body.angular_velocity,
+        specific_force: Vec2::ZERO,
+        fuel_percentage: state.fuel / rocket.max_fuel,
+        distance_to_target: pad_offset.length(),
+        pad_offset,
+        pad_velocity: Vec2::ZERO,
+        pad_tilt: 0.0,
+        zone_half_width: half_width,
+        height_above_ground: pad_offset.y,
+        landing_score: 0.0,
+        total_landings: 0,
+        total_crashes: 0,
+        consecutive_successes: 0,
+        legs_deployed,
+        legs_in_contact,
+    }
+}
+
+fn evaluate(controller: &mut dyn Controller, scenario: &Scenario) -> EvalReport {
+    let records = scenario
+        .seeds
+        .iter()
+        .map(|&seed| {
+            let flight = fly(controller, scenario, seed);
+            EpisodeRecord::new(seed, &flight.touchdown, flight.duration)
+        })
+        .collect();
+    EvalReport {
+        scenario: Some(scenario.name.to_string()),
+        pilot: controller.name().to_string(),
+        stage: "standard".to_string(),
+        body: scenario.body.name.clone(),
+        confidence: 0.95,
+        records,
+    }
+}
+
+// Taux de succès minimaux par scénario, sur les graines fixes du scénario. Les seuils sont un peu
+// sous les taux mesurés : les vols sont déterministes, une baisse signale un changement de comportement.
+fn assert_success_rates(controller: &mut dyn Controller, minimums: &[(&str, f64)]) {
+    for &(name, minimum) in minimums {
+        let report = evaluate(controller, &scenario(name));
+        assert!(
+            report.success_rate() >= minimum,
+            "{} sur {}: succès {:.0}% < {:.0}%\n{}",
+            controller.name(), name, report.success_rate() * 100.0, minimum * 100.0, report.table()
+        );
+    }
+}
+
+// Un point toutes les 0,5 s et le dernier état, puis l'issue
+fn snapshot(flight: &Flight) -> String {
+    let mut out = String::from("#      t         x         y        vx        vy   angle   omega    fuel\n");
+    let last = flight.trajectory.len() - 1;
+    for (index, state) in flight.trajectory.iter().enumerate() {
+        if index % SNAPSHOT_INTERVAL != 0 && index != last {
+            continue;
+        }
+        let body = state.body;
+        let _ = writeln!(out, "{:8.3} {:9.3} {:9.3} {:9.3} {:9.3} {:7.4} {:7.4} {:7.3}",
+                         index as f32 * STEP, body.position.x, body.position.y, body.velocity.x, body.velocity.y,
+                         body.angle, body.angular_velocity, state.fuel);
+    }
+    let _ = write!(out, "# {}", flight.touchdown.outcome.key());
+    for reason in &flight.touchdown.reasons {
+        let _ = write!(out, " {}", reason.key());
+    }
+    out.push('\n');
+    out
+}
+
+fn assert_snapshot(name: &str, actual: &str) {
+    let path = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("tests").join("snapshots").join(format!("{}.txt", name));
+    if std::env::var_os("UPDATE_SNAPSHOTS").is_some() {
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(&path, actual).unwrap();
+        return;
+    }
+    let expected = fs::read_to_string(&path)
+        .unwrap_or_else(|e| panic!("instantané {} illisible ({}) : UPDATE_SNAPSHOTS=1 cargo test pour le créer", path.display(), e));
+    assert_eq!(actual, expected, "trajectoire différente de l'instantané {}", path.display());
+}
+
+#[test]
+fn hoverslam_meets_minimum_success_rates() {
+    // La dérive de lateral_offset dépasse ce que les corrections pendant la chute peuvent rattraper :
+    // la fusée se pose hors de la zone, ce scénario n'est pas exigé
+    let mut hoverslam = Hoverslam::new(HoverslamSettings::default());
+    assert_success_rates(&mut hoverslam, &[
+        ("nominal", 0.95),
+        ("crosswind", 0.95),
+        ("low_fuel", 0.95),
+        ("tilted", 0.95),
+        ("spinning", 0.95),
+        ("heavy", 0.95),
+        ("moon", 0.95),
+    ]);
+}
+
+#[test]
+fn pid_meets_minimum_success_rates() {
+    // 20 % de carburant ne suffisent pas au PID, qui freine tôt : low_fuel n'est pas exigé
+    let mut pid = CascadedPid::new(CascadedPidSettings::default());
+    assert_success_rates(&mut pid, &[
+        ("nominal", 0.95),
+        ("lateral_offset", 0.95),
+        ("crosswind", 0.95),
+        ("tilted", 0.95),
+        ("spinning", 0.95),
+        ("heavy", 0.95),
+        ("moon", 0.95),
+    ]);
+}
+
+#[test]
+fn flights_are_reproducible() {
+    let scenario = scenario("tilted");
+    let mut pid = CascadedPid::new(CascadedPidSettings::default());
+    let first = fly(&mut pid, &scenario, 3);
+    let second = fly(&mut pid, &scenario, 3);
+    assert_eq!(first.trajectory, second.trajectory);
+    assert_ne!(first.trajectory, fly(&mut pid, &scenario, 4).trajectory);
+}
+
+#[test]
+fn hoverslam_nominal_trajectory_matches_snapshot() {
+    let mut hoverslam = Hoverslam::new(HoverslamSettings::default());
+    assert_snapshot("hoverslam_nominal", &snapshot(&fly(&mut hoverslam, &scenario("nominal"), 0)));
+}
+
+#[test]
+fn pid_lateral_offset_trajectory_matches_snapshot() {
+    let mut pid = CascadedPid::new(CascadedPidSettings::default());
+    assert_snapshot("pid_lateral_offset", &snapshot(&fly(&mut pid, &scenario("lateral_offset"), 0)));
+}
+
+#[test]
+fn hoverslam_spawn_check_agrees_with_flights() {
+    let criteria = LandingCriteria::default();
+    let half_width = criteria.zone_half_width(PadLayout::default().pads[0].width);
+    let mut hoverslam = Hoverslam::new(HoverslamSettings::default());
+    for name in ["nominal", "lateral_offset"] {
+        let scenario = scenario(name);
+        for seed in 0..3 {
+            let flight = fly(&mut hoverslam, &scenario, seed);
+            // Premier état du vol, déjà dans le repère de la plateforme
+            let start = flight.trajectory[0];
+            let rocket = Rocket::for_episode(&scenario.parameters);
+            let physics = rocket_body(start.body.velocity, start.body.angular_velocity);
+            let vehicle = Vehicle::of(&rocket, &physics, &episode_aerodynamics(&scenario.parameters));
+            let medium = Medium {
+                gravity: scenario.body.gravity() * scenario.parameters.gravity_scale,
+                atmosphere: scenario.body.atmosphere,
+                wind: Vec2::new(scenario.body.wind.mean_speed, 0.0),
+                ground_level: 0.0,
+            };
+            let check = check_landing(&start, &medium, &vehicle, &HoverslamSettings::default(), &criteria,
+                                      &ContactSettings::default(), 0.0, half_width);
+            assert_eq!(check.landable, flight.touchdown.outcome == TouchdownOutcome::Landed,
+                       "{} graine {}: {:?}", name, seed, flight.touchdown.reasons);
+        }
+    }
+}
@@ -0,0 +1,302 @@
+use std::collections::BTreeMap;
+use std::fmt::Write as _;
+use crate::eval::stats::{wilson_interval, Summary};
+use crate::game::outcome::{CrashReason, Touchdown, TouchdownMetrics, TouchdownOutcome};
+
+/// Issue et mesures d'un épisode évalué.
+#[derive(Clone, Debug, PartialEq)]
+pub struct EpisodeRecord {
+    pub seed: u64,
+    pub outcome: TouchdownOutcome,
+    pub reasons: Vec<&'static str>,
+    pub metrics: TouchdownMetrics,
+    /// Faux si l'épisode s'est terminé en vol : les mesures du posé n'ont alors pas de sens.
+    pub touched_down: bool,
+    /// Carburant consommé, fraction du réservoir.
+    pub fuel_used: f32,
+    /// Temps de vol (s).
+    pub duration: f32,
+}
+
+impl EpisodeRecord {
+    pub fn new(seed: u64, touchdown: &Touchdown, duration: f32) -> Self {
+        let in_flight = touchdown
+            .reasons
+            .iter()
+            .any(|reason| matches!(reason, CrashReason::OutOfBounds | CrashReason::Timeout));
+        Self {
+            seed,
+            outcome: touchdown.outcome,
+            reasons: touchdown.reasons.iter().map(CrashReason::key).collect(),
+            metrics: touchdown.metrics,
+            touched_down: !in_flight,
+            fuel_used: (touchdown.parameters.initial_fuel - touchdown.metrics.fuel_remaining).max(0.0),
+            duration,
+        }
+    }
+
+    pub fn landed(&self) -> bool {
+        self.outcome == TouchdownOutcome::Landed
+    }
+}
+
+// Grandeurs résumées : clé du JSON, libellé du tableau et mesure, absente hors posé
+type Metric = (&'static str, &'static str, fn(&EpisodeRecord) -> Option<f32>);
+
+const METRICS: [Metric; 7] = [
+    ("vertical_speed", "Vitesse verticale (m/s)", |r| r.touched_down.then_some(r.metrics.vertical_speed)),
+    ("horizontal_speed", "Vitesse horizontale (m/s)", |r| r.touched_down.then_some(r.metrics.horizontal_speed)),
+    ("impact_speed", "Vitesse d'impact (m/s)", |r| r.touched_down.then_some(r.metrics.impact_speed)),
+    ("tilt", "Inclinaison (°)", |r| r.touched_down.then_some(90.0 - r.metrics.angle)),
+    ("pad_offset", "Écart au centre (px)", |r| r.touched_down.then_some(r.metrics.pad_offset.abs())),
+    ("fuel_used", "Carburant consommé (%)", |r| Some(r.fuel_used * 100.0)),
+    ("duration", "Durée (s)", |r| Some(r.duration)),
+];
+
+/// Rapport d'évaluation : taux de succès, distributions des mesures et raisons d'échec.
+#[derive(Clone, Debug, PartialEq)]
+pub struct EvalReport {
+    /// Scénario de la suite de référence, absent pour une liste de graines.
+    pub scenario: Option<String>,
+    pub pilot: String,
+    pub stage: String,
+    pub body: String,
+    /// Niveau de confiance des intervalles (0.95).
+    pub confidence: f64,
+    pub records: Vec<EpisodeRecord>,
+}
+
+impl EvalReport {
+    pub fn landed(&self) -> usize {
+        self.records.iter().filter(|record| record.landed()).count()
+    }
+
+    pub fn success_rate(&self) -> f64 {
+        if self.records.is_empty() {
+            return 0.0;
+        }
+        self.landed() as f64 / self.records.len() as f64
+    }
+
+    pub fn success_interval(&self) -> (f64, f64) {
+        wilson_interval(self.landed(), self.records.len(), self.confidence)
+    }
+
+    pub fn outcome_count(&self, outcome: TouchdownOutcome) -> usize {
+        self.records.iter().filter(|record| record.outcome == outcome).count()
+    }
+
+    /// Nombre d'épisodes par raison d'échec ; un épisode peut en cumuler plusieurs.
+    pub fn crash_reasons(&self) -> BTreeMap<&'static str, usize> {
+        let mut counts = BTreeMap::new();
+        for reason in self.records.iter().flat_map(|record| &record.reasons) {
+            *counts.entry(*reason).or_default() += 1;
+        }
+        counts
+    }
+
+    /// Résumé de chaque grandeur : clé, libellé et statistiques.
+    pub fn summaries(&self) -> Vec<(&'static str, &'static str, Summary)> {
+        METRICS
+            .iter()
+            .map(|(key, label, measure)| {
+                let values = self.records.iter().filter_map(measure).map(f64::from);
+                (*key, *label, Summary::of(values))
+            })
+            .collect()
+    }
+
+    pub fn summary(&self, key: &str) -> Option<Summary> {
+        self.summaries()
+            .into_iter()
+            .find(|(candidate, _, summary)| *candidate == key && summary.count > 0)
+            .map(|(_, _, summary)| summary)
+    }
+
+    /// Tableau lisible pour la console.
+    pub fn table(&self) -> String {
+        let mut out = String::new();
+        let (lower, upper) = self.success_interval();
+        let scenario = self.scenario.as_deref().map_or(String::new(), |name| format!(" {}", name));
+        let _ = writeln!(out, "📊 Évaluation{}: pilote {}, niveau {}, {} - {} épisodes",
+                         scenario, self.pilot, self.stage, self.body, self.records.len());
+        let _ = writeln!(out, "   Succès: {}/{} = {:.1}% (IC {:.0}%: {:.1}% - {:.1}%)",
+                         self.landed(), self.records.len(), self.success_rate() * 100.0,
+                         self.confidence * 100.0, lower * 100.0, upper * 100.0);
+        let _ = writeln!(out, "   Issues: posées {}, écrasées {}, tronquées {}",
+                         self.outcome_count(TouchdownOutcome::Landed),
+                         self.outcome_count(TouchdownOutcome::Crashed),
+                         self.outcome_count(TouchdownOutcome::Truncated));
+
+        let _ = writeln!(out, "   {:<26} {:>4} {:>8} {:>8} {:>8} {:>8} {:>8} {:>8}",
+                         "Grandeur", "n", "moyenne", "σ", "min", "médiane", "p90", "max");
+        for (_, label, s) in self.summaries() {
+            if s.count == 0 {
+                let _ = writeln!(out, "   {:<26} {:>4} {:>8}", label, 0, "-");
+                continue;
+            }
+            let _ = writeln!(out, "   {:<26} {:>4} {:>8.2} {:>8.2} {:>8.2} {:>8.2} {:>8.2} {:>8.2}",
+                             label, s.count, s.mean, s.std_dev, s.min, s.median, s.p90, s.max);
+        }
+
+        let reasons = self.crash_reasons();
+        if !reasons.is_empty() {
+            let summary: Vec<String> = reasons.iter().map(|(key, count)| format!("{}: {}", key, count)).collect();
+            let _ = writeln!(out, "   Raisons d'échec: {}", summary.join(", "));
+        }
+        out
+    }
+
+    /// Rapport complet au format JSON, un objet par épisode compris.
+    pub fn to_json(&self) -> String {
+        self.json_object("") + "\n"
+    }
+
+    // Objet JSON dont les lignes suivant la première sont préfixées par `indent`
+    fn json_object(&self, indent: &str) -> String {
+        let (lower, upper) = self.success_interval();
+        let reasons: Vec<String> = self.crash_reasons().iter().map(|(key, count)| format!("{}: {}", json_string(key), count)).collect();
+        let metrics: Vec<String> = self
+            .summaries()
+            .iter()
+            .map(|(key, _, s)| format!(
+                "{}    {}: {{\"count\": {}, \"mean\": {}, \"std_dev\": {}, \"min\": {}, \"median\": {}, \"p90\": {}, \"max\": {}}}",
+                indent, json_string(key), s.count, json_number(s.mean), json_number(s.std_dev), json_number(s.min),
+                json_number(s.median), json_number(s.p90), json_number(s.max)
+            ))
+            .collect();
+        let episodes: Vec<String> = self
+            .records
+            .iter()
+            .map(|record| {
+                let reasons: Vec<String> = record.reasons.iter().map(|reason| json_string(reason)).collect();
+                let measures: Vec<String> = METRICS
+                    .iter()
+                    .map(|(key, _, measure)| format!("{}: {}", json_string(key), measure(record).map_or("null".to_string(), |v| json_number(v.into()))))
+                    .collect();
+                format!("{}    {{\"seed\": {}, \"outcome\": {}, \"reasons\": [{}], \"score\": {}, {}}}",
+                        indent, record.seed, json_string(record.outcome.key()), reasons.join(", "),
+                        json_number(record.metrics.score.into()), measures.join(", "))
+            })
+            .collect();
+
+        let fields = [
+            format!("\"scenario\": {}", self.scenario.as_deref().map_or("null".to_string(), json_string)),
+            format!("\"pilot\": {}", json_string(&self.pilot)),
+            format!("\"stage\": {}", json_string(&self.stage)),
+            format!("\"body\": {}", json_string(&self.body)),
+            format!("\"episode_count\": {}", self.records.len()),
+            format!("\"success\": {{\"landed\": {}, \"rate\": {}, \"confidence\": {}, \"lower\": {}, \"upper\": {}}}",
+                    self.landed(), json_number(self.success_rate()), json_number(self.confidence),
+                    json_number(lower), json_number(upper)),
+            format!("\"outcomes\": {{\"landed\": {}, \"crashed\": {}, \"truncated\": {}}}",
+                    self.outcome_count(TouchdownOutcome::Landed),
+                    self.outcome_count(TouchdownOutcome::Crashed),
+                    self.outcome_count(TouchdownOutcome::Truncated)),
+            format!("\"crash_reasons\": {{{}}}", reasons.join(", ")),
+            format!("\"metrics\": {{\n{}\n{}  }}", metrics.join(",\n"), indent),
+            format!("\"episodes\": [\n{}\n{}  ]", episodes.join(",\n"), indent),
+        ];
+        format!("{{\n{indent}  {}\n{indent}}}", fields.join(&format!(",\n{}  ", indent)))
+    }
+}
+
+/// Tableau de bord d'une suite de scénarios : un rapport par scénario et le bilan d'ensemble.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Scorecard {
+    pub reports: Vec<EvalReport>,
+}
+
+impl Scorecard {
+    pub fn episode_count(&self) -> usize {
+        self.reports.iter().map(|report| report.records.len()).sum()
+    }
+
+    pub fn landed(&self) -> usize {
+        self.reports.iter().map(EvalReport::landed).sum()
+    }
+
+    /// Intervalle du taux de succès sur l'ensemble des épisodes.
+    pub fn success_interval(&self) -> (f64, f64) {
+        let confidence = self.reports.first().map_or(0.95, |report| report.confidence);
+        wilson_interval(self.landed(), self.episode_count(), confidence)
+    }
+
+    /// Moyenne des taux de succès des scénarios : chacun pèse autant, quel que soit son nombre de graines.
+    pub fn mean_success_rate(&self) -> f64 {
+        if self.reports.is_empty() {
+            return 0.0;
+        }
+        self.reports.iter().map(EvalReport::success_rate).sum::<f64>() / self.reports.len() as f64
+    }
+
+    pub fn table(&self) -> String {
+        let mut out = String::new();
+        let Some(first) = self.reports.first() else {
+            return out;
+        };
+        let (lower, upper) = self.success_interval();
+        let _ = writeln!(out, "🏁 Tableau de bord: pilote {}, niveau {} - {} scénarios, {} épisodes",
+                         first.pilot, first.stage, self.reports.len(), self.episode_count());
+        let _ = writeln!(out, "   {:<16} {:>7} {:>15} {:>9} {:>8} {:>10} {:>7}  échec principal",
+                         "Scénario", "succès", "IC", "v. vert.", "écart", "carburant", "durée");
+        for report in &self.reports {
+            let (lower, upper) = report.success_interval();
+            let mean = |key| report.summary(key).map_or("-".to_string(), |s| format!("{:.2}", s.mean));
+            let main_reason = report
+                .crash_reasons()
+                .into_iter()
+                .max_by_key(|(_, count)| *count)
+                .map_or("-".to_string(), |(key, count)| format!("{} ({})", key, count));
+            let _ = writeln!(out, "   {:<16} {:>6.1}% {:>15} {:>9} {:>8} {:>10} {:>7}  {}",
+                             report.scenario.as_deref().unwrap_or("-"), report.success_rate() * 100.0,
+                             format!("{:.1}-{:.1}%", lower * 100.0, upper * 100.0),
+                             mean("vertical_speed"), mean("pad_offset"), mean("fuel_used"), mean("duration"), main_reason);
+        }
+        let _ = writeln!(out, "   Ensemble: {}/{} = {:.1}% (IC {:.0}%: {:.1}% - {:.1}%), moyenne des scénarios {:.1}%",
+                         self.landed(), self.episode_count(), self.landed() as f64 / self.episode_count().max(1) as f64 * 100.0,
+                         first.confidence * 100.0, lower * 100.0, upper * 100.0, self.mean_success_rate() * 100.0);
+        out
+    }
+
+    pub fn to_json(&self) -> String {
+        let (lower, upper) = self.success_interval();
+        let reports: Vec<String> = self.reports.iter().map(|report| format!("    {}", report.json_object("    "))).collect();
+        let (pilot, stage) = self.reports.first().map_or(("", ""), |report| (report.pilot.as_str(), report.stage.as_str()));
+        let mut out = String::from("{\n");
+        let _ = writeln!(out, "  \"pilot\": {},", json_string(pilot));
+        let _ = writeln!(out, "  \"stage\": {},", json_string(stage));
+        let _ = writeln!(out, "  \"episode_count\": {},", self.episode_count());
+        let _ = writeln!(out, "  \"success\": {{\"landed\": {}, \"lower\": {}, \"upper\": {}}},",
+                         self.landed(), json_number(lower), json_number(upper));
+        let _ = writeln!(out, "  \"mean_success_rate\": {},", json_number(self.mean_success_rate()));
+        let _ = writeln!(out, "  \"scenarios\": [\n{}\n  ]", reports.join(",\n"));
+        out.push_str("}\n");
+        out
+    }
+}
+
+pub(crate) fn json_string(text: &str) -> String {
+    let mut out = String::from("\"");
+    for c in text.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            c if (c as u32) < 0x20 => {
+                let _ = write!(out, "\\u{:04x}", c as u32);
+            }
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+    out
+}
+
+// Les valeurs non finies n'existent pas en JSON
+pub(crate) fn json_number(value: f64) -> String {
+    if value.is_finite() {
+        format!("{:.4}", value)
+    } else {
+        "null".to_string()
+    }
+}
@@ -0,0 +1,116 @@
+use bevy::prelude::*;
+use crate::game::environment::WindSettings;
+use crate::game::planet::CelestialBody;
+use crate::game::randomization::EpisodeParameters;
+use crate::game::rocket::InitialConditions;
+
+// Graines de chaque scénario : seules varient les petites perturbations du départ, les rafales
+// et le bruit des capteurs
+const SCENARIO_SEEDS: std::ops::Range<u64> = 0..20;
+
+// Hauteur de départ au-dessus de la plateforme (px), celle du niveau standard
+const DROP_HEIGHT: f32 = 500.0;
+
+// Perturbations tirées autour du départ nominal, pour que les graines ne rejouent pas le même vol
+const START_SPREAD: InitialConditions = InitialConditions {
+    offset: Vec2::new(10.0, 10.0),
+    rotation: 0.02,
+    velocity: Vec2::new(2.0, 2.0),
+    angular_velocity: 0.05,
+};
+
+/// Scénario de référence : départ, corps céleste, vent et paramètres physiques fixés,
+/// pour comparer pilotes et modifications sur les mêmes vols.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Scenario {
+    pub name: &'static str,
+    pub description: &'static str,
+    pub start: InitialConditions,
+    pub spread: InitialConditions,
+    pub body: CelestialBody,
+    pub parameters: EpisodeParameters,
+    pub seeds: Vec<u64>,
+}
+
+impl Scenario {
+    // Chute verticale sur Terre sans vent, paramètres nominaux
+    fn nominal(name: &'static str, description: &'static str) -> Self {
+        Self {
+            name,
+            description,
+            start: InitialConditions {
+                offset: Vec2::new(0.0, DROP_HEIGHT),
+                velocity: Vec2::new(0.0, -20.0),
+                ..default()
+            },
+            spread: START_SPREAD,
+            body: CelestialBody {
+                wind: WindSettings::calm(),
+                ..CelestialBody::earth()
+            },
+            parameters: EpisodeParameters::default(),
+            seeds: SCENARIO_SEEDS.collect(),
+        }
+    }
+
+    /// Suite de référence, dans l'ordre du tableau de bord.
+    pub fn suite() -> Vec<Self> {
+        let nominal = Self::nominal("nominal", "chute verticale au-dessus de la plateforme");
+        vec![
+            nominal.clone(),
+            Self {
+                start: InitialConditions {
+                    offset: Vec2::new(220.0, DROP_HEIGHT),
+                    velocity: Vec2::new(-15.0, -20.0),
+                    ..nominal.start
+                },
+                ..Self::nominal("lateral_offset", "départ à 220 px de la plateforme")
+            },
+            Self {
+                body: CelestialBody {
+                    wind: WindSettings { mean_speed: 25.0, gust_strength: 10.0 },
+                    ..CelestialBody::earth()
+                },
+                ..Self::nominal("crosswind", "vent de travers de 25 px/s, rafales de 10 px/s")
+            },
+            Self {
+                parameters: EpisodeParameters { initial_fuel: 0.2, ..default() },
+                ..Self::nominal("low_fuel", "réservoir rempli à 20 %")
+            },
+            Self {
+                start: InitialConditions { rotation: 0.5, ..nominal.start },
+                ..Self::nominal("tilted", "départ incliné de 29°")
+            },
+            Self {
+                start: InitialConditions { angular_velocity: 2.0, ..nominal.start },
+                ..Self::nominal("spinning", "départ en rotation à 2 rad/s")
+            },
+            Self {
+                parameters: EpisodeParameters { dry_mass_scale: 1.6, ..default() },
+                ..Self::nominal("heavy", "masse à vide ×1,6 : poussée et autorité réduites d'autant")
+            },
+            Self {
+                body: CelestialBody::moon(),
+                ..Self::nominal("moon", "gravité lunaire, sans atmosphère")
+            },
+        ]
+    }
+
+    /// `all` pour toute la suite, ou une liste de noms séparés par des virgules.
+    pub fn select(spec: &str) -> Result<Vec<Self>, String> {
+        let suite = Self::suite();
+        if spec.eq_ignore_ascii_case("all") {
+            return Ok(suite);
+        }
+        spec.split(',')
+            .map(|name| {
+                suite
+                    .iter()
+                    .find(|scenario| scenario.name == name.trim())
+                    .cloned()
+                    .ok_or_else(|| format!("scénario inconnu: '{}' ({})", name.trim(),
+                                           suite.iter().map(|s| s.name).collect::<Vec<_>>().join(", ")))
+            })
+            .collect()
+    }
+}
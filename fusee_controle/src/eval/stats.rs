@@ -0,0 +1,70 @@
+/// Quantile de la loi normale centrée réduite dépassé avec la probabilité `tail`
+/// (Abramowitz et Stegun 26.2.23, erreur < 4.5e-4).
+pub fn normal_quantile(tail: f64) -> f64 {
+    let tail = tail.clamp(1e-12, 0.5);
+    let t = (-2.0 * tail.ln()).sqrt();
+    t - (2.515517 + 0.802853 * t + 0.010328 * t * t) / (1.0 + 1.432788 * t + 0.189269 * t * t + 0.001308 * t * t * t)
+}
+
+/// Intervalle de Wilson d'une proportion `successes / trials` au niveau `confidence` (0.95).
+/// Contrairement à l'intervalle de Wald, il reste dans [0, 1] et informatif à 0 ou 100 %.
+pub fn wilson_interval(successes: usize, trials: usize, confidence: f64) -> (f64, f64) {
+    if trials == 0 {
+        return (0.0, 1.0);
+    }
+    let n = trials as f64;
+    let p = successes as f64 / n;
+    let z = normal_quantile((1.0 - confidence) / 2.0);
+    let z2 = z * z;
+
+    let center = (p + z2 / (2.0 * n)) / (1.0 + z2 / n);
+    let half_width = z / (1.0 + z2 / n) * (p * (1.0 - p) / n + z2 / (4.0 * n * n)).sqrt();
+    ((center - half_width).max(0.0), (center + half_width).min(1.0))
+}
+
+/// Résumé d'une série de mesures.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Summary {
+    pub count: usize,
+    pub mean: f64,
+    pub std_dev: f64,
+    pub min: f64,
+    pub median: f64,
+    pub p90: f64,
+    pub max: f64,
+}
+
+impl Summary {
+    pub fn of(values: impl IntoIterator<Item = f64>) -> Self {
+        let mut sorted: Vec<f64> = values.into_iter().filter(|v| v.is_finite()).collect();
+        if sorted.is_empty() {
+            return Self::default();
+        }
+        sorted.sort_by(f64::total_cmp);
+
+        let n = sorted.len() as f64;
+        let mean = sorted.iter().sum::<f64>() / n;
+        let variance = if sorted.len() > 1 {
+            sorted.iter().map(|v| (v - mean).powi(2)).sum::<f64>() / (n - 1.0)
+        } else {
+            0.0
+        };
+        Self {
+            count: sorted.len(),
+            mean,
+            std_dev: variance.sqrt(),
+            min: sorted[0],
+            median: quantile(&sorted, 0.5),
+            p90: quantile(&sorted, 0.9),
+            max: sorted[sorted.len() - 1],
+        }
+    }
+}
+
+// Quantile par interpolation linéaire d'une série triée et non vide
+fn quantile(sorted: &[f64], q: f64) -> f64 {
+    let position = q * (sorted.len() - 1) as f64;
+    let below = position.floor() as usize;
+    let above = (below + 1).min(sorted.len() - 1);
+    sorted[below] + (sorted[above] - sorted[below]) * (position - below as f64)
+}
@@ -0,0 +1,285 @@
+use bevy::prelude::*;
+use crate::game::drone_ship::{DeckMotion, DroneShip};
+use crate::game::environment::LandingPad;
+use crate::game::physics::PhysicsBody;
+use crate::game::rocket::{LandingLegs, Rocket, RocketMainBody};
+use crate::game::simulation::BodyState;
+use crate::game::terrain::Terrain;
+use crate::game::GameState;
+
+/// Paramètres du contact au sol : pieds ressort-amortisseur et critère de stabilisation.
+#[derive(Resource, Clone, Copy, Debug)]
+pub struct ContactSettings {
+    /// Raideur d'un pied (accélération par pixel d'enfoncement).
+    pub stiffness: f32,
+    /// Amortissement vertical d'un pied (1/s).
+    pub damping: f32,
+    /// Amortissement latéral d'un pied, borné par le frottement de Coulomb.
+    pub tangential_damping: f32,
+    pub friction: f32,
+    /// Seuils de stabilité (durée requise : `LandingCriteria::settle_time`).
+    pub settle_speed: f32,
+    pub settle_angular_speed: f32,
+    /// Inclinaison au-delà de laquelle une fusée au sol est considérée renversée (rad).
+    pub tip_over_angle: f32,
+}
+
+impl Default for ContactSettings {
+    fn default() -> Self {
+        Self {
+            stiffness: 800.0,
+            damping: 30.0,
+            tangential_damping: 20.0,
+            friction: 0.8,
+            settle_speed: 2.0,
+            settle_angular_speed: 0.05,
+            tip_over_angle: 60f32.to_radians(),
+        }
+    }
+}
+
+/// Surface sous un point : sol nu, pont d'une plateforme (éventuellement mobile) ou mer.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum Surface {
+    #[default]
+    Ground,
+    Deck(DeckMotion),
+    Water,
+}
+
+impl Surface {
+    /// Vitesse de la surface au point touché.
+    pub fn velocity_at(&self, point: Vec2) -> Vec2 {
+        match self {
+            Surface::Deck(deck) => deck.velocity + deck.angular_velocity * (point - deck.position).perp(),
+            Surface::Ground | Surface::Water => Vec2::ZERO,
+        }
+    }
+
+    pub fn tilt(&self) -> f32 {
+        match self {
+            Surface::Deck(deck) => deck.tilt,
+            Surface::Ground | Surface::Water => 0.0,
+        }
+    }
+
+    pub fn angular_velocity(&self) -> f32 {
+        match self {
+            Surface::Deck(deck) => deck.angular_velocity,
+            Surface::Ground | Surface::Water => 0.0,
+        }
+    }
+}
+
+/// État de la fusée au moment du premier contact avec le sol.
+#[derive(Clone, Copy, Debug)]
+pub struct TouchdownSnapshot {
+    pub position: Vec2,
+    pub velocity: Vec2,
+    pub angle: f32,
+    /// Surface touchée en premier, pour juger vitesse et angle relatifs au pont.
+    pub surface: Surface,
+}
+
+/// Suivi du contact au sol d'une fusée, mis à jour par `ground_contact`.
+#[derive(Component, Default, Debug)]
+pub struct GroundContact {
+    pub legs_in_contact: usize,
+    /// La coque elle-même touche le sol (fusée couchée).
+    pub hull_contact: bool,
+    pub touchdown: Option<TouchdownSnapshot>,
+    pub settled_time: f32,
+    /// Dernière surface porteuse touchée.
+    pub surface: Surface,
+    /// La fusée est tombée à la mer.
+    pub splashdown: bool,
+}
+
+/// Demi-dimensions de la coque, pour détecter le contact de la structure avec le sol.
+#[derive(Component, Clone, Copy, Debug)]
+pub struct HullShape {
+    pub half_size: Vec2,
+}
+
+// Vitesse d'un point du corps situé à `r` du centre de masse
+fn point_velocity(velocity: Vec2, angular_velocity: f32, r: Vec2) -> Vec2 {
+    velocity + angular_velocity * r.perp()
+}
+
+/// Surface la plus haute sous l'abscisse `x` et sa hauteur.
+pub fn surface_under(x: f32, terrain: &Terrain, pads: &[(&LandingPad, Option<&DroneShip>)]) -> (f32, Surface) {
+    let mut best = (terrain.height_at(x), Surface::Ground);
+
+    for (_, ship) in pads {
+        if let Some(ship) = ship
+            && ship.covers(x)
+            && ship.sea_level() > best.0
+        {
+            best = (ship.sea_level(), Surface::Water);
+        }
+    }
+    // À hauteur égale, le pont l'emporte sur le sol qu'il recouvre
+    for (pad, _) in pads {
+        if let Some(height) = pad.deck_height_at(x)
+            && height >= best.0
+        {
+            best = (height, Surface::Deck(pad.motion()));
+        }
+    }
+    best
+}
+
+/// Force exercée par le sol sur un pied enfoncé de `penetration`.
+pub fn foot_force(settings: &ContactSettings, penetration: f32, foot_velocity: Vec2) -> Vec2 {
+    if penetration <= 0.0 {
+        return Vec2::ZERO;
+    }
+
+    let normal = (settings.stiffness * penetration - settings.damping * foot_velocity.y).max(0.0);
+    let max_friction = settings.friction * normal;
+    let tangential = (-settings.tangential_damping * foot_velocity.x).clamp(-max_friction, max_friction);
+    Vec2::new(tangential, normal)
+}
+
+/// Un pas du contact au sol : ressorts des pieds déployés `feet` (repère de la fusée), contact de
+/// la coque, puis suivi du premier contact et de la stabilité dans `contact`. `surface_under` donne
+/// la surface la plus haute sous une abscisse. Seules les vitesses de `body` changent ; la réaction
+/// s'applique avant l'intégration du pas, pour qu'une fusée posée garde une vitesse nulle. Renvoie
+/// l'appui de chaque pied.
+#[allow(clippy::too_many_arguments)]
+pub fn contact_step(
+    settings: &ContactSettings,
+    body: &mut BodyState,
+    radius_of_gyration: f32,
+    hull: &HullShape,
+    feet: &[Vec2],
+    contact: &mut GroundContact,
+    surface_under: impl Fn(f32) -> (f32, Surface),
+    dt: f32,
+) -> Vec<bool> {
+    // État jugé : premier contact et stabilité
+    let state = *body;
+    let position = state.position;
+    let rotation = Vec2::from_angle(body.angle);
+
+    let mut acceleration = Vec2::ZERO;
+    let mut torque = 0.0;
+    let mut legs_in_contact = 0;
+    let mut touched = None;
+
+    let feet_contact = feet
+        .iter()
+        .map(|&foot_offset| {
+            let r = rotation.rotate(foot_offset);
+            let foot = position + r;
+            let (height, surface) = surface_under(foot.x);
+            let penetration = height - foot.y;
+            if penetration <= 0.0 {
+                return false;
+            }
+            touched = Some(surface);
+            // La mer ne porte pas la fusée
+            if surface == Surface::Water {
+                return false;
+            }
+
+            legs_in_contact += 1;
+            let relative_velocity = point_velocity(body.velocity, body.angular_velocity, r) - surface.velocity_at(foot);
+            let force = foot_force(settings, penetration, relative_velocity);
+            acceleration += force;
+            torque += r.perp_dot(force);
+            true
+        })
+        .collect();
+
+    body.velocity += acceleration * dt;
+    body.angular_velocity += torque / radius_of_gyration.powi(2) * dt;
+
+    let corners = [
+        Vec2::new(-hull.half_size.x, hull.half_size.y),
+        Vec2::new(hull.half_size.x, hull.half_size.y),
+        Vec2::new(-hull.half_size.x, -hull.half_size.y),
+        Vec2::new(hull.half_size.x, -hull.half_size.y),
+    ];
+    let hull_surface = corners.iter().find_map(|&corner| {
+        let point = position + rotation.rotate(corner);
+        let (height, surface) = surface_under(point.x);
+        (point.y <= height).then_some(surface)
+    });
+    contact.hull_contact = hull_surface.is_some_and(|surface| surface != Surface::Water);
+    contact.legs_in_contact = legs_in_contact;
+
+    if let Some(surface) = touched.or(hull_surface) {
+        contact.surface = surface;
+        contact.splashdown |= surface == Surface::Water;
+        if contact.touchdown.is_none() {
+            contact.touchdown = Some(TouchdownSnapshot {
+                position,
+                velocity: state.velocity,
+                angle: state.angle,
+                surface,
+            });
+        }
+    }
+
+    // Stabilité : tous les pieds posés, fusée quasi immobile par rapport au pont
+    let relative_velocity = state.velocity - contact.surface.velocity_at(position);
+    let relative_angular_velocity = state.angular_velocity - contact.surface.angular_velocity();
+    let at_rest = legs_in_contact >= 2
+        && relative_velocity.length() < settings.settle_speed
+        && relative_angular_velocity.abs() < settings.settle_angular_speed;
+    contact.settled_time = if at_rest { contact.settled_time + dt } else { 0.0 };
+    feet_contact
+}
+
+#[allow(clippy::type_complexity)]
+pub(crate) fn ground_contact(
+    mut rocket_query: Query<(&Transform, &mut PhysicsBody, &Rocket, &HullShape, &mut GroundContact, &Children), With<RocketMainBody>>,
+    mut legs_query: Query<&mut LandingLegs>,
+    pads_query: Query<(&LandingPad, Option<&DroneShip>)>,
+    settings: Res<ContactSettings>,
+    terrain: Res<Terrain>,
+    time: Res<Time>,
+    game_state: Res<GameState>,
+) {
+    if *game_state != GameState::Playing {
+        return;
+    }
+
+    // Pas de temps borné pour garder le ressort stable à faible FPS
+    let dt = time.delta_seconds().min(1.0 / 30.0);
+    let pads: Vec<_> = pads_query.iter().collect();
+
+    for (transform, mut physics, rocket, hull, mut contact, children) in rocket_query.iter_mut() {
+        if rocket.has_crashed || rocket.has_landed {
+            continue;
+        }
+
+        let mut body = BodyState {
+            position: transform.translation.truncate(),
+            velocity: physics.velocity,
+            angle: transform.rotation.to_euler(EulerRot::XYZ).2,
+            angular_velocity: physics.angular_velocity,
+        };
+        // Une jambe repliée ne porte pas : la coque touchera le sol
+        let feet: Vec<Vec2> = legs_query.iter_many(children).filter(|leg| leg.deployed).map(|leg| leg.foot).collect();
+        let feet_contact = contact_step(
+            &settings,
+            &mut body,
+            physics.radius_of_gyration,
+            hull,
+            &feet,
+            &mut contact,
+            |x| surface_under(x, &terrain, &pads),
+            dt,
+        );
+        physics.velocity = body.velocity;
+        physics.angular_velocity = body.angular_velocity;
+
+        let mut feet_contact = feet_contact.into_iter();
+        let mut legs = legs_query.iter_many_mut(children);
+        while let Some(mut leg) = legs.fetch_next() {
+            leg.contact = leg.deployed && feet_contact.next().unwrap_or(false);
+        }
+    }
+}
@@ -1,9 +1,41 @@
+use std::collections::VecDeque;
 use bevy::prelude::*;
-use crate::game::rocket::{Rocket, RocketMainBody};
-use crate::game::physics::PhysicsBody;
+use crate::game::rocket::{LandingLegs, Rocket, RocketMainBody};
+use crate::game::physics::{Aerodynamics, PhysicsBody};
+use crate::game::simulation::{attitude_rate, burn_fuel};
+use crate::game::terrain::Terrain;
 use crate::game::GameState;
+use crate::rl_agent::metrics::MetricsSettings;
 use crate::rl_agent::RocketControls;
 
+/// Commandes en transit vers les actionneurs : chaque commande prend effet après `delay` secondes.
+#[derive(Component, Debug, Default)]
+pub struct ActuatorLag {
+    pub delay: f32,
+    pending: VecDeque<(f32, f32, f32)>, // (instant de prise d'effet, poussée, rotation)
+    elapsed: f32,
+    applied: (f32, f32),
+}
+
+impl ActuatorLag {
+    pub fn new(delay: f32) -> Self {
+        Self { delay, ..default() }
+    }
+
+    /// Enregistre la commande demandée et renvoie (poussée, rotation) effectives.
+    pub fn step(&mut self, dt: f32, throttle: f32, rotation: f32) -> (f32, f32) {
+        self.elapsed += dt;
+        self.pending.push_back((self.elapsed + self.delay, throttle, rotation));
+        while let Some(&(due, throttle, rotation)) = self.pending.front()
+            && due <= self.elapsed
+        {
+            self.applied = (throttle, rotation);
+            self.pending.pop_front();
+        }
+        self.applied
+    }
+}
+
 pub struct RocketControlsPlugin;
 
 impl Plugin for RocketControlsPlugin {
@@ -11,6 +43,7 @@ impl Plugin for RocketControlsPlugin {
         app.add_systems(Update, (
             keyboard_controls,
             apply_controls,
+            deploy_landing_legs.after(keyboard_controls),
             consume_fuel,
         ));
     }
@@ -54,6 +87,11 @@ fn keyboard_controls(
             manual_control = true;
         }
 
+        // Déploiement des jambes : disponible en manuel sans reprendre la main à l'IA
+        if keyboard_input.just_pressed(KeyCode::G) {
+            controls.deploy_legs = true;
+        }
+
         // Retour au mode AI si pas de contrôle manuel
         if !manual_control && !controls.controlled_by_ai {
             controls.controlled_by_ai = true;
@@ -62,29 +100,81 @@ fn keyboard_controls(
 }
 
 fn apply_controls(
-    mut rocket_query: Query<(&mut Rocket, &mut PhysicsBody), With<RocketMainBody>>,
+    mut rocket_query: Query<(&mut Rocket, &mut PhysicsBody, &mut ActuatorLag), With<RocketMainBody>>,
     controls_query: Query<&RocketControls>,
+    time: Res<Time>,
     game_state: Res<GameState>,
 ) {
     if *game_state != GameState::Playing {
         return;
     }
 
-    if let (Ok((mut rocket, mut physics)), Ok(controls)) = (rocket_query.get_single_mut(), controls_query.get_single()) {
+    if let (Ok((mut rocket, mut physics, mut lag)), Ok(controls)) = (rocket_query.get_single_mut(), controls_query.get_single()) {
         if rocket.has_crashed || rocket.has_landed {
             return;
         }
 
+        // Les actionneurs exécutent la commande avec retard
+        let (throttle, rotation) = lag.step(time.delta_seconds(), controls.throttle, controls.rotation);
+
         // Appliquer la poussée
-        rocket.throttle = controls.throttle;
+        rocket.throttle = throttle;
         
-        // Appliquer la rotation
-        physics.angular_velocity = controls.rotation * rocket.rotation_speed;
+        // Propulseurs d'attitude : suivent la vitesse de rotation demandée avec une autorité
+        // limitée, les couples extérieurs (aérodynamique) restent donc perceptibles
+        physics.angular_velocity = attitude_rate(
+            physics.angular_velocity,
+            rotation,
+            rocket.rotation_speed,
+            rocket.rcs_authority,
+            time.delta_seconds(),
+        );
+    }
+}
+
+// Déploiement unique : une fois sorties, les jambes restent déployées jusqu'à la fin de l'épisode
+fn deploy_landing_legs(
+    mut rocket_query: Query<(&mut Rocket, &mut Aerodynamics, &Transform, &Children), With<RocketMainBody>>,
+    mut legs_query: Query<(&mut LandingLegs, &mut Visibility)>,
+    mut controls_query: Query<&mut RocketControls>,
+    terrain: Res<Terrain>,
+    metrics: Res<MetricsSettings>,
+    game_state: Res<GameState>,
+) {
+    let Ok(mut controls) = controls_query.get_single_mut() else {
+        return;
+    };
+    // La demande est consommée à chaque image, même hors vol
+    let requested = std::mem::take(&mut controls.deploy_legs);
+
+    if *game_state != GameState::Playing || !requested {
+        return;
+    }
+
+    if let Ok((mut rocket, mut aero, transform, children)) = rocket_query.get_single_mut()
+        && rocket.legs_deploy_altitude.is_none()
+        && !rocket.has_crashed
+        && !rocket.has_landed
+    {
+        let altitude = transform.translation.y - terrain.height_at(transform.translation.x);
+        rocket.legs_deploy_altitude = Some(altitude);
+        aero.gear_deployed = true;
+
+        let mut legs = legs_query.iter_many_mut(children);
+        while let Some((mut leg, mut visibility)) = legs.fetch_next() {
+            leg.deployed = true;
+            *visibility = Visibility::Inherited;
+        }
+
+        if metrics.shows_flight_events() {
+            println!("🦵 JAMBES DÉPLOYÉES à {:.1} m du sol", altitude);
+        }
     }
 }
 
 fn consume_fuel(
     mut rocket_query: Query<&mut Rocket>,
+    metrics: Res<MetricsSettings>,
     time: Res<Time>,
     game_state: Res<GameState>,
 ) {
@@ -92,14 +182,14 @@ fn consume_fuel(
         return;
     }
 
-    if let Ok(mut rocket) = rocket_query.get_single_mut() {
-        if rocket.throttle > 0.0 && rocket.fuel > 0.0 && !rocket.has_crashed && !rocket.has_landed {
-            let fuel_consumption = rocket.throttle * 15.0 * time.delta_seconds();
-            rocket.fuel -= fuel_consumption;
-            rocket.fuel = rocket.fuel.max(0.0);
-            
-            if rocket.fuel <= 0.0 {
-                rocket.throttle = 0.0;
+    if let Ok(mut rocket) = rocket_query.get_single_mut()
+        && rocket.throttle > 0.0 && rocket.fuel > 0.0 && !rocket.has_crashed && !rocket.has_landed
+    {
+        rocket.fuel = burn_fuel(rocket.fuel, rocket.throttle, time.delta_seconds());
+
+        if rocket.fuel <= 0.0 {
+            rocket.throttle = 0.0;
+            if metrics.shows_flight_events() {
                 println!("⛽ PLUS DE CARBURANT!");
             }
         }
@@ -0,0 +1,91 @@
+use bevy::prelude::*;
+use crate::settings::{parse_value, SettingsFile};
+
+/// Tolérances d'un atterrissage réussi. Les vitesses sont relatives au pont de la plateforme,
+/// l'angle est l'écart à la perpendiculaire du pont.
+#[derive(Resource, Clone, Copy, Debug, PartialEq)]
+pub struct LandingCriteria {
+    /// Part de la demi-largeur de la plateforme acceptée autour de son centre (1.0 = toute la zone).
+    pub zone_fraction: f32,
+    /// Écart maximal à la verticale (degrés).
+    pub angle_tolerance: f32,
+    pub max_vertical_speed: f32,
+    pub max_horizontal_speed: f32,
+    /// Au-delà, l'impact détruit la fusée quelle que soit sa direction.
+    pub max_impact_speed: f32,
+    /// Durée de stabilité requise avant de valider un atterrissage (s).
+    pub settle_time: f32,
+}
+
+impl Default for LandingCriteria {
+    fn default() -> Self {
+        Self {
+            zone_fraction: 1.0,
+            angle_tolerance: 9.0, // 10% de 90°
+            max_vertical_speed: 3.0,
+            max_horizontal_speed: 1.0,
+            max_impact_speed: 25.0,
+            settle_time: 1.0,
+        }
+    }
+}
+
+impl LandingCriteria {
+    /// Lit la section `[landing]` du fichier de configuration, à partir des valeurs par défaut.
+    pub fn from_settings(settings: &SettingsFile) -> Result<Self, String> {
+        let mut criteria = Self::default();
+        settings.apply_section("landing", |key, value| {
+            let field = match key {
+                "zone_fraction" => &mut criteria.zone_fraction,
+                "angle_tolerance" => &mut criteria.angle_tolerance,
+                "max_vertical_speed" => &mut criteria.max_vertical_speed,
+                "max_horizontal_speed" => &mut criteria.max_horizontal_speed,
+                "max_impact_speed" => &mut criteria.max_impact_speed,
+                "settle_time" => &mut criteria.settle_time,
+                _ => return Ok(false),
+            };
+            *field = parse_value(key, value)?;
+            Ok(true)
+        })?;
+        Ok(criteria)
+    }
+
+    /// Critères assouplis (`scale` > 1) ou durcis (`scale` < 1) sur l'angle et les vitesses.
+    pub fn with_tolerance_scale(&self, scale: f32) -> Self {
+        Self {
+            angle_tolerance: self.angle_tolerance * scale,
+            max_vertical_speed: self.max_vertical_speed * scale,
+            max_horizontal_speed: self.max_horizontal_speed * scale,
+            ..*self
+        }
+    }
+
+    /// Demi-largeur de la zone acceptée pour une plateforme de largeur `pad_width`.
+    pub fn zone_half_width(&self, pad_width: f32) -> f32 {
+        pad_width / 2.0 * self.zone_fraction
+    }
+
+    /// Fenêtre d'angle mesurée depuis l'horizontale du pont (90° = vertical).
+    pub fn angle_window(&self) -> (f32, f32) {
+        (90.0 - self.angle_tolerance, 90.0 + self.angle_tolerance)
+    }
+
+    /// Score de précision sur 100 : 100 pour un posé parfait, 0 à la limite des tolérances.
+    pub fn precision_score(&self, angle_deviation: f32, vertical_speed: f32, horizontal_speed: f32) -> f32 {
+        let angle_score = 1.0 - angle_deviation / self.angle_tolerance;
+        let vertical_score = 1.0 - vertical_speed / self.max_vertical_speed;
+        let horizontal_score = 1.0 - horizontal_speed / self.max_horizontal_speed;
+        100.0 * angle_score * vertical_score * horizontal_score
+    }
+
+    /// Résumé des critères pour l'interface et la console.
+    pub fn describe(&self, pad_width: f32) -> String {
+        let (min_angle, max_angle) = self.angle_window();
+        let half_width = self.zone_half_width(pad_width);
+        format!(
+            "• Zone JAUNE: -{:.0} à +{:.0}\n• Angle: {:.0}° à {:.0}° (90° ± {:.0}°)\n• Vitesse V < {} m/s\n• Vitesse H < {} m/s\n• Stable pendant {} s",
+            half_width, half_width, min_angle, max_angle, self.angle_tolerance,
+            self.max_vertical_speed, self.max_horizontal_speed, self.settle_time
+        )
+    }
+}
@@ -0,0 +1,249 @@
+use std::collections::VecDeque;
+use bevy::prelude::*;
+use crate::game::criteria::LandingCriteria;
+use crate::game::environment::{ActivePad, LandingPad, LandingZone, PadLayout};
+use crate::game::outcome::{Touchdown, TouchdownOutcome};
+use crate::settings::{parse_value, SettingsFile};
+
+/// Difficulté d'un niveau du programme d'apprentissage.
+#[derive(Clone, Debug, PartialEq)]
+pub struct CurriculumStage {
+    pub name: String,
+    /// Hauteur de départ au-dessus de la plateforme cible (min, max), px.
+    pub spawn_height: (f32, f32),
+    /// Écart horizontal de départ maximal à la plateforme, px.
+    pub spawn_offset: f32,
+    /// Inclinaison de départ maximale, rad.
+    pub spawn_rotation: f32,
+    /// Vitesse initiale maximale par axe (horizontale ±x, descente 0..y), px/s.
+    pub spawn_velocity: Vec2,
+    /// Facteur appliqué au vent du corps céleste.
+    pub wind_scale: f32,
+    /// Facteur appliqué aux tolérances d'angle et de vitesse des critères.
+    pub tolerance_scale: f32,
+    /// Facteur appliqué à la largeur des plateformes.
+    pub pad_width_scale: f32,
+}
+
+impl CurriculumStage {
+    pub fn presets() -> Vec<Self> {
+        vec![
+            Self {
+                name: "initiation".to_string(),
+                spawn_height: (250.0, 300.0),
+                spawn_offset: 30.0,
+                spawn_rotation: 0.1,
+                spawn_velocity: Vec2::new(0.0, 5.0),
+                wind_scale: 0.0,
+                tolerance_scale: 2.0,
+                pad_width_scale: 1.5,
+            },
+            Self {
+                name: "intermédiaire".to_string(),
+                spawn_height: (350.0, 450.0),
+                spawn_offset: 80.0,
+                spawn_rotation: 0.3,
+                spawn_velocity: Vec2::new(10.0, 20.0),
+                wind_scale: 0.5,
+                tolerance_scale: 1.5,
+                pad_width_scale: 1.2,
+            },
+            // Conditions historiques du simulateur
+            Self {
+                name: "standard".to_string(),
+                spawn_height: (500.0, 560.0),
+                spawn_offset: 100.0,
+                spawn_rotation: 0.5,
+                spawn_velocity: Vec2::new(20.0, 30.0),
+                wind_scale: 1.0,
+                tolerance_scale: 1.0,
+                pad_width_scale: 1.0,
+            },
+            Self {
+                name: "expert".to_string(),
+                spawn_height: (550.0, 650.0),
+                spawn_offset: 250.0,
+                spawn_rotation: 0.8,
+                spawn_velocity: Vec2::new(40.0, 40.0),
+                wind_scale: 1.5,
+                tolerance_scale: 0.8,
+                pad_width_scale: 0.8,
+            },
+        ]
+    }
+}
+
+/// Changement de niveau décidé après un épisode.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum StageChange {
+    Promoted,
+    Demoted,
+}
+
+/// Programme d'apprentissage : ajuste la difficulté selon le taux de succès glissant.
+#[derive(Resource, Clone, Debug)]
+pub struct Curriculum {
+    pub stages: Vec<CurriculumStage>,
+    pub current: usize,
+    pub enabled: bool,
+    /// Nombre d'épisodes du taux de succès glissant.
+    pub window: usize,
+    pub promote_threshold: f32,
+    pub demote_threshold: f32,
+    pub history: VecDeque<bool>,
+}
+
+impl Default for Curriculum {
+    fn default() -> Self {
+        Self {
+            stages: CurriculumStage::presets(),
+            current: 0,
+            enabled: true,
+            window: 20,
+            promote_threshold: 0.7,
+            demote_threshold: 0.2,
+            history: VecDeque::new(),
+        }
+    }
+}
+
+impl Curriculum {
+    /// Lit la section `[curriculum]` : `enabled`, `stage`, `window`, `promote`, `demote`.
+    pub fn from_settings(settings: &SettingsFile) -> Result<Self, String> {
+        let mut curriculum = Self::default();
+        settings.apply_section("curriculum", |key, value| {
+            match key {
+                "enabled" => curriculum.enabled = parse_value(key, value)?,
+                "stage" => curriculum.current = curriculum.stage_index(value)?,
+                "window" => curriculum.window = parse_value::<usize>(key, value)?.max(1),
+                "promote" => curriculum.promote_threshold = parse_value(key, value)?,
+                "demote" => curriculum.demote_threshold = parse_value(key, value)?,
+                _ => return Ok(false),
+            }
+            Ok(true)
+        })?;
+        Ok(curriculum)
+    }
+
+    /// Index d'un niveau, par son nom ou son numéro.
+    pub fn stage_index(&self, name: &str) -> Result<usize, String> {
+        name.parse::<usize>()
+            .ok()
+            .or_else(|| self.stages.iter().position(|stage| stage.name == name))
+            .filter(|&index| index < self.stages.len())
+            .ok_or_else(|| format!("niveau inconnu: '{}'", name))
+    }
+
+    pub fn stage(&self) -> &CurriculumStage {
+        &self.stages[self.current]
+    }
+
+    pub fn success_rate(&self) -> f32 {
+        if self.history.is_empty() {
+            return 0.0;
+        }
+        self.history.iter().filter(|&&success| success).count() as f32 / self.history.len() as f32
+    }
+
+    /// Enregistre l'issue d'un épisode ; change de niveau une fois la fenêtre remplie.
+    /// Renvoie le changement et le taux de succès qui l'a motivé.
+    pub fn record(&mut self, success: bool) -> Option<(StageChange, f32)> {
+        self.history.push_back(success);
+        if self.history.len() > self.window {
+            self.history.pop_front();
+        }
+        if !self.enabled || self.history.len() < self.window {
+            return None;
+        }
+
+        let rate = self.success_rate();
+        let change = if rate >= self.promote_threshold && self.current + 1 < self.stages.len() {
+            self.current += 1;
+            StageChange::Promoted
+        } else if rate <= self.demote_threshold && self.current > 0 {
+            self.current -= 1;
+            StageChange::Demoted
+        } else {
+            return None;
+        };
+
+        // Le nouveau niveau est jugé sur ses propres épisodes
+        self.history.clear();
+        Some((change, rate))
+    }
+}
+
+/// Critères de la configuration, avant l'ajustement du niveau courant.
+#[derive(Resource, Clone, Copy, Debug)]
+struct BaseCriteria(LandingCriteria);
+
+pub struct CurriculumPlugin;
+
+impl Plugin for CurriculumPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<Curriculum>();
+        let criteria = app.world.get_resource::<LandingCriteria>().copied().unwrap_or_default();
+
+        app.insert_resource(BaseCriteria(criteria))
+           .add_systems(Startup, announce_stage)
+           .add_systems(Update, (update_curriculum, apply_stage).chain());
+    }
+}
+
+fn announce_stage(curriculum: Res<Curriculum>) {
+    let stage = curriculum.stage();
+    println!("📚 Niveau {} / {}: {}{}", curriculum.current + 1, curriculum.stages.len(), stage.name,
+             if curriculum.enabled { "" } else { " (fixe)" });
+}
+
+fn update_curriculum(mut curriculum: ResMut<Curriculum>, mut touchdowns: EventReader<Touchdown>) {
+    for touchdown in touchdowns.read() {
+        // Une troncature compte comme un échec : la fusée ne s'est pas posée
+        let success = touchdown.outcome == TouchdownOutcome::Landed;
+        if let Some((change, rate)) = curriculum.record(success) {
+            let label = match change {
+                StageChange::Promoted => "⬆️ Promotion",
+                StageChange::Demoted => "⬇️ Rétrogradation",
+            };
+            println!("{} au niveau {} / {}: {} (taux de succès {:.0}%, seuils {:.0}% / {:.0}%)",
+                     label, curriculum.current + 1, curriculum.stages.len(), curriculum.stage().name,
+                     rate * 100.0, curriculum.demote_threshold * 100.0, curriculum.promote_threshold * 100.0);
+        }
+    }
+}
+
+// Tolérances et largeur des plateformes suivent le niveau courant
+fn apply_stage(
+    curriculum: Res<Curriculum>,
+    base: Res<BaseCriteria>,
+    layout: Res<PadLayout>,
+    mut criteria: ResMut<LandingCriteria>,
+    mut active_pad: ResMut<ActivePad>,
+    mut pads: Query<(&mut LandingPad, &mut LandingZone, &mut Transform)>,
+) {
+    if !curriculum.is_changed() && !active_pad.is_changed() {
+        return;
+    }
+    let stage = curriculum.stage();
+
+    let scaled = base.0.with_tolerance_scale(stage.tolerance_scale);
+    if *criteria != scaled {
+        *criteria = scaled;
+    }
+
+    for (mut pad, mut zone, mut transform) in pads.iter_mut() {
+        let width = layout.pads[pad.index].width * stage.pad_width_scale;
+        if pad.width == width {
+            continue;
+        }
+        pad.width = width;
+        zone.x_min = pad.position.x - width / 2.0;
+        zone.x_max = pad.position.x + width / 2.0;
+        transform.scale.x = stage.pad_width_scale;
+    }
+
+    let width = layout.pads[active_pad.index].width * stage.pad_width_scale;
+    if active_pad.width != width {
+        active_pad.width = width;
+    }
+}
@@ -0,0 +1,209 @@
+use std::f32::consts::TAU;
+use bevy::prelude::*;
+use rand::Rng;
+use crate::game::contact::ground_contact;
+use crate::game::environment::{ActivePad, LandingPad, LandingZone};
+use crate::game::episode::{EpisodeClock, EpisodeRng};
+use crate::game::rocket::restart_system;
+use crate::game::GameState;
+
+// Hauteur du pont au-dessus de la mer (px)
+pub const DECK_FREEBOARD: f32 = 14.0;
+// Profondeur d'eau entre le fond et la surface de la mer
+pub const SEA_DEPTH: f32 = 20.0;
+// Demi-largeur de la mer dessinée et simulée autour d'une barge
+pub const SEA_HALF_WIDTH: f32 = 300.0;
+// Avance maximale de la houle tirée à chaque épisode (s)
+const SEA_PHASE_SPAN: f32 = 600.0;
+
+/// État de la mer : dérive horizontale, pilonnement et roulis de la barge.
+/// Amplitudes en px (roulis en radians), périodes en secondes.
+#[derive(Resource, Clone, Copy, Debug, PartialEq)]
+pub struct SeaState {
+    pub drift_amplitude: f32,
+    pub drift_period: f32,
+    pub heave_amplitude: f32,
+    pub heave_period: f32,
+    pub roll_amplitude: f32,
+    pub roll_period: f32,
+}
+
+impl Default for SeaState {
+    fn default() -> Self {
+        Self::moderate()
+    }
+}
+
+impl SeaState {
+    pub fn calm() -> Self {
+        Self {
+            drift_amplitude: 15.0,
+            drift_period: 20.0,
+            heave_amplitude: 2.0,
+            heave_period: 7.0,
+            roll_amplitude: 1f32.to_radians(),
+            roll_period: 9.0,
+        }
+    }
+
+    pub fn moderate() -> Self {
+        Self {
+            drift_amplitude: 40.0,
+            drift_period: 16.0,
+            heave_amplitude: 6.0,
+            heave_period: 6.0,
+            roll_amplitude: 3f32.to_radians(),
+            roll_period: 7.0,
+        }
+    }
+
+    pub fn rough() -> Self {
+        Self {
+            drift_amplitude: 80.0,
+            drift_period: 12.0,
+            heave_amplitude: 12.0,
+            heave_period: 5.0,
+            roll_amplitude: 6f32.to_radians(),
+            roll_period: 6.0,
+        }
+    }
+
+    /// `calm`, `moderate`, `rough` ou `custom:<dérive>:<pilonnement>:<roulis °>`
+    /// (amplitudes, périodes des préréglages modérés).
+    pub fn from_spec(spec: &str) -> Result<Self, String> {
+        match spec {
+            "calm" | "calme" => return Ok(Self::calm()),
+            "moderate" | "moderee" => return Ok(Self::moderate()),
+            "rough" | "forte" => return Ok(Self::rough()),
+            _ => {}
+        }
+
+        let parts: Vec<&str> = spec.split(':').collect();
+        if parts[0] != "custom" || parts.len() != 4 {
+            return Err(format!("état de mer inconnu: '{}'", spec));
+        }
+        let number = |s: &str| s.parse::<f32>().map_err(|_| format!("nombre invalide '{}' dans '{}'", s, spec));
+        Ok(Self {
+            drift_amplitude: number(parts[1])?,
+            heave_amplitude: number(parts[2])?,
+            roll_amplitude: number(parts[3])?.to_radians(),
+            ..Self::moderate()
+        })
+    }
+
+    /// Mouvement du pont à l'instant `t` pour une barge ancrée en `anchor` (centre du pont au repos).
+    pub fn deck_motion(&self, anchor: Vec2, phase: f32, t: f32) -> DeckMotion {
+        let wave = |amplitude: f32, period: f32, offset: f32| {
+            let omega = TAU / period;
+            let argument = omega * t + offset;
+            (amplitude * argument.sin(), amplitude * omega * argument.cos())
+        };
+
+        let (drift, drift_rate) = wave(self.drift_amplitude, self.drift_period, phase);
+        let (heave, heave_rate) = wave(self.heave_amplitude, self.heave_period, 2.0 * phase);
+        let (roll, roll_rate) = wave(self.roll_amplitude, self.roll_period, 3.0 * phase);
+
+        DeckMotion {
+            position: anchor + Vec2::new(drift, heave),
+            velocity: Vec2::new(drift_rate, heave_rate),
+            tilt: roll,
+            angular_velocity: roll_rate,
+        }
+    }
+}
+
+/// Position, vitesse et inclinaison instantanées d'un pont.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct DeckMotion {
+    pub position: Vec2,
+    pub velocity: Vec2,
+    pub tilt: f32,
+    pub angular_velocity: f32,
+}
+
+/// Barge autonome : la plateforme portant ce composant suit la houle.
+#[derive(Component, Clone, Copy, Debug)]
+pub struct DroneShip {
+    pub anchor: Vec2,
+    /// Déphasage propre à chaque barge, pour qu'elles ne bougent pas à l'unisson.
+    pub phase: f32,
+}
+
+impl DroneShip {
+    /// Niveau de la mer autour de la barge.
+    pub fn sea_level(&self) -> f32 {
+        self.anchor.y - DECK_FREEBOARD
+    }
+
+    pub fn covers(&self, x: f32) -> bool {
+        (x - self.anchor.x).abs() <= SEA_HALF_WIDTH
+    }
+}
+
+/// Avance de la houle au début de l'épisode (s), tirée dans le générateur des épisodes : la mer
+/// ne dépend que de la graine et du temps de vol, pas des épisodes précédents.
+#[derive(Resource, Clone, Copy, Debug, Default, PartialEq)]
+pub struct SeaPhase {
+    pub offset: f32,
+}
+
+impl SeaPhase {
+    pub fn sample(rng: &mut impl Rng) -> Self {
+        Self { offset: rng.random_range(0.0..SEA_PHASE_SPAN) }
+    }
+}
+
+pub struct DroneShipPlugin;
+
+impl Plugin for DroneShipPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<SeaState>()
+           .init_resource::<EpisodeRng>();
+        // Première houle tirée comme les suivantes, dans le générateur des épisodes
+        let phase = SeaPhase::sample(&mut app.world.resource_mut::<EpisodeRng>().0);
+
+        app.insert_resource(phase)
+           .add_systems(Update, (
+               move_drone_ships.before(ground_contact),
+               sample_sea_phase.run_if(resource_equals(GameState::Restarting)).before(restart_system),
+           ));
+    }
+}
+
+fn sample_sea_phase(mut phase: ResMut<SeaPhase>, mut rng: ResMut<EpisodeRng>) {
+    *phase = SeaPhase::sample(&mut rng.0);
+}
+
+// La houle suit le temps de vol de l'épisode : figée entre deux épisodes
+fn move_drone_ships(
+    mut ships: Query<(&DroneShip, &mut LandingPad, &mut LandingZone, &mut Transform)>,
+    mut active_pad: ResMut<ActivePad>,
+    sea: Res<SeaState>,
+    phase: Res<SeaPhase>,
+    clock: Res<EpisodeClock>,
+) {
+    let t = clock.elapsed + phase.offset;
+
+    for (ship, mut pad, mut zone, mut transform) in ships.iter_mut() {
+        let motion = sea.deck_motion(ship.anchor, ship.phase, t);
+
+        pad.position = motion.position;
+        pad.velocity = motion.velocity;
+        pad.tilt = motion.tilt;
+        pad.angular_velocity = motion.angular_velocity;
+
+        zone.x_min = motion.position.x - pad.width / 2.0;
+        zone.x_max = motion.position.x + pad.width / 2.0;
+
+        transform.translation.x = motion.position.x;
+        transform.translation.y = motion.position.y - 3.0;
+        transform.rotation = Quat::from_rotation_z(motion.tilt);
+
+        if pad.index == active_pad.index {
+            active_pad.position = motion.position;
+            active_pad.velocity = motion.velocity;
+            active_pad.tilt = motion.tilt;
+            active_pad.angular_velocity = motion.angular_velocity;
+        }
+    }
+}
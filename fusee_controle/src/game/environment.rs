@@ -1,16 +1,148 @@
 use bevy::prelude::*;
 use bevy::sprite::{MaterialMesh2dBundle, Mesh2dHandle};
+use rand::Rng;
+use crate::game::drone_ship::{DeckMotion, DroneShip, DECK_FREEBOARD, SEA_DEPTH, SEA_HALF_WIDTH};
+use crate::game::curriculum::Curriculum;
+use crate::game::episode::EpisodeRng;
+use crate::game::planet::CelestialBody;
+use crate::game::rocket::restart_system;
+use crate::game::terrain::Terrain;
+use crate::game::GameState;
+use crate::rl_agent::metrics::MetricsSettings;
 
-#[derive(Component)]
-pub struct Ground;
+// Niveau de référence du sol (terrain plat, base de l'atmosphère)
+pub const GROUND_LEVEL: f32 = -340.0;
 
 #[derive(Component)]
-pub struct ScreenBoundary;
+pub struct Ground;
 
 #[derive(Component)]
 pub struct LandingPad {
+    pub index: usize,
+    pub position: Vec2, // Centre de la surface de la plateforme
+    pub width: f32,
+    // Mouvement du pont (nul pour une plateforme au sol, voir drone_ship.rs)
+    pub velocity: Vec2,
+    pub tilt: f32,
+    pub angular_velocity: f32,
+}
+
+impl LandingPad {
+    /// Demi-largeur du pont porteur (plateforme grise, plus large que la zone).
+    pub fn deck_half_width(&self) -> f32 {
+        self.width / 2.0 + 10.0
+    }
+
+    /// Hauteur du pont à l'abscisse `x`, ou `None` en dehors du pont.
+    pub fn deck_height_at(&self, x: f32) -> Option<f32> {
+        let along = (x - self.position.x) / self.tilt.cos();
+        (along.abs() <= self.deck_half_width()).then(|| self.position.y + (x - self.position.x) * self.tilt.tan())
+    }
+
+    pub fn motion(&self) -> DeckMotion {
+        DeckMotion {
+            position: self.position,
+            velocity: self.velocity,
+            tilt: self.tilt,
+            angular_velocity: self.angular_velocity,
+        }
+    }
+}
+
+/// Plateforme demandée : position horizontale et largeur de la zone d'atterrissage.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct PadSpec {
+    pub x: f32,
+    pub width: f32,
+    /// Barge flottante soumise à la houle plutôt que plateforme au sol.
+    pub drone_ship: bool,
+}
+
+impl PadSpec {
+    /// Hauteur de la surface de la plateforme au repos.
+    pub fn surface_height(&self, terrain: &Terrain) -> f32 {
+        let ground = terrain.height_at(self.x);
+        if self.drone_ship { ground + SEA_DEPTH + DECK_FREEBOARD } else { ground }
+    }
+}
+
+/// Plateformes de la session, posées sur le terrain au démarrage.
+#[derive(Resource, Clone, Debug)]
+pub struct PadLayout {
+    pub pads: Vec<PadSpec>,
+}
+
+impl Default for PadLayout {
+    fn default() -> Self {
+        Self {
+            pads: vec![PadSpec { x: 0.0, width: 80.0, drone_ship: false }],
+        }
+    }
+}
+
+impl PadLayout {
+    /// Liste `x:largeur[:ship]` séparée par des virgules, par ex. `-300:80,0:80,250:60:ship`.
+    pub fn from_spec(spec: &str) -> Result<Self, String> {
+        let pads = spec
+            .split(',')
+            .map(|pad| {
+                let fields: Vec<&str> = pad.split(':').collect();
+                let number = |s: &str| s.trim().parse::<f32>().map_err(|_| format!("nombre invalide '{}' dans '{}'", s, pad));
+                let drone_ship = match fields[..] {
+                    [_, _] => false,
+                    [_, _, "ship"] => true,
+                    _ => return Err(format!("plateforme invalide '{}' (attendu x:largeur[:ship])", pad)),
+                };
+                Ok(PadSpec { x: number(fields[0])?, width: number(fields[1])?, drone_ship })
+            })
+            .collect::<Result<Vec<_>, String>>()?;
+        Ok(Self { pads })
+    }
+}
+
+/// Choix de la plateforme cible à chaque épisode.
+#[derive(Resource, Clone, Copy, Debug, Default, PartialEq)]
+pub enum TargetSelection {
+    #[default]
+    First,
+    Fixed(usize),
+    Random,
+}
+
+impl TargetSelection {
+    pub fn from_spec(spec: &str) -> Result<Self, String> {
+        match spec {
+            "random" => Ok(Self::Random),
+            index => index
+                .parse()
+                .map(Self::Fixed)
+                .map_err(|_| format!("cible invalide: '{}' (index ou 'random')", spec)),
+        }
+    }
+}
+
+/// Plateforme visée pendant l'épisode courant ; observations et récompenses s'y rapportent.
+#[derive(Resource, Clone, Copy, Debug)]
+pub struct ActivePad {
+    pub index: usize,
     pub position: Vec2,
     pub width: f32,
+    pub velocity: Vec2,
+    pub tilt: f32,
+    pub angular_velocity: f32,
+}
+
+impl Default for ActivePad {
+    fn default() -> Self {
+        Self {
+            index: 0,
+            position: Vec2::new(0.0, GROUND_LEVEL),
+            width: 80.0,
+            velocity: Vec2::ZERO,
+            tilt: 0.0,
+            angular_velocity: 0.0,
+        }
+    }
 }
 
 #[derive(Component)]
@@ -19,102 +151,203 @@ pub struct LandingZone {
     pub x_max: f32,
 }
 
+/// Paramètres du vent horizontal : vitesse moyenne et intensité des rafales (px/s).
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct WindSettings {
+    pub mean_speed: f32,
+    pub gust_strength: f32,
+}
+
+impl WindSettings {
+    pub fn calm() -> Self {
+        Self {
+            mean_speed: 0.0,
+            gust_strength: 0.0,
+        }
+    }
+}
+
+/// Vent courant, vu par la traînée aérodynamique.
+#[derive(Resource, Default)]
+pub struct Wind {
+    pub velocity: Vec2,
+    pub gust: f32,
+}
+
+// Constante de temps des rafales (s)
+const GUST_TIME_CONSTANT: f32 = 2.0;
+
 pub struct EnvironmentPlugin;
 
 impl Plugin for EnvironmentPlugin {
     fn build(&self, app: &mut App) {
-        app.add_systems(Startup, spawn_environment)
-           .add_systems(Update, keep_rocket_on_screen);
+        app.init_resource::<Terrain>()
+           .init_resource::<PadLayout>()
+           .init_resource::<TargetSelection>()
+           .init_resource::<ActivePad>()
+           .init_resource::<Wind>()
+           .add_systems(PreStartup, (prepare_landing_site, select_target_pad).chain())
+           .add_systems(Startup, spawn_environment)
+           .add_systems(Update, (
+               update_wind,
+               select_target_pad.run_if(resource_equals(GameState::Restarting)).before(restart_system),
+               highlight_active_pad,
+           ));
+    }
+}
+
+// Aplanit le terrain sous chaque plateforme avant que quoi que ce soit ne s'y pose
+fn prepare_landing_site(mut terrain: ResMut<Terrain>, layout: Res<PadLayout>) {
+    // Les barges flottent : le fond marin garde son relief
+    for pad in layout.pads.iter().filter(|pad| !pad.drone_ship) {
+        let height = terrain.height_at(pad.x);
+        terrain.flatten(pad.x, pad.width + 20.0, height);
+    }
+}
+
+fn select_target_pad(
+    mut active_pad: ResMut<ActivePad>,
+    layout: Res<PadLayout>,
+    selection: Res<TargetSelection>,
+    terrain: Res<Terrain>,
+    mut rng: ResMut<EpisodeRng>,
+    metrics: Res<MetricsSettings>,
+) {
+    let index = match *selection {
+        TargetSelection::First => 0,
+        TargetSelection::Fixed(index) => index.min(layout.pads.len() - 1),
+        TargetSelection::Random => rng.0.random_range(0..layout.pads.len()),
+    };
+    let pad = layout.pads[index];
+
+    // Une barge met à jour position et mouvement à chaque image (voir drone_ship.rs)
+    *active_pad = ActivePad {
+        index,
+        position: Vec2::new(pad.x, pad.surface_height(&terrain)),
+        width: pad.width,
+        ..default()
+    };
+    if layout.pads.len() > 1 && metrics.shows_flight_events() {
+        println!("🎯 Plateforme cible: #{} (x = {:.0})", index, pad.x);
     }
 }
 
+// La zone JAUNE marque la plateforme visée, les autres restent grisées
+fn highlight_active_pad(
+    active_pad: Res<ActivePad>,
+    zones: Query<(&LandingPad, &Handle<ColorMaterial>), With<LandingZone>>,
+    mut materials: ResMut<Assets<ColorMaterial>>,
+) {
+    if !active_pad.is_changed() {
+        return;
+    }
+
+    for (pad, material) in zones.iter() {
+        if let Some(material) = materials.get_mut(material) {
+            material.color = if pad.index == active_pad.index {
+                Color::rgb(1.0, 1.0, 0.0)
+            } else {
+                Color::rgb(0.5, 0.5, 0.3)
+            };
+        }
+    }
+}
+
+fn update_wind(
+    mut wind: ResMut<Wind>,
+    body: Res<CelestialBody>,
+    curriculum: Res<Curriculum>,
+    mut rng: ResMut<EpisodeRng>,
+    time: Res<Time>,
+) {
+    let dt = time.delta_seconds();
+    let settings = body.wind;
+
+    // Rafales : processus d'Ornstein-Uhlenbeck autour du vent moyen
+    let noise = rng.0.random_range(-1.0..1.0) * 3.0_f32.sqrt();
+    wind.gust += -wind.gust / GUST_TIME_CONSTANT * dt
+        + settings.gust_strength * (2.0 * dt / GUST_TIME_CONSTANT).sqrt() * noise;
+
+    wind.velocity = Vec2::new(settings.mean_speed + wind.gust, 0.0) * curriculum.stage().wind_scale;
+}
+
 fn spawn_environment(
     mut commands: Commands,
     mut meshes: ResMut<Assets<Mesh>>,
     mut materials: ResMut<Assets<ColorMaterial>>,
+    terrain: Res<Terrain>,
+    layout: Res<PadLayout>,
 ) {
-    // Sol vert foncé
+    // Sol vert foncé, suivant le relief
     commands.spawn((
         MaterialMesh2dBundle {
-            mesh: Mesh2dHandle(meshes.add(shape::Box::new(2000.0, 20.0, 0.0).into())),
+            mesh: Mesh2dHandle(meshes.add(terrain.mesh())),
             material: materials.add(ColorMaterial::from(Color::rgb(0.1, 0.5, 0.1))),
-            transform: Transform::from_xyz(0.0, -350.0, 0.0),
+            transform: Transform::from_xyz(0.0, 0.0, 0.0),
             ..default()
         },
         Ground,
     ));
 
-    // ZONE D'ATTERRISSAGE ÉLARGIE (80 pixels de large au lieu de 60)
-    commands.spawn((
-        MaterialMesh2dBundle {
-            mesh: Mesh2dHandle(meshes.add(shape::Box::new(80.0, 10.0, 0.0).into())), // 80 au lieu de 60
-            material: materials.add(ColorMaterial::from(Color::rgb(1.0, 1.0, 0.0))), // JAUNE VIF
-            transform: Transform::from_xyz(0.0, -340.0, 1.0),
-            ..default()
-        },
-        LandingZone {
-            x_min: -40.0, // ÉLARGI
-            x_max: 40.0,  // ÉLARGI
-        },
-    ));
+    for (index, pad) in layout.pads.iter().enumerate() {
+        let surface = pad.surface_height(&terrain);
 
-    // Plateforme grise (visuelle seulement)
-    commands.spawn((
-        MaterialMesh2dBundle {
-            mesh: Mesh2dHandle(meshes.add(shape::Box::new(100.0, 8.0, 0.0).into())), // Légèrement plus large
-            material: materials.add(ColorMaterial::from(Color::rgb(0.4, 0.4, 0.4))),
-            transform: Transform::from_xyz(0.0, -340.0, 0.5),
-            ..default()
-        },
-        LandingPad {
-            position: Vec2::new(0.0, -340.0),
-            width: 100.0,
-        },
-    ));
-
-    create_screen_boundaries(&mut commands, &mut meshes, &mut materials);
-}
-
-fn create_screen_boundaries(
-    commands: &mut Commands,
-    meshes: &mut ResMut<Assets<Mesh>>,
-    materials: &mut ResMut<Assets<ColorMaterial>>,
-) {
-    let screen_width = 1200.0;
-    let screen_height = 800.0;
-    let boundary_thickness = 50.0;
-
-    // Limites invisibles autour de l'écran
-    let boundaries = [
-        (-screen_width / 2.0 - boundary_thickness / 2.0, 0.0, boundary_thickness, screen_height * 2.0), // Gauche
-        (screen_width / 2.0 + boundary_thickness / 2.0, 0.0, boundary_thickness, screen_height * 2.0), // Droite
-        (0.0, screen_height / 2.0 + boundary_thickness / 2.0, screen_width * 2.0, boundary_thickness), // Haut
-    ];
-
-    for (x, y, width, height) in boundaries {
-        commands.spawn((
+        // Zone d'atterrissage (JAUNE pour la cible active), la plateforme suit ses mouvements
+        let mut zone = commands.spawn((
             MaterialMesh2dBundle {
-                mesh: Mesh2dHandle(meshes.add(shape::Box::new(width, height, 0.0).into())),
-                material: materials.add(ColorMaterial::from(Color::rgba(0.0, 0.0, 0.0, 0.0))),
-                transform: Transform::from_xyz(x, y, 0.0),
+                mesh: Mesh2dHandle(meshes.add(shape::Box::new(pad.width, 6.0, 0.0).into())),
+                material: materials.add(ColorMaterial::from(Color::rgb(1.0, 1.0, 0.0))),
+                transform: Transform::from_xyz(pad.x, surface - 3.0, 1.0),
                 ..default()
             },
-            ScreenBoundary,
+            LandingZone {
+                x_min: pad.x - pad.width / 2.0,
+                x_max: pad.x + pad.width / 2.0,
+            },
+            LandingPad {
+                index,
+                position: Vec2::new(pad.x, surface),
+                width: pad.width,
+                velocity: Vec2::ZERO,
+                tilt: 0.0,
+                angular_velocity: 0.0,
+            },
         ));
-    }
-}
 
-fn keep_rocket_on_screen(
-    mut rocket_query: Query<&mut Transform, (With<crate::game::rocket::RocketMainBody>, Without<ScreenBoundary>)>,
-) {
-    if let Ok(mut transform) = rocket_query.get_single_mut() {
-        let screen_width = 1200.0;
-        let screen_height = 800.0;
-        
-        // Empêche de sortir des côtés
-        transform.translation.x = transform.translation.x.clamp(-screen_width / 2.0 + 40.0, screen_width / 2.0 - 40.0);
-        
-        // Empêche de sortir par le haut
-        transform.translation.y = transform.translation.y.clamp(-screen_height, screen_height / 2.0 - 50.0);
+        zone.with_children(|parent| {
+            // Plateforme grise, légèrement plus large que la zone
+            parent.spawn(MaterialMesh2dBundle {
+                mesh: Mesh2dHandle(meshes.add(shape::Box::new(pad.width + 20.0, 8.0, 0.0).into())),
+                material: materials.add(ColorMaterial::from(Color::rgb(0.4, 0.4, 0.4))),
+                transform: Transform::from_xyz(0.0, -1.0, -0.5),
+                ..default()
+            });
+
+            // Coque de la barge, sous le pont
+            if pad.drone_ship {
+                parent.spawn(MaterialMesh2dBundle {
+                    mesh: Mesh2dHandle(meshes.add(shape::Box::new(pad.width + 40.0, 24.0, 0.0).into())),
+                    material: materials.add(ColorMaterial::from(Color::rgb(0.15, 0.15, 0.2))),
+                    transform: Transform::from_xyz(0.0, -17.0, -0.6),
+                    ..default()
+                });
+            }
+        });
+
+        if pad.drone_ship {
+            zone.insert(DroneShip {
+                anchor: Vec2::new(pad.x, surface),
+                phase: index as f32 * 1.7,
+            });
+
+            // Mer bleue autour de la barge, devant le fond marin
+            let sea_level = surface - DECK_FREEBOARD;
+            commands.spawn(MaterialMesh2dBundle {
+                mesh: Mesh2dHandle(meshes.add(shape::Box::new(2.0 * SEA_HALF_WIDTH, 200.0, 0.0).into())),
+                material: materials.add(ColorMaterial::from(Color::rgba(0.1, 0.3, 0.7, 0.8))),
+                transform: Transform::from_xyz(pad.x, sea_level - 100.0, 0.2),
+                ..default()
+            });
+        }
     }
-}
\ No newline at end of file
+}
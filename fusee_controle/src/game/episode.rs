@@ -0,0 +1,158 @@
+use bevy::prelude::*;
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+use crate::game::outcome::{CrashReason, Touchdown, TouchdownMetrics, TouchdownOutcome};
+use crate::game::randomization::EpisodeParameters;
+use crate::game::rocket::{restart_system, Rocket, RocketMainBody, RocketStats};
+use crate::game::GameState;
+use crate::rl_agent::metrics::MetricsSettings;
+use crate::settings::{parse_value, SettingsFile};
+
+/// Limites d'un épisode : durée maximale et zone de vol autorisée.
+#[derive(Resource, Clone, Copy, Debug, PartialEq)]
+pub struct EpisodeLimits {
+    /// Au-delà, l'épisode est tronqué (pas un échec de pilotage).
+    pub max_duration: f32,
+    /// Demi-largeur de la zone de vol, centrée sur l'écran.
+    pub half_width: f32,
+    /// Altitude au-dessus de laquelle la fusée est perdue.
+    pub ceiling: f32,
+}
+
+impl Default for EpisodeLimits {
+    fn default() -> Self {
+        Self {
+            max_duration: 40.0,
+            half_width: 600.0,
+            ceiling: 450.0,
+        }
+    }
+}
+
+impl EpisodeLimits {
+    /// Lit la section `[episode]` du fichier de configuration.
+    pub fn from_settings(settings: &SettingsFile) -> Result<Self, String> {
+        let mut limits = Self::default();
+        settings.apply_section("episode", |key, value| {
+            let field = match key {
+                "max_duration" => &mut limits.max_duration,
+                "half_width" => &mut limits.half_width,
+                "ceiling" => &mut limits.ceiling,
+                _ => return Ok(false),
+            };
+            *field = parse_value(key, value)?;
+            Ok(true)
+        })?;
+        Ok(limits)
+    }
+
+    pub fn contains(&self, position: Vec2) -> bool {
+        position.x.abs() <= self.half_width && position.y <= self.ceiling
+    }
+}
+
+/// Temps de vol écoulé dans l'épisode courant.
+#[derive(Resource, Default)]
+pub struct EpisodeClock {
+    pub elapsed: f32,
+}
+
+/// Générateur des tirages d'un épisode : départ, paramètres physiques, corps céleste, cible et rafales.
+/// Aléatoire par défaut ; l'évaluation le réensemence avant chaque épisode.
+#[derive(Resource)]
+pub struct EpisodeRng(pub StdRng);
+
+impl Default for EpisodeRng {
+    fn default() -> Self {
+        Self(StdRng::from_rng(&mut rand::rng()))
+    }
+}
+
+impl EpisodeRng {
+    pub fn seeded(seed: u64) -> Self {
+        Self(StdRng::seed_from_u64(seed))
+    }
+}
+
+pub struct EpisodePlugin;
+
+impl Plugin for EpisodePlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<EpisodeLimits>()
+           .init_resource::<EpisodeClock>()
+           .init_resource::<EpisodeRng>()
+           .add_systems(Update, (
+               enforce_episode_limits,
+               reset_episode_clock.run_if(resource_equals(GameState::Restarting)).before(restart_system),
+           ));
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+fn enforce_episode_limits(
+    mut game_state: ResMut<GameState>,
+    mut clock: ResMut<EpisodeClock>,
+    mut rocket_query: Query<(&Transform, &mut Rocket), With<RocketMainBody>>,
+    limits: Res<EpisodeLimits>,
+    parameters: Res<EpisodeParameters>,
+    mut stats: ResMut<RocketStats>,
+    mut touchdowns: EventWriter<Touchdown>,
+    metrics_settings: Res<MetricsSettings>,
+    time: Res<Time>,
+) {
+    if *game_state != GameState::Playing {
+        return;
+    }
+    let Ok((transform, mut rocket)) = rocket_query.get_single_mut() else {
+        return;
+    };
+    if rocket.has_crashed || rocket.has_landed {
+        return;
+    }
+
+    clock.elapsed += time.delta_seconds();
+    let metrics = TouchdownMetrics {
+        fuel_remaining: rocket.fuel / rocket.max_fuel,
+        ..default()
+    };
+
+    // Sortie de la zone de vol : fin définitive de l'épisode
+    if !limits.contains(transform.translation.truncate()) {
+        rocket.has_crashed = true;
+        stats.total_crashes += 1;
+        stats.consecutive_successes = 0;
+        if metrics_settings.shows_flight_events() {
+            println!("💥 PERDUE! Sortie de la zone de vol ({:.0}, {:.0})", transform.translation.x, transform.translation.y);
+        }
+
+        let mut reasons = vec![CrashReason::OutOfBounds];
+        if rocket.fuel <= 0.0 {
+            reasons.push(CrashReason::FuelExhausted);
+        }
+        *game_state = GameState::Crashed;
+        touchdowns.send(Touchdown {
+            outcome: TouchdownOutcome::Crashed,
+            reasons,
+            metrics,
+            parameters: *parameters,
+        });
+    } else if clock.elapsed >= limits.max_duration {
+        // Temps écoulé : épisode tronqué, la fusée volait encore
+        stats.consecutive_successes = 0;
+        if metrics_settings.shows_flight_events() {
+            println!("⏱️ TEMPS ÉCOULÉ après {:.0} s de vol", clock.elapsed);
+        }
+
+        *game_state = GameState::TimedOut;
+        touchdowns.send(Touchdown {
+            outcome: TouchdownOutcome::Truncated,
+            reasons: vec![CrashReason::Timeout],
+            metrics,
+            parameters: *parameters,
+        });
+    }
+}
+
+fn reset_episode_clock(mut clock: ResMut<EpisodeClock>) {
+    clock.elapsed = 0.0;
+}
@@ -0,0 +1,374 @@
+use bevy::prelude::*;
+use crate::game::environment::ActivePad;
+use crate::game::outcome::Touchdown;
+use crate::game::planet::CelestialBody;
+use crate::game::rocket::{restart_system, RocketStats};
+use crate::game::sensors::{read_sensors, Channel, SensorModel, Sensors};
+use crate::game::terrain::Terrain;
+use crate::game::GameState;
+use crate::rl_agent::metrics::MetricsSettings;
+use crate::settings::{parse_value, SettingsFile};
+
+// Indices de l'état estimé : position et vitesse dans le repère monde, inclinaison et vitesse angulaire
+const X: usize = 0;
+const Y: usize = 1;
+const VX: usize = 2;
+const VY: usize = 3;
+const ANGLE: usize = 4;
+const RATE: usize = 5;
+const STATE_SIZE: usize = 6;
+
+// Variance minimale d'une mesure, même pour un capteur déclaré parfait
+const MIN_MEASUREMENT_VARIANCE: f32 = 1.0e-4;
+
+type Matrix = [[f32; STATE_SIZE]; STATE_SIZE];
+
+/// Réglages de l'estimateur, section `[estimator]`.
+#[derive(Resource, Clone, Copy, Debug, PartialEq)]
+pub struct EstimatorSettings {
+    pub enabled: bool,
+    /// La politique observe l'estimation au lieu de la mesure brute des capteurs.
+    pub policy_input: bool,
+    /// Écart-type des accélérations non modélisées (px/s²) : traînée, contacts, erreurs de gravité.
+    pub acceleration_noise: f32,
+    /// Écart-type des accélérations angulaires non modélisées (rad/s²) : propulseurs d'attitude.
+    pub angular_acceleration_noise: f32,
+}
+
+impl Default for EstimatorSettings {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            policy_input: false,
+            acceleration_noise: 5.0,
+            angular_acceleration_noise: 10.0,
+        }
+    }
+}
+
+impl EstimatorSettings {
+    pub fn from_settings(settings: &SettingsFile) -> Result<Self, String> {
+        let mut estimator = Self::default();
+        settings.apply_section("estimator", |key, value| {
+            match key {
+                "enabled" => estimator.enabled = parse_value(key, value)?,
+                "policy_input" => estimator.policy_input = parse_value(key, value)?,
+                "acceleration_noise" => estimator.acceleration_noise = parse_value(key, value)?,
+                "angular_acceleration_noise" => estimator.angular_acceleration_noise = parse_value(key, value)?,
+                _ => return Ok(false),
+            }
+            Ok(true)
+        })?;
+        // Fournir l'estimation à la politique impose de la calculer
+        estimator.enabled |= estimator.policy_input;
+        Ok(estimator)
+    }
+}
+
+/// Filtre de Kalman étendu sur les équations du mouvement de la fusée.
+///
+/// La prédiction intègre l'accélération spécifique mesurée par la centrale inertielle, tournée
+/// dans le repère monde par l'inclinaison estimée, plus la gravité du corps céleste : c'est
+/// `apply_rocket_thrust` et la traînée de `apply_physics` vues par l'accéléromètre. Les mesures
+/// sont fusionnées une à une (bruits indépendants), ce qui évite toute inversion de matrice.
+#[derive(Clone, Debug)]
+pub struct ExtendedKalmanFilter {
+    pub state: [f32; STATE_SIZE],
+    pub covariance: Matrix,
+}
+
+impl ExtendedKalmanFilter {
+    pub fn new(state: [f32; STATE_SIZE], variances: [f32; STATE_SIZE]) -> Self {
+        let mut covariance = [[0.0; STATE_SIZE]; STATE_SIZE];
+        for (i, variance) in variances.into_iter().enumerate() {
+            covariance[i][i] = variance;
+        }
+        Self { state, covariance }
+    }
+
+    pub fn position(&self) -> Vec2 {
+        Vec2::new(self.state[X], self.state[Y])
+    }
+
+    pub fn velocity(&self) -> Vec2 {
+        Vec2::new(self.state[VX], self.state[VY])
+    }
+
+    /// Inclinaison ramenée dans ]-π, π].
+    pub fn angle(&self) -> f32 {
+        wrap_angle(self.state[ANGLE])
+    }
+
+    pub fn angular_velocity(&self) -> f32 {
+        self.state[RATE]
+    }
+
+    /// Propage l'état de `dt` avec l'accélération spécifique `specific_force` (repère fusée).
+    pub fn predict(&mut self, specific_force: Vec2, gravity: f32, dt: f32, settings: &EstimatorSettings) {
+        let (sin, cos) = self.state[ANGLE].sin_cos();
+        let world_force = Vec2::new(
+            cos * specific_force.x - sin * specific_force.y,
+            sin * specific_force.x + cos * specific_force.y,
+        );
+
+        self.state[X] += self.state[VX] * dt;
+        self.state[Y] += self.state[VY] * dt;
+        self.state[VX] += world_force.x * dt;
+        self.state[VY] += (world_force.y + gravity) * dt;
+        self.state[ANGLE] += self.state[RATE] * dt;
+
+        // Jacobienne de la transition
+        let mut f = identity();
+        f[X][VX] = dt;
+        f[Y][VY] = dt;
+        f[ANGLE][RATE] = dt;
+        f[VX][ANGLE] = -world_force.y * dt;
+        f[VY][ANGLE] = world_force.x * dt;
+
+        // Bruit de processus : accélérations non modélisées, intégrées sur le pas
+        let acceleration = (settings.acceleration_noise * dt).powi(2);
+        let angular = (settings.angular_acceleration_noise * dt).powi(2);
+        let mut q = [[0.0; STATE_SIZE]; STATE_SIZE];
+        q[X][X] = acceleration * dt * dt / 4.0;
+        q[Y][Y] = acceleration * dt * dt / 4.0;
+        q[VX][VX] = acceleration;
+        q[VY][VY] = acceleration;
+        q[ANGLE][ANGLE] = angular * dt * dt / 4.0;
+        q[RATE][RATE] = angular;
+
+        let propagated = multiply(&multiply(&f, &self.covariance), &transpose(&f));
+        for i in 0..STATE_SIZE {
+            for j in 0..STATE_SIZE {
+                self.covariance[i][j] = propagated[i][j] + q[i][j];
+            }
+        }
+    }
+
+    /// Fusionne une mesure scalaire : `h` est la ligne de la jacobienne de la mesure,
+    /// `innovation` l'écart entre la mesure et sa prédiction.
+    pub fn update(&mut self, h: [f32; STATE_SIZE], innovation: f32, variance: f32) {
+        let ph: Vec<f32> = (0..STATE_SIZE)
+            .map(|i| (0..STATE_SIZE).map(|j| self.covariance[i][j] * h[j]).sum())
+            .collect();
+        let s = h.iter().zip(&ph).map(|(h, ph)| h * ph).sum::<f32>() + variance;
+        if s <= f32::EPSILON {
+            return;
+        }
+
+        let gain: Vec<f32> = ph.iter().map(|ph| ph / s).collect();
+        for (state, k) in self.state.iter_mut().zip(&gain) {
+            *state += k * innovation;
+        }
+        // P = P - K (H P), H P étant la transposée de P Hᵀ (P symétrique)
+        for (row, k) in self.covariance.iter_mut().zip(&gain) {
+            for (p, ph) in row.iter_mut().zip(&ph) {
+                *p -= k * ph;
+            }
+        }
+    }
+}
+
+fn identity() -> Matrix {
+    let mut m = [[0.0; STATE_SIZE]; STATE_SIZE];
+    for (i, row) in m.iter_mut().enumerate() {
+        row[i] = 1.0;
+    }
+    m
+}
+
+fn multiply(a: &Matrix, b: &Matrix) -> Matrix {
+    let mut m = [[0.0; STATE_SIZE]; STATE_SIZE];
+    for i in 0..STATE_SIZE {
+        for j in 0..STATE_SIZE {
+            m[i][j] = (0..STATE_SIZE).map(|k| a[i][k] * b[k][j]).sum();
+        }
+    }
+    m
+}
+
+fn transpose(a: &Matrix) -> Matrix {
+    let mut m = [[0.0; STATE_SIZE]; STATE_SIZE];
+    for i in 0..STATE_SIZE {
+        for j in 0..STATE_SIZE {
+            m[i][j] = a[j][i];
+        }
+    }
+    m
+}
+
+fn wrap_angle(angle: f32) -> f32 {
+    angle.sin().atan2(angle.cos())
+}
+
+fn unit(index: usize) -> [f32; STATE_SIZE] {
+    let mut h = [0.0; STATE_SIZE];
+    h[index] = 1.0;
+    h
+}
+
+// Variance d'une voie : bruit blanc et erreur de quantification (uniforme sur un pas)
+fn measurement_variance(model: &SensorModel, channel: Channel) -> f32 {
+    let errors = model.errors(channel);
+    (errors.noise.powi(2) + errors.quantization.powi(2) / 12.0).max(MIN_MEASUREMENT_VARIANCE)
+}
+
+/// Erreurs quadratiques cumulées de l'estimation sur l'épisode, par rapport à l'état exact.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct EstimationErrors {
+    pub position: f32,
+    pub velocity: f32,
+    pub angle: f32,
+    pub samples: u32,
+}
+
+impl EstimationErrors {
+    /// Erreurs quadratiques moyennes (position px, vitesse px/s, angle rad).
+    pub fn rms(&self) -> (f32, f32, f32) {
+        let n = self.samples.max(1) as f32;
+        ((self.position / n).sqrt(), (self.velocity / n).sqrt(), (self.angle / n).sqrt())
+    }
+}
+
+/// Estimation de l'état de la fusée à partir des capteurs.
+#[derive(Resource, Default)]
+pub struct StateEstimator {
+    pub settings: EstimatorSettings,
+    pub filter: Option<ExtendedKalmanFilter>,
+    /// Mesure des capteurs dont position, vitesses, angle et hauteur sont remplacés par l'estimation.
+    pub estimate: Option<RocketStats>,
+    pub errors: EstimationErrors,
+}
+
+impl StateEstimator {
+    /// Estimation à fournir à la politique, si elle est configurée pour l'observer.
+    pub fn policy_input(&self) -> Option<&RocketStats> {
+        self.estimate.as_ref().filter(|_| self.settings.policy_input)
+    }
+
+    pub fn reset(&mut self) {
+        self.filter = None;
+        self.estimate = None;
+        self.errors = EstimationErrors::default();
+    }
+}
+
+pub struct EstimatorPlugin;
+
+impl Plugin for EstimatorPlugin {
+    fn build(&self, app: &mut App) {
+        let settings = app.world.get_resource::<EstimatorSettings>().copied().unwrap_or_default();
+
+        app.insert_resource(StateEstimator { settings, ..default() })
+           .add_systems(Update, (
+               run_estimator.after(read_sensors),
+               log_estimation_errors,
+               reset_estimator.run_if(resource_equals(GameState::Restarting)).before(restart_system),
+           ));
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn run_estimator(
+    mut estimator: ResMut<StateEstimator>,
+    sensors: Res<Sensors>,
+    model: Res<SensorModel>,
+    truth: Res<RocketStats>,
+    active_pad: Res<ActivePad>,
+    terrain: Res<Terrain>,
+    body: Res<CelestialBody>,
+    time: Res<Time>,
+    game_state: Res<GameState>,
+) {
+    if !estimator.settings.enabled || *game_state != GameState::Playing {
+        return;
+    }
+    let Some(reading) = sensors.reading.as_ref() else {
+        return;
+    };
+
+    let measured_position = reading.pad_offset + active_pad.position;
+    let measured_velocity = Vec2::new(reading.horizontal_speed, reading.vertical_speed);
+    let settings = estimator.settings;
+
+    let Some(filter) = estimator.filter.as_mut() else {
+        // Première mesure de l'épisode : l'incertitude initiale est celle des capteurs
+        let variance = |channel| measurement_variance(&model, channel);
+        estimator.filter = Some(ExtendedKalmanFilter::new(
+            [
+                measured_position.x,
+                measured_position.y,
+                measured_velocity.x,
+                measured_velocity.y,
+                reading.angle,
+                reading.angular_velocity,
+            ],
+            [
+                variance(Channel::Position),
+                variance(Channel::Position),
+                variance(Channel::Velocity),
+                variance(Channel::Velocity),
+                variance(Channel::Angle),
+                variance(Channel::AngularVelocity),
+            ],
+        ));
+        return;
+    };
+
+    filter.predict(reading.specific_force, body.gravity(), time.delta_seconds(), &settings);
+
+    let position_variance = measurement_variance(&model, Channel::Position);
+    filter.update(unit(X), measured_position.x - filter.state[X], position_variance);
+    filter.update(unit(Y), measured_position.y - filter.state[Y], position_variance);
+
+    let velocity_variance = measurement_variance(&model, Channel::Velocity);
+    filter.update(unit(VX), measured_velocity.x - filter.state[VX], velocity_variance);
+    filter.update(unit(VY), measured_velocity.y - filter.state[VY], velocity_variance);
+
+    let innovation = wrap_angle(reading.angle - filter.state[ANGLE]);
+    filter.update(unit(ANGLE), innovation, measurement_variance(&model, Channel::Angle));
+    filter.update(unit(RATE), reading.angular_velocity - filter.state[RATE], measurement_variance(&model, Channel::AngularVelocity));
+
+    // Radar altimètre : hauteur au-dessus du relief connu, non linéaire en x
+    let (x, y) = (filter.state[X], filter.state[Y]);
+    let slope = (terrain.height_at(x + 1.0) - terrain.height_at(x - 1.0)) / 2.0;
+    let mut h = unit(Y);
+    h[X] = -slope;
+    let predicted_height = y - terrain.height_at(x);
+    filter.update(h, reading.height_above_ground - predicted_height, measurement_variance(&model, Channel::Altitude));
+
+    let (position, velocity, angle, angular_velocity) =
+        (filter.position(), filter.velocity(), filter.angle(), filter.angular_velocity());
+
+    let mut estimate = reading.clone();
+    estimate.pad_offset = position - active_pad.position;
+    estimate.horizontal_speed = velocity.x;
+    estimate.vertical_speed = velocity.y;
+    estimate.angle = angle;
+    estimate.angular_velocity = angular_velocity;
+    estimate.altitude = position.y;
+    estimate.height_above_ground = position.y - terrain.height_at(position.x);
+    estimate.distance_to_target = estimate.pad_offset.length();
+
+    let errors = &mut estimator.errors;
+    errors.position += (estimate.pad_offset - truth.pad_offset).length_squared();
+    errors.velocity += (velocity - Vec2::new(truth.horizontal_speed, truth.vertical_speed)).length_squared();
+    errors.angle += wrap_angle(angle - truth.angle).powi(2);
+    errors.samples += 1;
+
+    estimator.estimate = Some(estimate);
+}
+
+fn log_estimation_errors(estimator: Res<StateEstimator>, metrics: Res<MetricsSettings>, mut touchdowns: EventReader<Touchdown>) {
+    for _ in touchdowns.read() {
+        if estimator.errors.samples == 0 || !metrics.shows_flight_events() {
+            continue;
+        }
+        let (position, velocity, angle) = estimator.errors.rms();
+        println!("🛰️ Erreur d'estimation (RMS sur {} pas): position {:.2} px, vitesse {:.2} px/s, angle {:.2}°",
+                 estimator.errors.samples, position, velocity, angle.to_degrees());
+    }
+}
+
+fn reset_estimator(mut estimator: ResMut<StateEstimator>) {
+    estimator.reset();
+}
@@ -0,0 +1,203 @@
+// Propriétés de la physique et de la dynamique de la fusée, vérifiées sur des cas tirés d'un
+// générateur à graine fixe : un échec se rejoue à l'identique et son message donne le cas fautif.
+// L'intégrateur est d'Euler semi-implicite (vitesse puis position) : ses écarts aux solutions
+// analytiques sont connus exactement et servent de tolérances.
+
+use bevy::prelude::*;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use crate::game::physics::{body_axis, Aerodynamics, Atmosphere};
+use crate::game::randomization::EpisodeParameters;
+use crate::game::rocket::{episode_aerodynamics, rocket_body, Rocket};
+use crate::game::simulation::{
+    attitude_rate, burn_fuel, integrate, step, BodyState, Medium, RocketState, Vehicle, MAX_ANGULAR_VELOCITY, MAX_SPEED,
+};
+
+const CASES: usize = 200;
+
+fn vacuum(gravity: f32) -> Medium {
+    Medium { gravity, atmosphere: Atmosphere::Vacuum, wind: Vec2::ZERO, ground_level: 0.0 }
+}
+
+fn random_dt(rng: &mut StdRng) -> f32 {
+    rng.random_range(1.0 / 240.0..1.0 / 30.0)
+}
+
+fn random_body(rng: &mut StdRng, max_speed: f32) -> BodyState {
+    BodyState {
+        position: Vec2::new(rng.random_range(-500.0..500.0), rng.random_range(-300.0..400.0)),
+        velocity: Vec2::from_angle(rng.random_range(-std::f32::consts::PI..std::f32::consts::PI))
+            * rng.random_range(0.0..max_speed),
+        angle: rng.random_range(-std::f32::consts::PI..std::f32::consts::PI),
+        angular_velocity: rng.random_range(-2.0..2.0),
+    }
+}
+
+// Fusée d'un épisode aux paramètres tirés dans les plages de la randomisation
+fn random_vehicle(rng: &mut StdRng) -> Vehicle {
+    let parameters = EpisodeParameters {
+        dry_mass_scale: rng.random_range(0.7..1.6),
+        engine_power_scale: rng.random_range(0.8..1.2),
+        drag_scale: rng.random_range(0.5..2.0),
+        initial_fuel: rng.random_range(0.05..1.0),
+        ..default()
+    };
+    Vehicle::of(&Rocket::for_episode(&parameters), &rocket_body(Vec2::ZERO, 0.0), &episode_aerodynamics(&parameters))
+}
+
+#[test]
+fn energy_is_conserved_without_thrust_or_drag() {
+    let mut rng = StdRng::seed_from_u64(1);
+    for case in 0..CASES {
+        let gravity = -rng.random_range(20.0..200.0);
+        let dt = random_dt(&mut rng);
+        let start = random_body(&mut rng, 80.0);
+        // Assez court pour ne pas atteindre la vitesse limite
+        let steps = ((MAX_SPEED - 80.0) / -gravity / dt).min(180.0) as usize;
+        let energy = |state: &BodyState| 0.5 * state.velocity.length_squared() - gravity * state.position.y;
+
+        let mut state = start;
+        for _ in 0..steps {
+            integrate(&mut state, &vacuum(gravity), Some(&Aerodynamics::default()), 10.0, dt);
+        }
+        // Chaque pas d'Euler semi-implicite retire exactement g²dt²/2 d'énergie
+        let duration = steps as f32 * dt;
+        let drift = 0.5 * gravity * gravity * dt * duration;
+        let error = energy(&state) - energy(&start) + drift;
+        let tolerance = 1e-4 * (energy(&start).abs() + 0.5 * MAX_SPEED * MAX_SPEED + drift);
+        assert!(error.abs() <= tolerance,
+                "cas {}: g={} dt={} {} pas, écart d'énergie {} (tolérance {})", case, gravity, dt, steps, error, tolerance);
+    }
+}
+
+#[test]
+fn free_fall_matches_analytic_trajectory() {
+    let mut rng = StdRng::seed_from_u64(2);
+    for case in 0..CASES {
+        let gravity = -rng.random_range(1.0..200.0);
+        let dt = random_dt(&mut rng);
+        let start = random_body(&mut rng, 60.0);
+        let steps = ((MAX_SPEED - 60.0) / -gravity / dt).min(240.0) as usize;
+
+        let mut state = start;
+        for _ in 0..steps {
+            integrate(&mut state, &vacuum(gravity), Some(&Aerodynamics::default()), 10.0, dt);
+        }
+        let t = steps as f32 * dt;
+        let acceleration = Vec2::new(0.0, gravity);
+        let velocity = start.velocity + acceleration * t;
+        let position = start.position + start.velocity * t + 0.5 * acceleration * t * t;
+
+        let scale = 1.0 + start.position.length() + MAX_SPEED * t;
+        assert!((state.velocity - velocity).length() <= 1e-4 * scale,
+                "cas {}: vitesse {} au lieu de {}", case, state.velocity, velocity);
+        // La position d'Euler semi-implicite devance la parabole de g·dt·t/2
+        let lag = 0.5 * acceleration * dt * t;
+        assert!((state.position - position - lag).length() <= 1e-4 * scale,
+                "cas {}: position {} au lieu de {} (+ {})", case, state.position, position, lag);
+        // Sans air, rien ne fait tourner le corps
+        assert!((state.angular_velocity - start.angular_velocity).abs() <= 1e-6);
+        assert!((state.angle - start.angle - start.angular_velocity * t).abs() <= 1e-4 * (1.0 + start.angle.abs()));
+    }
+}
+
+#[test]
+fn thrust_accelerates_along_body_axis() {
+    let mut rng = StdRng::seed_from_u64(3);
+    for case in 0..CASES {
+        let vehicle = random_vehicle(&mut rng);
+        let gravity = -rng.random_range(0.0..200.0);
+        let dt = random_dt(&mut rng);
+        let throttle = rng.random_range(0.0..1.0);
+        let rotation = rng.random_range(-1.0..1.0);
+        let state = RocketState { body: random_body(&mut rng, 100.0), fuel: rng.random_range(1.0..100.0) };
+
+        let next = step(&state, &vacuum(gravity), &vehicle, throttle, rotation, dt);
+        // Accélération mesurée moins la gravité : la force spécifique de la poussée
+        let specific_force = (next.body.velocity - state.body.velocity) / dt - Vec2::new(0.0, gravity);
+        let expected = body_axis(state.body.angle) * throttle * vehicle.engine_power;
+        assert!((specific_force - expected).length() <= 1e-2 * (1.0 + vehicle.engine_power),
+                "cas {}: force spécifique {} au lieu de {}", case, specific_force, expected);
+
+        // Commande hors plage : plafonnée à la pleine poussée, jamais de poussée inverse
+        let saturated = step(&state, &vacuum(gravity), &vehicle, 1.0 + throttle, rotation, dt);
+        let full = step(&state, &vacuum(gravity), &vehicle, 1.0, rotation, dt);
+        assert_eq!(saturated, full, "cas {}: poussée au-delà de 1", case);
+        let reversed = step(&state, &vacuum(gravity), &vehicle, -throttle, rotation, dt);
+        let idle = step(&state, &vacuum(gravity), &vehicle, 0.0, rotation, dt);
+        assert_eq!(reversed, idle, "cas {}: poussée négative", case);
+    }
+}
+
+#[test]
+fn fuel_never_goes_negative() {
+    let mut rng = StdRng::seed_from_u64(4);
+    let earth = Medium {
+        gravity: -147.15,
+        atmosphere: Atmosphere::Exponential { sea_level_density: 1.0, scale_height: 400.0 },
+        wind: Vec2::ZERO,
+        ground_level: -340.0,
+    };
+    for case in 0..CASES / 10 {
+        let vehicle = random_vehicle(&mut rng);
+        let mut state = RocketState { body: random_body(&mut rng, 50.0), fuel: rng.random_range(0.0..10.0) };
+        for _ in 0..600 {
+            let dt = rng.random_range(0.0..0.2);
+            let throttle = rng.random_range(-0.5..1.5);
+            let next = step(&state, &earth, &vehicle, throttle, rng.random_range(-2.0..2.0), dt);
+            assert!(next.fuel >= 0.0 && next.fuel <= state.fuel,
+                    "cas {}: carburant {} -> {} (poussée {}, dt {})", case, state.fuel, next.fuel, throttle, dt);
+            if state.fuel <= 0.0 {
+                // Réservoir vide : le moteur ne pousse plus
+                let unpowered = step(&state, &earth, &vehicle, 0.0, 0.0, dt);
+                assert_eq!(next.body.velocity, unpowered.body.velocity, "cas {}: poussée sans carburant", case);
+            }
+            state = next;
+        }
+        assert_eq!(state.fuel, 0.0, "cas {}: réservoir non vidé", case);
+    }
+
+    for _ in 0..CASES {
+        let fuel = rng.random_range(0.0..100.0);
+        assert!(burn_fuel(fuel, rng.random_range(0.0..1.0), rng.random_range(0.0..100.0)) >= 0.0);
+    }
+}
+
+#[test]
+fn angular_velocity_stays_clamped() {
+    let mut rng = StdRng::seed_from_u64(5);
+    // Air très dense et vent fort : de grands couples aérodynamiques
+    let storm = Medium {
+        gravity: -147.15,
+        atmosphere: Atmosphere::Exponential { sea_level_density: 50.0, scale_height: 400.0 },
+        wind: Vec2::new(150.0, 0.0),
+        ground_level: -340.0,
+    };
+    for case in 0..CASES {
+        let vehicle = random_vehicle(&mut rng);
+        let dt = random_dt(&mut rng);
+        let mut state = RocketState { body: random_body(&mut rng, MAX_SPEED), fuel: 100.0 };
+        state.body.angular_velocity = rng.random_range(-10.0..10.0);
+
+        for _ in 0..60 {
+            let rotation = rng.random_range(-3.0..3.0);
+            let next = step(&state, &storm, &vehicle, rng.random_range(0.0..1.0), rotation, dt);
+            assert!(next.body.angular_velocity.abs() <= MAX_ANGULAR_VELOCITY,
+                    "cas {}: vitesse angulaire {}", case, next.body.angular_velocity);
+            assert!(next.body.velocity.length() <= MAX_SPEED * (1.0 + 1e-6),
+                    "cas {}: vitesse {}", case, next.body.velocity.length());
+            state = next;
+        }
+
+        // Les propulseurs d'attitude rapprochent la vitesse angulaire de la consigne sans la dépasser,
+        // d'au plus leur autorité par pas
+        let current = rng.random_range(-MAX_ANGULAR_VELOCITY..MAX_ANGULAR_VELOCITY);
+        let rotation = rng.random_range(-1.0..1.0);
+        let target = rotation * vehicle.rotation_speed;
+        let next = attitude_rate(current, rotation, vehicle.rotation_speed, vehicle.rcs_authority, dt);
+        assert!((next - current).abs() <= vehicle.rcs_authority * dt * (1.0 + 1e-5),
+                "cas {}: variation {} au-delà de l'autorité {}", case, next - current, vehicle.rcs_authority * dt);
+        assert!((next - target).abs() <= (current - target).abs() + 1e-6,
+                "cas {}: {} s'éloigne de la consigne {}", case, next, target);
+    }
+}
@@ -1,7 +1,21 @@
 pub mod rocket;
 pub mod physics;
+pub mod contact;
+pub mod criteria;
+pub mod curriculum;
 pub mod controls;
 pub mod environment;
+pub mod episode;
+pub mod estimator;
+#[cfg(test)]
+mod invariants;
+pub mod outcome;
+pub mod drone_ship;
+pub mod planet;
+pub mod randomization;
+pub mod sensors;
+pub mod simulation;
+pub mod terrain;
 pub mod ui;
 
 use bevy::prelude::*;
@@ -10,6 +24,16 @@ use controls::RocketControlsPlugin;
 use physics::PhysicsPlugin;
 use ui::UIPlugin;
 use environment::EnvironmentPlugin;
+use drone_ship::DroneShipPlugin;
+use episode::EpisodePlugin;
+use curriculum::CurriculumPlugin;
+use planet::PlanetPlugin;
+use randomization::RandomizationPlugin;
+use sensors::SensorPlugin;
+use estimator::EstimatorPlugin;
+use criteria::LandingCriteria;
+use environment::ActivePad;
+use crate::rl_agent::metrics::MetricsSettings;
 
 pub struct RocketGamePlugin;
 
@@ -17,12 +41,21 @@ impl Plugin for RocketGamePlugin {
     fn build(&self, app: &mut App) {
         app
             .add_plugins((
+                PlanetPlugin,
+                RandomizationPlugin,
+                SensorPlugin,
+                EstimatorPlugin,
                 PhysicsPlugin,
                 RocketPlugin,
                 RocketControlsPlugin,
                 UIPlugin,
                 EnvironmentPlugin,
+                DroneShipPlugin,
+                EpisodePlugin,
+                CurriculumPlugin,
             ))
+            .init_resource::<LandingCriteria>()
+            .init_resource::<MetricsSettings>()
             .add_systems(Startup, (setup_camera, startup_message))
             .add_systems(Update, game_state_system)
             .insert_resource(ClearColor(Color::rgb(0.05, 0.05, 0.1)))
@@ -35,6 +68,7 @@ pub enum GameState {
     Playing,
     Crashed,
     Landed,
+    TimedOut, // Épisode tronqué par la limite de durée
     Restarting,
 }
 
@@ -46,22 +80,19 @@ fn setup_camera(mut commands: Commands) {
 }
 
 
-fn startup_message() {
+fn startup_message(criteria: Res<LandingCriteria>, active_pad: Res<ActivePad>) {
     println!("
 🎮 ROCKET LANDING SIMULATOR - CONDITIONS STRICTES
 =================================================
 CONDITIONS D'ATTERRISSAGE OBLIGATOIRES:
-• Zone d'atterrissage: -40 à +40 (rectangle JAUNE)
-• Angle parfait: 81° à 99° seulement (90° ± 10%)
-• Vitesse verticale < 3 m/s
-• Vitesse horizontale < 1 m/s
+{}
 =================================================
 RÈGLE STRICTE:
 • TOUTE condition non respectée = CRASH immédiat
 • Pas de tolérance d'erreur
 • Feedback détaillé des erreurs
 =================================================
-    ");
+    ", criteria.describe(active_pad.width));
 }
 
 fn game_state_system(
@@ -69,11 +100,9 @@ fn game_state_system(
     keyboard_input: Res<Input<KeyCode>>,
 ) {
     match *game_state {
-        GameState::Crashed | GameState::Landed => {
-            if keyboard_input.just_pressed(KeyCode::R) {
-                println!("🔄 REDÉMARRAGE MANUEL!");
-                *game_state = GameState::Restarting;
-            }
+        GameState::Crashed | GameState::Landed | GameState::TimedOut if keyboard_input.just_pressed(KeyCode::R) => {
+            println!("🔄 REDÉMARRAGE MANUEL!");
+            *game_state = GameState::Restarting;
         }
         _ => {}
     }
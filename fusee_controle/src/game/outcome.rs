@@ -0,0 +1,201 @@
+use std::fmt;
+use bevy::prelude::*;
+use crate::game::criteria::LandingCriteria;
+use crate::game::randomization::EpisodeParameters;
+
+/// Issue d'un épisode, jugée au posé ou par les limites d'épisode.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TouchdownOutcome {
+    Landed,
+    Crashed,
+    /// Épisode interrompu par la limite de durée : ni succès ni échec de pilotage.
+    Truncated,
+}
+
+impl TouchdownOutcome {
+    /// Identifiant stable pour les journaux et rapports.
+    pub fn key(&self) -> &'static str {
+        match self {
+            TouchdownOutcome::Landed => "landed",
+            TouchdownOutcome::Crashed => "crashed",
+            TouchdownOutcome::Truncated => "truncated",
+        }
+    }
+}
+
+/// Raison d'un échec. Les valeurs mesurées accompagnent la raison pour les rapports.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum CrashReason {
+    /// Écart le long du pont et demi-largeur acceptée.
+    OutOfZone { offset: f32, half_width: f32 },
+    /// Angle depuis l'horizontale du pont (90° = vertical) hors de la fenêtre acceptée.
+    BadAttitude { angle: f32, min: f32, max: f32 },
+    /// Fusée couchée : coque au sol ou inclinaison au-delà du point de basculement.
+    TippedOver { tilt: f32 },
+    VerticalOverspeed { speed: f32, limit: f32 },
+    LateralOverspeed { speed: f32, limit: f32 },
+    ViolentImpact { speed: f32, limit: f32 },
+    LegsNotDeployed,
+    Splashdown,
+    FuelExhausted,
+    OutOfBounds,
+    Timeout,
+}
+
+impl CrashReason {
+    /// Identifiant stable pour les journaux et rapports.
+    pub fn key(&self) -> &'static str {
+        match self {
+            CrashReason::OutOfZone { .. } => "out_of_zone",
+            CrashReason::BadAttitude { .. } => "bad_attitude",
+            CrashReason::TippedOver { .. } => "tipped_over",
+            CrashReason::VerticalOverspeed { .. } => "vertical_overspeed",
+            CrashReason::LateralOverspeed { .. } => "lateral_overspeed",
+            CrashReason::ViolentImpact { .. } => "violent_impact",
+            CrashReason::LegsNotDeployed => "legs_not_deployed",
+            CrashReason::Splashdown => "splashdown",
+            CrashReason::FuelExhausted => "fuel_exhausted",
+            CrashReason::OutOfBounds => "out_of_bounds",
+            CrashReason::Timeout => "timeout",
+        }
+    }
+}
+
+impl fmt::Display for CrashReason {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            CrashReason::OutOfZone { offset, half_width } => write!(
+                f, "Hors zone d'atterrissage: écart {:.1} (doit être entre -{:.0} et {:.0})", offset, half_width, half_width
+            ),
+            CrashReason::BadAttitude { angle, min, max } => write!(
+                f, "Angle incorrect: {:.1}° (doit être entre {}° et {}°)", angle, min, max
+            ),
+            CrashReason::TippedOver { tilt } => write!(f, "Fusée renversée: inclinaison {:.1}°", tilt),
+            CrashReason::VerticalOverspeed { speed, limit } => write!(
+                f, "Vitesse verticale trop élevée: {:.1} m/s (> {} m/s)", speed, limit
+            ),
+            CrashReason::LateralOverspeed { speed, limit } => write!(
+                f, "Vitesse horizontale trop élevée: {:.1} m/s (> {} m/s)", speed, limit
+            ),
+            CrashReason::ViolentImpact { speed, .. } => write!(f, "Impact trop violent: {:.1} m/s", speed),
+            CrashReason::LegsNotDeployed => write!(f, "Jambes d'atterrissage non déployées"),
+            CrashReason::Splashdown => write!(f, "Amerrissage: la fusée est tombée à la mer"),
+            CrashReason::FuelExhausted => write!(f, "Carburant épuisé avant le posé"),
+            CrashReason::OutOfBounds => write!(f, "Sortie de la zone de vol"),
+            CrashReason::Timeout => write!(f, "Temps limite dépassé"),
+        }
+    }
+}
+
+/// Mesures relevées au moment du jugement (vitesses et angle relatifs au pont).
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct TouchdownMetrics {
+    pub pad_offset: f32,
+    pub angle: f32,
+    pub vertical_speed: f32,
+    pub horizontal_speed: f32,
+    pub impact_speed: f32,
+    pub settled_time: f32,
+    pub fuel_remaining: f32,
+    pub score: f32,
+}
+
+/// Fin d'épisode : émis une seule fois par fusée, lu par l'agent, l'interface et les journaux.
+#[derive(Event, Clone, Debug)]
+pub struct Touchdown {
+    pub outcome: TouchdownOutcome,
+    pub reasons: Vec<CrashReason>,
+    pub metrics: TouchdownMetrics,
+    /// Paramètres physiques tirés pour l'épisode.
+    pub parameters: EpisodeParameters,
+}
+
+/// Ce que le jugement du posé doit savoir d'une fusée au sol, dans le repère du pont.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct ContactReading {
+    /// Vitesse relative au pont au premier contact, avant que les jambes n'amortissent.
+    pub impact_velocity: Vec2,
+    /// Inclinaison actuelle par rapport à la perpendiculaire du pont (rad).
+    pub tilt: f32,
+    /// Écart actuel au centre de la plateforme, le long du pont.
+    pub pad_offset: f32,
+    pub legs_deployed: bool,
+    pub hull_contact: bool,
+    pub splashdown: bool,
+    pub settled_time: f32,
+    pub fuel: f32,
+    pub max_fuel: f32,
+}
+
+/// Issue d'un contact avec le sol selon `criteria`. Un défaut à l'impact est un crash immédiat ;
+/// sinon, le posé n'est jugé qu'après `settle_time` de stabilité et `None` signifie qu'il faut
+/// encore attendre.
+pub fn judge_touchdown(
+    reading: &ContactReading,
+    criteria: &LandingCriteria,
+    half_width: f32,
+    tip_over_angle: f32,
+    parameters: EpisodeParameters,
+) -> Option<Touchdown> {
+    // Angle mesuré depuis le pont : 90° = fusée perpendiculaire à la surface
+    let angle = 90.0 - reading.tilt.abs().to_degrees();
+    let (min_angle, max_angle) = criteria.angle_window();
+    let vertical_speed = reading.impact_velocity.y.abs();
+    let horizontal_speed = reading.impact_velocity.x.abs();
+    let impact_speed = reading.impact_velocity.length();
+
+    let mut reasons = Vec::new();
+    if reading.splashdown {
+        reasons.push(CrashReason::Splashdown);
+    }
+    if impact_speed > criteria.max_impact_speed {
+        reasons.push(CrashReason::ViolentImpact { speed: impact_speed, limit: criteria.max_impact_speed });
+    }
+    if vertical_speed >= criteria.max_vertical_speed {
+        reasons.push(CrashReason::VerticalOverspeed { speed: vertical_speed, limit: criteria.max_vertical_speed });
+    }
+    if horizontal_speed >= criteria.max_horizontal_speed {
+        reasons.push(CrashReason::LateralOverspeed { speed: horizontal_speed, limit: criteria.max_horizontal_speed });
+    }
+    if !reading.legs_deployed {
+        reasons.push(CrashReason::LegsNotDeployed);
+    } else if reading.hull_contact || reading.tilt.abs() > tip_over_angle {
+        reasons.push(CrashReason::TippedOver { tilt: reading.tilt.abs().to_degrees() });
+    }
+
+    // Sans défaut à l'impact, on attend que la fusée soit stable sur ses jambes
+    if reasons.is_empty() {
+        if reading.settled_time < criteria.settle_time {
+            return None;
+        }
+        // ZONE D'ATTERRISSAGE OBLIGATOIRE : relative à la plateforme cible
+        if reading.pad_offset.abs() > half_width {
+            reasons.push(CrashReason::OutOfZone { offset: reading.pad_offset, half_width });
+        }
+        if !(min_angle..=max_angle).contains(&angle) {
+            reasons.push(CrashReason::BadAttitude { angle, min: min_angle, max: max_angle });
+        }
+    }
+    if !reasons.is_empty() && reading.fuel <= 0.0 {
+        // Réservoir vide : la descente n'était plus contrôlable
+        reasons.push(CrashReason::FuelExhausted);
+    }
+
+    let landed = reasons.is_empty();
+    Some(Touchdown {
+        outcome: if landed { TouchdownOutcome::Landed } else { TouchdownOutcome::Crashed },
+        reasons,
+        metrics: TouchdownMetrics {
+            pad_offset: reading.pad_offset,
+            angle,
+            vertical_speed,
+            horizontal_speed,
+            impact_speed,
+            settled_time: reading.settled_time,
+            fuel_remaining: reading.fuel / reading.max_fuel,
+            // Score de précision
+            score: if landed { criteria.precision_score((angle - 90.0).abs(), vertical_speed, horizontal_speed) } else { 0.0 },
+        },
+        parameters,
+    })
+}
@@ -1,27 +1,139 @@
 use bevy::prelude::*;
+use crate::game::contact::{ground_contact, ContactSettings};
+use crate::game::environment::{Wind, GROUND_LEVEL};
+use crate::game::planet::CelestialBody;
+use crate::game::randomization::EpisodeParameters;
 use crate::game::rocket::{Rocket, RocketMainBody};
+use crate::game::simulation::{integrate, BodyState, Medium};
 use crate::game::GameState;
 
 #[derive(Component)]
 pub struct PhysicsBody {
     pub velocity: Vec2,
     pub angular_velocity: f32,
+    pub radius_of_gyration: f32, // Convertit un couple (par unité de masse) en accélération angulaire
 }
 
-// CONSTANTES PHYSIQUES AJUSTÉES - GRAVITÉ RÉDUITE
-const GRAVITY: f32 = -9.81 * 15.0; // RÉDUIT de 25 à 15 (moins rapide)
-const AIR_RESISTANCE: f32 = 0.008;  // RÉSISTANCE RÉDUITE
+// La gravité et l'atmosphère viennent du corps céleste actif (voir planet.rs),
+// l'intégration est partagée avec les simulations hors monde (voir simulation.rs)
+
+/// Modèle d'atmosphère : densité de l'air en fonction de l'altitude au-dessus du sol.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Atmosphere {
+    /// Aucun air (Lune, astéroïdes) : aucune traînée.
+    Vacuum,
+    /// Densité exponentielle `rho = sea_level_density * exp(-altitude / scale_height)`.
+    Exponential {
+        sea_level_density: f32,
+        scale_height: f32,
+    },
+}
+
+impl Atmosphere {
+    pub fn density_at(&self, altitude: f32) -> f32 {
+        match *self {
+            Atmosphere::Vacuum => 0.0,
+            Atmosphere::Exponential { sea_level_density, scale_height } => {
+                sea_level_density * (-altitude.max(0.0) / scale_height).exp()
+            }
+        }
+    }
+}
+
+/// Coefficients aérodynamiques d'un corps. Les surfaces sont données par unité de masse,
+/// les charges calculées sont donc directement des accélérations.
+#[derive(Component, Clone, Copy, Debug, PartialEq)]
+pub struct Aerodynamics {
+    /// Coefficient de traînée le long de l'axe de la fusée.
+    pub axial_coefficient: f32,
+    /// Coefficient de traînée perpendiculaire à l'axe (vol en travers).
+    pub normal_coefficient: f32,
+    /// Surface de référence par unité de masse.
+    pub reference_area: f32,
+    /// Position du centre de poussée le long de l'axe, mesurée depuis le centre de masse
+    /// (positif vers le nez).
+    pub center_of_pressure_offset: f32,
+    /// Applique le couple aérodynamique autour du centre de poussée.
+    pub aerodynamic_torque: bool,
+    /// Traînée supplémentaire (axiale et normale) des jambes d'atterrissage déployées.
+    pub gear_drag_coefficient: f32,
+    pub gear_deployed: bool,
+}
+
+impl Default for Aerodynamics {
+    fn default() -> Self {
+        Self {
+            axial_coefficient: 0.3,
+            normal_coefficient: 1.5,
+            reference_area: 1.0e-3,
+            center_of_pressure_offset: 15.0,
+            aerodynamic_torque: true,
+            gear_drag_coefficient: 0.4,
+            gear_deployed: false,
+        }
+    }
+}
+
+/// Charges aérodynamiques instantanées sur un corps.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct AeroLoads {
+    pub acceleration: Vec2,
+    /// Couple par unité de masse autour du centre de masse.
+    pub torque: f32,
+    pub angle_of_attack: f32,
+}
+
+/// Direction du nez de la fusée dans le repère monde pour une rotation `angle` autour de Z.
+pub fn body_axis(angle: f32) -> Vec2 {
+    Vec2::new(-angle.sin(), angle.cos())
+}
+
+/// Traînée quadratique décomposée en composantes axiale et normale.
+/// `air_velocity` est la vitesse du corps par rapport à l'air.
+pub fn aerodynamic_loads(aero: &Aerodynamics, density: f32, air_velocity: Vec2, angle: f32) -> AeroLoads {
+    let speed = air_velocity.length();
+    if density <= 0.0 || speed <= f32::EPSILON {
+        return AeroLoads::default();
+    }
+
+    let axis = body_axis(angle);
+    let axial_velocity = axis * air_velocity.dot(axis);
+    let normal_velocity = air_velocity - axial_velocity;
+
+    let gear_drag = if aero.gear_deployed { aero.gear_drag_coefficient } else { 0.0 };
+    let dynamic_factor = 0.5 * density * aero.reference_area * speed;
+    let axial_force = -axial_velocity * (aero.axial_coefficient + gear_drag) * dynamic_factor;
+    let normal_force = -normal_velocity * (aero.normal_coefficient + gear_drag) * dynamic_factor;
+
+    // Couple de la force normale appliquée au centre de poussée
+    let torque = if aero.aerodynamic_torque {
+        let lever_arm = axis * aero.center_of_pressure_offset;
+        lever_arm.perp_dot(normal_force)
+    } else {
+        0.0
+    };
+
+    AeroLoads {
+        acceleration: axial_force + normal_force,
+        torque,
+        angle_of_attack: axis.perp_dot(air_velocity).atan2(axis.dot(air_velocity)),
+    }
+}
 
 pub struct PhysicsPlugin;
 
 impl Plugin for PhysicsPlugin {
     fn build(&self, app: &mut App) {
-        app.add_systems(Update, (apply_physics, apply_rocket_thrust));
+        app.init_resource::<ContactSettings>()
+           .add_systems(Update, (ground_contact, apply_rocket_thrust, apply_physics).chain());
     }
 }
 
-fn apply_physics(
-    mut query: Query<(&mut Transform, &mut PhysicsBody)>,
+pub(crate) fn apply_physics(
+    mut query: Query<(&mut Transform, &mut PhysicsBody, Option<&Aerodynamics>)>,
+    body: Res<CelestialBody>,
+    parameters: Res<EpisodeParameters>,
+    wind: Res<Wind>,
     time: Res<Time>,
     game_state: Res<GameState>,
 ) {
@@ -29,25 +141,28 @@ fn apply_physics(
         return;
     }
 
-    for (mut transform, mut body) in query.iter_mut() {
-        // Gravité plus douce
-        body.velocity.y += GRAVITY * time.delta_seconds();
-        
-        // Résistance de l'air réduite
-        body.velocity *= 1.0 - (AIR_RESISTANCE * time.delta_seconds());
-        
-        // Limites de vitesse réalistes
-        body.velocity = body.velocity.clamp_length_max(200.0);
-        
-        // Mise à jour position
-        transform.translation.x += body.velocity.x * time.delta_seconds();
-        transform.translation.y += body.velocity.y * time.delta_seconds();
-        
-        // Mise à jour rotation
-        transform.rotate_z(body.angular_velocity * time.delta_seconds());
-        
-        // Limite la rotation angulaire
-        body.angular_velocity = body.angular_velocity.clamp(-3.0, 3.0);
+    let medium = Medium {
+        gravity: body.gravity() * parameters.gravity_scale,
+        atmosphere: body.atmosphere,
+        wind: wind.velocity,
+        ground_level: GROUND_LEVEL,
+    };
+
+    for (mut transform, mut physics, aerodynamics) in query.iter_mut() {
+        let angle = transform.rotation.to_euler(EulerRot::XYZ).2;
+        let mut state = BodyState {
+            position: transform.translation.truncate(),
+            velocity: physics.velocity,
+            angle,
+            angular_velocity: physics.angular_velocity,
+        };
+        integrate(&mut state, &medium, aerodynamics, physics.radius_of_gyration, time.delta_seconds());
+
+        transform.translation.x = state.position.x;
+        transform.translation.y = state.position.y;
+        transform.rotate_z(state.angle - angle);
+        physics.velocity = state.velocity;
+        physics.angular_velocity = state.angular_velocity;
     }
 }
 
@@ -60,14 +175,13 @@ fn apply_rocket_thrust(
         return;
     }
 
-    if let Ok((mut physics, rocket, transform)) = query.get_single_mut() {
-        if rocket.throttle > 0.0 && rocket.fuel > 0.0 && !rocket.has_crashed && !rocket.has_landed {
-            let thrust_power = rocket.throttle * rocket.engine_power;
-            let angle = transform.rotation.to_euler(EulerRot::XYZ).2;
-            
-            // Poussée réaliste basée sur l'orientation
-            physics.velocity.x += thrust_power * angle.sin() * time.delta_seconds();
-            physics.velocity.y += thrust_power * angle.cos() * time.delta_seconds();
-        }
+    if let Ok((mut physics, rocket, transform)) = query.get_single_mut()
+        && rocket.throttle > 0.0 && rocket.fuel > 0.0 && !rocket.has_crashed && !rocket.has_landed
+    {
+        let thrust_power = rocket.throttle * rocket.engine_power;
+        let angle = transform.rotation.to_euler(EulerRot::XYZ).2;
+
+        // Poussée le long de l'axe de la fusée
+        physics.velocity += body_axis(angle) * thrust_power * time.delta_seconds();
     }
-}
\ No newline at end of file
+}
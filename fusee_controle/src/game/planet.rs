@@ -0,0 +1,206 @@
+use bevy::prelude::*;
+use rand::seq::IndexedRandom;
+use rand::Rng;
+use crate::game::environment::WindSettings;
+use crate::game::episode::EpisodeRng;
+use crate::game::physics::Atmosphere;
+use crate::game::rocket::restart_system;
+use crate::game::GameState;
+use crate::rl_agent::metrics::MetricsSettings;
+
+// Facteur d'échelle historique entre m/s² et px/s² (gravité "réduite" du simulateur)
+pub const GRAVITY_SCALE: f32 = 15.0;
+
+/// Corps céleste : gravité, atmosphère et vent par défaut.
+#[derive(Resource, Clone, Debug, PartialEq)]
+pub struct CelestialBody {
+    pub name: String,
+    /// Gravité de surface en m/s² (positive).
+    pub surface_gravity: f32,
+    pub atmosphere: Atmosphere,
+    pub wind: WindSettings,
+}
+
+impl Default for CelestialBody {
+    fn default() -> Self {
+        Self::earth()
+    }
+}
+
+impl CelestialBody {
+    pub fn earth() -> Self {
+        Self {
+            name: "Earth".to_string(),
+            surface_gravity: 9.81,
+            atmosphere: Atmosphere::Exponential {
+                sea_level_density: 1.0,
+                scale_height: 1500.0,
+            },
+            wind: WindSettings {
+                mean_speed: 5.0,
+                gust_strength: 3.0,
+            },
+        }
+    }
+
+    pub fn moon() -> Self {
+        Self {
+            name: "Moon".to_string(),
+            surface_gravity: 1.62,
+            atmosphere: Atmosphere::Vacuum,
+            wind: WindSettings::calm(),
+        }
+    }
+
+    pub fn mars() -> Self {
+        Self {
+            name: "Mars".to_string(),
+            surface_gravity: 3.71,
+            // ~1,5 % de la densité terrestre, hauteur d'échelle ~30 % plus grande
+            atmosphere: Atmosphere::Exponential {
+                sea_level_density: 0.015,
+                scale_height: 1950.0,
+            },
+            wind: WindSettings {
+                mean_speed: 8.0,
+                gust_strength: 6.0,
+            },
+        }
+    }
+
+    pub fn custom(name: &str, surface_gravity: f32, atmosphere: Atmosphere, wind: WindSettings) -> Self {
+        Self {
+            name: name.to_string(),
+            surface_gravity,
+            atmosphere,
+            wind,
+        }
+    }
+
+    pub fn presets() -> Vec<Self> {
+        vec![Self::earth(), Self::moon(), Self::mars()]
+    }
+
+    /// Accélération verticale appliquée par la physique (px/s², vers le bas).
+    pub fn gravity(&self) -> f32 {
+        -self.surface_gravity * GRAVITY_SCALE
+    }
+
+    /// Lit un corps depuis la ligne de commande :
+    /// `earth`, `moon`, `mars` ou `custom:<nom>:<gravité m/s²>[:<densité>:<hauteur d'échelle>]`.
+    pub fn from_spec(spec: &str) -> Result<Self, String> {
+        match spec.to_lowercase().as_str() {
+            "earth" | "terre" => return Ok(Self::earth()),
+            "moon" | "lune" => return Ok(Self::moon()),
+            "mars" => return Ok(Self::mars()),
+            _ => {}
+        }
+
+        let parts: Vec<&str> = spec.split(':').collect();
+        if parts[0].to_lowercase() != "custom" || !(parts.len() == 3 || parts.len() == 5) {
+            return Err(format!("corps céleste inconnu: '{}'", spec));
+        }
+
+        let number = |s: &str| s.parse::<f32>().map_err(|_| format!("nombre invalide '{}' dans '{}'", s, spec));
+        let surface_gravity = number(parts[2])?;
+        let atmosphere = if parts.len() == 5 {
+            Atmosphere::Exponential {
+                sea_level_density: number(parts[3])?,
+                scale_height: number(parts[4])?,
+            }
+        } else {
+            Atmosphere::Vacuum
+        };
+
+        Ok(Self::custom(parts[1], surface_gravity, atmosphere, WindSettings::calm()))
+    }
+}
+
+/// Choix du corps céleste pour la session : fixe, ou tiré au sort à chaque épisode.
+#[derive(Resource, Clone, Debug)]
+pub enum BodySelection {
+    Fixed(CelestialBody),
+    RandomPerEpisode(Vec<CelestialBody>),
+}
+
+impl Default for BodySelection {
+    fn default() -> Self {
+        Self::Fixed(CelestialBody::earth())
+    }
+}
+
+impl BodySelection {
+    /// `random` tire parmi les préréglages, une liste `earth,mars` tire parmi la liste.
+    pub fn from_spec(spec: &str) -> Result<Self, String> {
+        if spec.eq_ignore_ascii_case("random") {
+            return Ok(Self::RandomPerEpisode(CelestialBody::presets()));
+        }
+
+        let bodies = spec
+            .split(',')
+            .map(CelestialBody::from_spec)
+            .collect::<Result<Vec<_>, _>>()?;
+
+        match bodies.len() {
+            1 => Ok(Self::Fixed(bodies.into_iter().next().unwrap())),
+            _ => Ok(Self::RandomPerEpisode(bodies)),
+        }
+    }
+
+    /// Description enregistrée dans les checkpoints et rapports.
+    pub fn describe(&self) -> String {
+        match self {
+            BodySelection::Fixed(body) => body.name.clone(),
+            BodySelection::RandomPerEpisode(bodies) => format!(
+                "random({})",
+                bodies.iter().map(|b| b.name.as_str()).collect::<Vec<_>>().join(", ")
+            ),
+        }
+    }
+
+    pub fn pick(&self, rng: &mut impl Rng) -> CelestialBody {
+        match self {
+            BodySelection::Fixed(body) => body.clone(),
+            BodySelection::RandomPerEpisode(bodies) => bodies
+                .choose(rng)
+                .cloned()
+                .unwrap_or_default(),
+        }
+    }
+}
+
+pub struct PlanetPlugin;
+
+impl Plugin for PlanetPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<BodySelection>()
+           .init_resource::<EpisodeRng>();
+        // Premier corps tiré comme les suivants, dans le générateur des épisodes
+        let body = app.world.resource_scope(|world, mut rng: Mut<EpisodeRng>| {
+            world.resource::<BodySelection>().pick(&mut rng.0)
+        });
+
+        app.insert_resource(body)
+           .add_systems(Startup, announce_body)
+           .add_systems(Update, select_body_for_episode.before(restart_system));
+    }
+}
+
+fn announce_body(body: Res<CelestialBody>) {
+    println!("🪐 Corps céleste: {} (g = {:.2} m/s²)", body.name, body.surface_gravity);
+}
+
+fn select_body_for_episode(
+    game_state: Res<GameState>,
+    selection: Res<BodySelection>,
+    mut body: ResMut<CelestialBody>,
+    mut rng: ResMut<EpisodeRng>,
+    metrics: Res<MetricsSettings>,
+) {
+    if *game_state == GameState::Restarting && matches!(*selection, BodySelection::RandomPerEpisode(_)) {
+        *body = selection.pick(&mut rng.0);
+        if metrics.shows_flight_events() {
+                println!("🪐 Corps céleste: {} (g = {:.2} m/s²)", body.name, body.surface_gravity);
+        }
+    }
+}
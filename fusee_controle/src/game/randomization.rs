@@ -0,0 +1,206 @@
+use std::fmt;
+use bevy::prelude::*;
+use rand::Rng;
+use crate::game::episode::EpisodeRng;
+use crate::game::rocket::restart_system;
+use crate::game::GameState;
+use crate::settings::SettingsFile;
+
+/// Loi de tirage d'un paramètre physique.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Distribution {
+    Fixed(f32),
+    Uniform { min: f32, max: f32 },
+    /// Loi normale tronquée à ±3 écarts-types.
+    Normal { mean: f32, std_dev: f32 },
+}
+
+impl Distribution {
+    /// `1.0` (fixe), `0.9..1.1` (uniforme) ou `normal:<moyenne>:<écart-type>`.
+    pub fn from_spec(spec: &str) -> Result<Self, String> {
+        let number = |s: &str| s.trim().parse::<f32>().map_err(|_| format!("nombre invalide '{}' dans '{}'", s, spec));
+
+        if let Some(rest) = spec.strip_prefix("normal:") {
+            let (mean, std_dev) = rest.split_once(':').ok_or_else(|| format!("loi normale invalide: '{}'", spec))?;
+            return Ok(Self::Normal { mean: number(mean)?, std_dev: number(std_dev)?.abs() });
+        }
+        if let Some((min, max)) = spec.split_once("..") {
+            let (min, max) = (number(min)?, number(max)?);
+            if min > max {
+                return Err(format!("intervalle vide: '{}'", spec));
+            }
+            return Ok(Self::Uniform { min, max });
+        }
+        Ok(Self::Fixed(number(spec)?))
+    }
+
+    pub fn sample(&self, rng: &mut impl Rng) -> f32 {
+        match *self {
+            Distribution::Fixed(value) => value,
+            Distribution::Uniform { min, max } => rng.random_range(min..=max),
+            Distribution::Normal { mean, std_dev } => mean + std_dev * standard_normal(rng).clamp(-3.0, 3.0),
+        }
+    }
+}
+
+/// Tirage d'une loi normale centrée réduite (Box-Muller).
+pub fn standard_normal(rng: &mut impl Rng) -> f32 {
+    let u1: f32 = rng.random_range(f32::EPSILON..1.0);
+    let u2: f32 = rng.random();
+    (-2.0 * u1.ln()).sqrt() * (std::f32::consts::TAU * u2).cos()
+}
+
+/// Lois de tirage des paramètres physiques à chaque épisode, section `[randomization]`.
+/// Par défaut tout est fixé aux valeurs nominales : aucune randomisation.
+#[derive(Resource, Clone, Debug, PartialEq)]
+pub struct DomainRandomization {
+    /// Facteur appliqué à la gravité du corps céleste.
+    pub gravity_scale: Distribution,
+    /// Facteur appliqué à la poussée du moteur.
+    pub engine_power_scale: Distribution,
+    /// Masse à vide relative à la masse nominale.
+    pub dry_mass_scale: Distribution,
+    /// Facteur appliqué aux coefficients de traînée.
+    pub drag_scale: Distribution,
+    /// Retard entre la commande et sa prise d'effet (s).
+    pub actuator_delay: Distribution,
+    /// Carburant au départ, fraction du réservoir.
+    pub initial_fuel: Distribution,
+}
+
+impl Default for DomainRandomization {
+    fn default() -> Self {
+        Self {
+            gravity_scale: Distribution::Fixed(1.0),
+            engine_power_scale: Distribution::Fixed(1.0),
+            dry_mass_scale: Distribution::Fixed(1.0),
+            drag_scale: Distribution::Fixed(1.0),
+            actuator_delay: Distribution::Fixed(0.0),
+            initial_fuel: Distribution::Fixed(1.0),
+        }
+    }
+}
+
+impl DomainRandomization {
+    pub fn from_settings(settings: &SettingsFile) -> Result<Self, String> {
+        let mut randomization = Self::default();
+        settings.apply_section("randomization", |key, value| {
+            let field = match key {
+                "gravity" => &mut randomization.gravity_scale,
+                "engine_power" => &mut randomization.engine_power_scale,
+                "dry_mass" => &mut randomization.dry_mass_scale,
+                "drag" => &mut randomization.drag_scale,
+                "actuator_delay" => &mut randomization.actuator_delay,
+                "initial_fuel" => &mut randomization.initial_fuel,
+                _ => return Ok(false),
+            };
+            *field = Distribution::from_spec(value).map_err(|e| format!("{}: {}", key, e))?;
+            Ok(true)
+        })?;
+        Ok(randomization)
+    }
+
+    /// Aucune randomisation : chaque épisode reprend `parameters`.
+    pub fn fixed(parameters: &EpisodeParameters) -> Self {
+        Self {
+            gravity_scale: Distribution::Fixed(parameters.gravity_scale),
+            engine_power_scale: Distribution::Fixed(parameters.engine_power_scale),
+            dry_mass_scale: Distribution::Fixed(parameters.dry_mass_scale),
+            drag_scale: Distribution::Fixed(parameters.drag_scale),
+            actuator_delay: Distribution::Fixed(parameters.actuator_delay),
+            initial_fuel: Distribution::Fixed(parameters.initial_fuel),
+        }
+    }
+
+    pub fn is_fixed(&self) -> bool {
+        [
+            self.gravity_scale,
+            self.engine_power_scale,
+            self.dry_mass_scale,
+            self.drag_scale,
+            self.actuator_delay,
+            self.initial_fuel,
+        ]
+        .iter()
+        .all(|distribution| matches!(distribution, Distribution::Fixed(_)))
+    }
+
+    pub fn sample(&self, rng: &mut impl Rng) -> EpisodeParameters {
+        EpisodeParameters {
+            gravity_scale: self.gravity_scale.sample(rng).max(0.0),
+            engine_power_scale: self.engine_power_scale.sample(rng).max(0.0),
+            dry_mass_scale: self.dry_mass_scale.sample(rng).max(0.1),
+            drag_scale: self.drag_scale.sample(rng).max(0.0),
+            actuator_delay: self.actuator_delay.sample(rng).max(0.0),
+            initial_fuel: self.initial_fuel.sample(rng).clamp(0.0, 1.0),
+        }
+    }
+}
+
+/// Paramètres physiques tirés pour l'épisode courant.
+#[derive(Resource, Clone, Copy, Debug, PartialEq)]
+pub struct EpisodeParameters {
+    pub gravity_scale: f32,
+    pub engine_power_scale: f32,
+    pub dry_mass_scale: f32,
+    pub drag_scale: f32,
+    pub actuator_delay: f32,
+    pub initial_fuel: f32,
+}
+
+impl Default for EpisodeParameters {
+    fn default() -> Self {
+        Self {
+            gravity_scale: 1.0,
+            engine_power_scale: 1.0,
+            dry_mass_scale: 1.0,
+            drag_scale: 1.0,
+            actuator_delay: 0.0,
+            initial_fuel: 1.0,
+        }
+    }
+}
+
+impl fmt::Display for EpisodeParameters {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "g ×{:.2}, poussée ×{:.2}, masse ×{:.2}, traînée ×{:.2}, retard {:.0} ms, carburant {:.0}%",
+            self.gravity_scale, self.engine_power_scale, self.dry_mass_scale, self.drag_scale,
+            self.actuator_delay * 1000.0, self.initial_fuel * 100.0
+        )
+    }
+}
+
+pub struct RandomizationPlugin;
+
+impl Plugin for RandomizationPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<DomainRandomization>()
+           .init_resource::<EpisodeRng>();
+        // Paramètres du premier épisode tirés comme les suivants, dans le générateur des épisodes
+        let parameters = app.world.resource_scope(|world, mut rng: Mut<EpisodeRng>| {
+            world.resource::<DomainRandomization>().sample(&mut rng.0)
+        });
+
+        app.insert_resource(parameters)
+           .add_systems(Startup, announce_randomization)
+           .add_systems(Update, sample_episode_parameters.run_if(resource_equals(GameState::Restarting)).before(restart_system));
+    }
+}
+
+fn announce_randomization(randomization: Res<DomainRandomization>, parameters: Res<EpisodeParameters>) {
+    if !randomization.is_fixed() {
+        println!("🎲 Randomisation du domaine active - épisode 1: {}", *parameters);
+    }
+}
+
+fn sample_episode_parameters(
+    randomization: Res<DomainRandomization>,
+    mut parameters: ResMut<EpisodeParameters>,
+    mut rng: ResMut<EpisodeRng>,
+) {
+    if !randomization.is_fixed() {
+        *parameters = randomization.sample(&mut rng.0);
+    }
+}
@@ -1,8 +1,19 @@
 use bevy::prelude::*;
 use bevy::sprite::{MaterialMesh2dBundle, Mesh2dHandle};
 use rand::Rng;
-use crate::game::physics::PhysicsBody;
+use crate::game::contact::{ContactSettings, GroundContact, HullShape};
+use crate::game::criteria::LandingCriteria;
+use crate::game::controls::ActuatorLag;
+use crate::game::curriculum::Curriculum;
+use crate::game::outcome::{judge_touchdown, ContactReading, Touchdown, TouchdownOutcome};
+use crate::game::environment::ActivePad;
+use crate::game::episode::EpisodeRng;
+use crate::game::terrain::Terrain;
+use crate::game::physics::{apply_physics, Aerodynamics, PhysicsBody};
+use crate::game::planet::CelestialBody;
+use crate::game::randomization::EpisodeParameters;
 use crate::game::GameState;
+use crate::rl_agent::metrics::MetricsSettings;
 
 #[derive(Component)]
 pub struct Rocket {
@@ -11,9 +22,69 @@ pub struct Rocket {
     pub throttle: f32,
     pub engine_power: f32,
     pub rotation_speed: f32,
+    pub rcs_authority: f32, // Accélération angulaire max des propulseurs d'attitude (rad/s²)
     pub has_crashed: bool,
     pub has_landed: bool,
-    pub size_factor: f32, // Facteur de taille pour l'apprentissage progressif
+    pub legs_deploy_altitude: Option<f32>, // Hauteur au-dessus du sol au déploiement des jambes
+}
+
+impl Rocket {
+    /// Fusée neuve aux paramètres physiques de l'épisode. Les grandeurs sont par unité de masse :
+    /// une masse à vide plus grande réduit d'autant la poussée et l'autorité des propulseurs d'attitude.
+    pub fn for_episode(parameters: &EpisodeParameters) -> Self {
+        let mass = parameters.dry_mass_scale;
+        Self {
+            fuel: 100.0 * parameters.initial_fuel,
+            max_fuel: 100.0,
+            throttle: 0.0,
+            engine_power: 600.0 * parameters.engine_power_scale / mass,
+            rotation_speed: 1.2,
+            rcs_authority: 8.0 / mass,
+            has_crashed: false,
+            has_landed: false,
+            legs_deploy_altitude: None,
+        }
+    }
+}
+
+// Dimensions de la coque (px)
+const HULL_WIDTH: f32 = 20.0;
+const HULL_HEIGHT: f32 = 60.0;
+// Pied d'une jambe déployée : écart latéral au bord de la coque et hauteur sous sa base (px)
+const LEG_SPREAD: f32 = 10.0;
+const LEG_DROP: f32 = 8.0;
+/// Distance du centre de la fusée aux pieds des jambes déployées (px), voir `leg_feet`.
+pub const LEG_REACH: f32 = HULL_HEIGHT / 2.0 + LEG_DROP;
+
+/// Corps physique de la fusée : barre uniforme de la hauteur de la coque.
+pub fn rocket_body(velocity: Vec2, angular_velocity: f32) -> PhysicsBody {
+    PhysicsBody {
+        velocity,
+        angular_velocity,
+        radius_of_gyration: HULL_HEIGHT / 12f32.sqrt(),
+    }
+}
+
+/// Coque de la fusée, pour le contact de la structure avec le sol.
+pub fn hull_shape() -> HullShape {
+    HullShape { half_size: Vec2::new(HULL_WIDTH / 2.0, HULL_HEIGHT / 2.0) }
+}
+
+/// Pieds des jambes d'atterrissage déployées (gauche, droite), dans le repère de la fusée.
+pub fn leg_feet() -> [Vec2; 2] {
+    [-1.0, 1.0].map(|side| Vec2::new(side * (HULL_WIDTH / 2.0 + LEG_SPREAD), -LEG_REACH))
+}
+
+/// Traînée de l'épisode, rapportée comme la poussée à l'unité de masse.
+pub fn episode_aerodynamics(parameters: &EpisodeParameters) -> Aerodynamics {
+    let nominal = Aerodynamics::default();
+    Aerodynamics {
+        axial_coefficient: nominal.axial_coefficient * parameters.drag_scale,
+        normal_coefficient: nominal.normal_coefficient * parameters.drag_scale,
+        gear_drag_coefficient: nominal.gear_drag_coefficient * parameters.drag_scale,
+        reference_area: nominal.reference_area / parameters.dry_mass_scale,
+        ..nominal
+    }
 }
 
 #[derive(Component)]
@@ -26,6 +97,7 @@ pub struct RocketFlame;
 pub struct LandingLegs {
     pub deployed: bool,
     pub contact: bool,
+    pub foot: Vec2, // Position du pied dans le repère de la fusée
 }
 
 #[derive(Component)]
@@ -33,18 +105,59 @@ pub struct RocketExplosion {
     pub timer: Timer,
 }
 
-#[derive(Resource)]
+#[derive(Resource, Clone)]
 pub struct RocketStats {
     pub altitude: f32,
     pub vertical_speed: f32,
     pub horizontal_speed: f32,
     pub angle: f32,
+    pub angular_velocity: f32,
+    pub specific_force: Vec2,       // Accélération hors gravité, repère de la fusée (centrale inertielle)
     pub fuel_percentage: f32,
     pub distance_to_target: f32,
+    pub pad_offset: Vec2,           // Position relative au centre de la plateforme cible
+    pub pad_velocity: Vec2,         // Mouvement du pont cible (barge)
+    pub pad_tilt: f32,
+    pub zone_half_width: f32,       // Demi-largeur de la zone acceptée par les critères
+    pub height_above_ground: f32,
     pub landing_score: f32,
     pub total_landings: u32,
     pub total_crashes: u32,
     pub consecutive_successes: u32, // Succès consécutifs pour l'apprentissage
+    pub legs_deployed: bool,
+    pub legs_in_contact: usize,
+}
+
+/// Conditions de départ de la fusée, relatives à la plateforme cible.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct InitialConditions {
+    /// Position par rapport au centre du pont (px).
+    pub offset: Vec2,
+    /// Inclinaison (rad).
+    pub rotation: f32,
+    pub velocity: Vec2,
+    pub angular_velocity: f32,
+}
+
+impl InitialConditions {
+    /// Écart uniforme d'au plus `spread` sur chaque grandeur.
+    pub fn perturbed(&self, spread: &InitialConditions, rng: &mut impl Rng) -> Self {
+        let mut jitter = |half_width: f32| if half_width > 0.0 { rng.random_range(-half_width..=half_width) } else { 0.0 };
+        Self {
+            offset: self.offset + Vec2::new(jitter(spread.offset.x), jitter(spread.offset.y)),
+            rotation: self.rotation + jitter(spread.rotation),
+            velocity: self.velocity + Vec2::new(jitter(spread.velocity.x), jitter(spread.velocity.y)),
+            angular_velocity: self.angular_velocity + jitter(spread.angular_velocity),
+        }
+    }
+}
+
+/// Départ imposé à la place du tirage du niveau courant (scénarios d'évaluation).
+#[derive(Resource, Clone, Copy, Debug, Default, PartialEq)]
+pub struct StartOverride {
+    pub conditions: Option<InitialConditions>,
+    /// Demi-amplitude des écarts tirés autour de `conditions` à chaque épisode.
+    pub spread: InitialConditions,
 }
 
 pub struct RocketPlugin;
@@ -57,18 +170,29 @@ impl Plugin for RocketPlugin {
                 vertical_speed: 0.0,
                 horizontal_speed: 0.0,
                 angle: 0.0,
+                angular_velocity: 0.0,
+                specific_force: Vec2::ZERO,
                 fuel_percentage: 1.0,
                 distance_to_target: 0.0,
+                pad_offset: Vec2::ZERO,
+                pad_velocity: Vec2::ZERO,
+                pad_tilt: 0.0,
+                zone_half_width: 40.0,
+                height_above_ground: 0.0,
                 landing_score: 0.0,
                 total_landings: 0,
                 total_crashes: 0,
                 consecutive_successes: 0,
+                legs_deployed: false,
+                legs_in_contact: 0,
             })
+            .init_resource::<StartOverride>()
+            .add_event::<Touchdown>()
             .add_systems(Startup, spawn_rocket)
             .add_systems(Update, (
-                update_rocket_stats, 
+                update_rocket_stats.after(apply_physics),
                 update_flame_visibility,
-                check_landing_conditions,
+                check_landing_conditions.after(apply_physics),
                 explosion_system,
                 restart_system,
                 auto_restart_timer,
@@ -76,51 +200,66 @@ impl Plugin for RocketPlugin {
     }
 }
 
+#[allow(clippy::too_many_arguments)]
 fn spawn_rocket(
     mut commands: Commands,
     mut meshes: ResMut<Assets<Mesh>>,
     mut materials: ResMut<Assets<ColorMaterial>>,
-    stats: Res<RocketStats>,
+    curriculum: Res<Curriculum>,
+    active_pad: Res<ActivePad>,
+    parameters: Res<EpisodeParameters>,
+    mut rng: ResMut<EpisodeRng>,
+    start_override: Res<StartOverride>,
+    metrics_settings: Res<MetricsSettings>,
 ) {
-    let mut rng = rand::r#rng();
-    
-    // POSITION DE DÉPART ALÉATOIRE pour l'apprentissage RL
-    let start_x = rng.gen_range(-100.0..100.0); // Position horizontale aléatoire
-    let start_y = 200.0; // Hauteur fixe
-    let start_rotation = rng.gen_range(-0.5..0.5); // Rotation initiale aléatoire
-    
-    // Taille adaptative basée sur les succès consécutifs
-    let size_factor = 1.0 + (stats.consecutive_successes as f32 * 0.05).min(0.3); // +5% par succès, max +30%
+    let rng = &mut rng.0;
     
+    // POSITION DE DÉPART ALÉATOIRE autour de la cible, selon le niveau du programme,
+    // sauf départ imposé par un scénario
+    let start = match start_override.conditions {
+        Some(conditions) => conditions.perturbed(&start_override.spread, rng),
+        None => {
+            let stage = curriculum.stage();
+            InitialConditions {
+                offset: Vec2::new(
+                    rng.random_range(-stage.spawn_offset..=stage.spawn_offset),
+                    rng.random_range(stage.spawn_height.0..=stage.spawn_height.1),
+                ),
+                rotation: rng.random_range(-stage.spawn_rotation..=stage.spawn_rotation),
+                velocity: Vec2::new(
+                    rng.random_range(-stage.spawn_velocity.x..=stage.spawn_velocity.x),
+                    -rng.random_range(0.0..=stage.spawn_velocity.y),
+                ),
+                angular_velocity: 0.0,
+            }
+        }
+    };
+    let start_x = active_pad.position.x + start.offset.x;
+    let start_y = active_pad.position.y + start.offset.y;
+
     let rocket_entity = commands.spawn((
         TransformBundle::from(Transform::from_xyz(start_x, start_y, 1.0)
-            .with_rotation(Quat::from_rotation_z(start_rotation))),
+            .with_rotation(Quat::from_rotation_z(start.rotation))),
         RocketMainBody,
-        Rocket {
-            fuel: 100.0,
-            max_fuel: 100.0,
-            throttle: 0.0,
-            engine_power: 600.0,
-            rotation_speed: 1.2,
-            has_crashed: false,
-            has_landed: false,
-            size_factor,
-        },
-        PhysicsBody {
-            velocity: Vec2::new(0.0, 0.0),
-            angular_velocity: 0.0,
-        },
+        Rocket::for_episode(&parameters),
     )).id();
 
-    let base_width = 20.0 * size_factor;
-    let base_height = 60.0 * size_factor;
-    let flame_width = 15.0 * size_factor;
-    let flame_height = 30.0 * size_factor;
-    let leg_width = 5.0 * size_factor;
-    let leg_height = 20.0 * size_factor;
+    let base_width = HULL_WIDTH;
+    let base_height = HULL_HEIGHT;
+    let flame_width = 15.0;
+    let flame_height = 30.0;
+    let leg_width = 5.0;
+
+    commands.entity(rocket_entity).insert((
+        rocket_body(start.velocity, start.angular_velocity),
+        episode_aerodynamics(&parameters),
+        ActuatorLag::new(parameters.actuator_delay),
+        hull_shape(),
+        GroundContact::default(),
+    ));
 
     commands.entity(rocket_entity).with_children(|parent| {
-        // Corps principal de la fusée (taille adaptative)
+        // Corps principal de la fusée
         parent.spawn((
             MaterialMesh2dBundle {
                 mesh: Mesh2dHandle(meshes.add(shape::Box::new(base_width, base_height, 0.0).into())),
@@ -130,7 +269,7 @@ fn spawn_rocket(
             },
         ));
 
-        // Flamme du moteur (taille adaptative)
+        // Flamme du moteur
         parent.spawn((
             MaterialMesh2dBundle {
                 mesh: Mesh2dHandle(meshes.add(shape::Box::new(flame_width, flame_height, 0.0).into())),
@@ -141,43 +280,70 @@ fn spawn_rocket(
             RocketFlame,
         ));
 
-        // Jambes d'atterrissage (taille adaptative)
-        parent.spawn((
-            MaterialMesh2dBundle {
-                mesh: Mesh2dHandle(meshes.add(shape::Box::new(leg_width, leg_height, 0.0).into())),
-                material: materials.add(ColorMaterial::from(Color::rgb(0.7, 0.7, 0.7))),
-                transform: Transform::from_xyz(-base_width/2.0 + 2.0, -base_height/2.0 + 5.0, 0.0),
-                ..default()
-            },
-            LandingLegs { deployed: false, contact: false },
-        ));
+        // Jambes d'atterrissage écartées (repliées et invisibles jusqu'au déploiement)
+        for (side, foot) in [-1.0, 1.0].into_iter().zip(leg_feet()) {
+            let attach = Vec2::new(side * base_width / 2.0, -base_height / 2.0 + 8.0);
+            let strut = foot - attach;
+            let center = (attach + foot) / 2.0;
 
-        parent.spawn((
-            MaterialMesh2dBundle {
-                mesh: Mesh2dHandle(meshes.add(shape::Box::new(leg_width, leg_height, 0.0).into())),
-                material: materials.add(ColorMaterial::from(Color::rgb(0.7, 0.7, 0.7))),
-                transform: Transform::from_xyz(base_width/2.0 - 2.0, -base_height/2.0 + 5.0, 0.0),
-                ..default()
-            },
-            LandingLegs { deployed: false, contact: false },
-        ));
+            parent.spawn((
+                MaterialMesh2dBundle {
+                    mesh: Mesh2dHandle(meshes.add(shape::Box::new(leg_width, strut.length(), 0.0).into())),
+                    material: materials.add(ColorMaterial::from(Color::rgb(0.7, 0.7, 0.7))),
+                    transform: Transform::from_xyz(center.x, center.y, 0.0)
+                        .with_rotation(Quat::from_rotation_z(Vec2::NEG_Y.angle_between(strut))),
+                    visibility: Visibility::Hidden,
+                    ..default()
+                },
+                LandingLegs { deployed: false, contact: false, foot },
+            ));
+        }
     });
 
-    println!("🚀 FUSÉE CRÉÉE - Position: ({:.1}, {:.1}), Rotation: {:.1}°, Taille: +{:.0}%", 
-             start_x, start_y, start_rotation.to_degrees(), (size_factor - 1.0) * 100.0);
+    if metrics_settings.shows_flight_events() {
+        println!("🚀 FUSÉE CRÉÉE - Position: ({:.1}, {:.1}), Rotation: {:.1}°, Niveau: {}",
+                 start_x, start_y, start.rotation.to_degrees(), curriculum.stage().name);
+    }
 }
 
-fn update_rocket_stats(
-    rocket_query: Query<(&Transform, &Rocket, &PhysicsBody), With<RocketMainBody>>,
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn update_rocket_stats(
+    rocket_query: Query<(&Transform, &Rocket, Ref<PhysicsBody>, &GroundContact), With<RocketMainBody>>,
+    active_pad: Res<ActivePad>,
+    criteria: Res<LandingCriteria>,
+    terrain: Res<Terrain>,
+    body: Res<CelestialBody>,
+    parameters: Res<EpisodeParameters>,
+    time: Res<Time>,
     mut stats: ResMut<RocketStats>,
 ) {
-    if let Ok((transform, rocket, physics)) = rocket_query.get_single() {
+    if let Ok((transform, rocket, physics, contact)) = rocket_query.get_single() {
         stats.altitude = transform.translation.y;
         stats.angle = transform.rotation.to_euler(EulerRot::XYZ).2;
+
+        // Accélération ressentie par une centrale inertielle : variation de vitesse sur le pas de
+        // physique moins la gravité. Une fusée qui vient d'apparaître n'a pas de vitesse précédente
+        let dt = time.delta_seconds();
+        if physics.is_added() {
+            stats.specific_force = Vec2::ZERO;
+        } else if dt > 0.0 {
+            let previous_velocity = Vec2::new(stats.horizontal_speed, stats.vertical_speed);
+            let acceleration = (physics.velocity - previous_velocity) / dt;
+            let specific_force = acceleration - Vec2::new(0.0, body.gravity() * parameters.gravity_scale);
+            stats.specific_force = Vec2::from_angle(-stats.angle).rotate(specific_force);
+        }
+        stats.angular_velocity = physics.angular_velocity;
         stats.fuel_percentage = rocket.fuel / rocket.max_fuel;
         stats.vertical_speed = physics.velocity.y;
         stats.horizontal_speed = physics.velocity.x;
-        stats.distance_to_target = (transform.translation.x.powi(2) + (transform.translation.y + 340.0).powi(2)).sqrt();
+        stats.pad_offset = transform.translation.truncate() - active_pad.position;
+        stats.pad_velocity = active_pad.velocity;
+        stats.pad_tilt = active_pad.tilt;
+        stats.zone_half_width = criteria.zone_half_width(active_pad.width);
+        stats.distance_to_target = stats.pad_offset.length();
+        stats.height_above_ground = transform.translation.y - terrain.height_at(transform.translation.x);
+        stats.legs_deployed = rocket.legs_deploy_altitude.is_some();
+        stats.legs_in_contact = contact.legs_in_contact;
     }
 }
 
@@ -194,123 +360,98 @@ fn update_flame_visibility(
     }
 }
 
+#[allow(clippy::too_many_arguments)]
 fn check_landing_conditions(
     mut commands: Commands,
     mut game_state: ResMut<GameState>,
-    mut rocket_query: Query<(&Transform, &mut Rocket, &PhysicsBody), With<RocketMainBody>>,
+    mut rocket_query: Query<(&Transform, &mut Rocket, &GroundContact), With<RocketMainBody>>,
+    contact_settings: Res<ContactSettings>,
+    criteria: Res<LandingCriteria>,
+    active_pad: Res<ActivePad>,
+    parameters: Res<EpisodeParameters>,
     mut stats: ResMut<RocketStats>,
+    mut touchdowns: EventWriter<Touchdown>,
     mut meshes: ResMut<Assets<Mesh>>,
     mut materials: ResMut<Assets<ColorMaterial>>,
+    metrics_settings: Res<MetricsSettings>,
 ) {
     if *game_state != GameState::Playing {
         return;
     }
 
-    if let Ok((transform, mut rocket, physics)) = rocket_query.get_single_mut() {
-        let rocket_bottom = transform.translation.y - (60.0 * rocket.size_factor / 2.0);
-        
-        // Vérifie si la fusée touche le sol (y = -340)
-        if rocket_bottom <= -330.0 && !rocket.has_crashed && !rocket.has_landed {
-            let angle_deg = transform.rotation.to_euler(EulerRot::XYZ).2.abs().to_degrees();
-            let vertical_speed = physics.velocity.y.abs();
-            let horizontal_speed = physics.velocity.x.abs();
-            
-            // ZONE D'ATTERRISSAGE OBLIGATOIRE (80 pixels de large)
-            let landing_zone_x_min = -40.0;
-            let landing_zone_x_max = 40.0;
-            let landing_zone_y_min = -345.0;
-            let landing_zone_y_max = -335.0;
-            
-            let in_landing_zone = transform.translation.x >= landing_zone_x_min && 
-                                 transform.translation.x <= landing_zone_x_max &&
-                                 transform.translation.y >= landing_zone_y_min && 
-                                 transform.translation.y <= landing_zone_y_max;
-            
-            // CONDITIONS D'ATTERRISSAGE TRÈS STRICTES - INTERVALLE DE 10%
-            let min_angle = 81.0;  // 90° - 9° (10% de 90°)
-            let max_angle = 99.0;  // 90° + 9° (10% de 90°)
-            let acceptable_angle = (min_angle..=max_angle).contains(&angle_deg);
-            
-            let good_vertical_speed = vertical_speed < 3.0;
-            let good_horizontal_speed = horizontal_speed < 1.0;
-            
-            // VÉRIFICATION STRICTE - TOUTES les conditions doivent être respectées
-            let perfect_landing = in_landing_zone && acceptable_angle && good_vertical_speed && good_horizontal_speed;
-            
-            if perfect_landing {
-                // ATTERRISSAGE PARFAIT RÉUSSI
-                rocket.has_landed = true;
-                stats.total_landings += 1;
-                stats.consecutive_successes += 1;
-                
-                // Calcul du score de précision
-                let angle_deviation = (angle_deg - 90.0).abs();
-                let angle_score = 1.0 - (angle_deviation / 9.0); // 9° = 10% de 90°
-                let vertical_score = 1.0 - (vertical_speed / 3.0);
-                let horizontal_score = 1.0 - (horizontal_speed / 1.0);
-                let zone_score = if in_landing_zone { 1.0 } else { 0.0 };
-                
-                stats.landing_score = 100.0 * angle_score * vertical_score * horizontal_score * zone_score;
-                
-                println!("🎯 ATTERRISSAGE PARFAIT RÉUSSI!");
-                println!("   • Zone: {:.1} (entre -40 et +40 ✓)", transform.translation.x);
-                println!("   • Angle: {:.1}° (entre {}° et {}° ✓)", angle_deg, min_angle, max_angle);
-                println!("   • Vitesse verticale: {:.1} m/s (< 3 m/s ✓)", vertical_speed);
-                println!("   • Vitesse horizontale: {:.1} m/s (< 1 m/s ✓)", horizontal_speed);
-                println!("   • Score: {:.1}/100", stats.landing_score);
-                println!("   • Succès consécutifs: {}", stats.consecutive_successes);
-                println!("   • Taille fusée: +{:.0}%", (rocket.size_factor - 1.0) * 100.0);
-                
-                *game_state = GameState::Landed;
-                
-            } else {
-                // CRASH - AU MOINS une condition n'est pas respectée
-                rocket.has_crashed = true;
-                stats.total_crashes += 1;
-                stats.consecutive_successes = 0;
-                
-                println!("💥 CRASH! Conditions non respectées:");
-                
-                // Détail exact des conditions échouées
-                let mut crash_reasons = Vec::new();
-                
-                if !in_landing_zone { 
-                    crash_reasons.push(format!("Hors zone d'atterrissage: {:.1} (doit être entre -40 et 40)", transform.translation.x));
-                }
-                if !acceptable_angle { 
-                    crash_reasons.push(format!("Angle incorrect: {:.1}° (doit être entre {}° et {}°)", angle_deg, min_angle, max_angle));
-                }
-                if !good_vertical_speed { 
-                    crash_reasons.push(format!("Vitesse verticale trop élevée: {:.1} m/s (> 3 m/s)", vertical_speed));
-                }
-                if !good_horizontal_speed { 
-                    crash_reasons.push(format!("Vitesse horizontale trop élevée: {:.1} m/s (> 1 m/s)", horizontal_speed));
-                }
-                
-                for reason in &crash_reasons {
-                    println!("   • {}", reason);
-                }
-                
-                println!("   • Succès consécutifs réinitialisés");
-                
-                *game_state = GameState::Crashed;
-                
-                // Crée une explosion
-                spawn_explosion(&mut commands, &mut meshes, &mut materials, transform.translation);
-            }
+    let Ok((transform, mut rocket, contact)) = rocket_query.get_single_mut() else {
+        return;
+    };
+    // Rien à juger tant que la fusée n'a pas touché le sol
+    let Some(touchdown) = contact.touchdown else {
+        return;
+    };
+    if rocket.has_crashed || rocket.has_landed {
+        return;
+    }
+
+    // Vitesses relatives au pont, jugées au premier contact ; écart mesuré le long du pont,
+    // qui peut rouler avec la houle
+    let reading = ContactReading {
+        impact_velocity: touchdown.velocity - touchdown.surface.velocity_at(touchdown.position),
+        tilt: transform.rotation.to_euler(EulerRot::XYZ).2 - contact.surface.tilt(),
+        pad_offset: Vec2::from_angle(-active_pad.tilt)
+            .rotate(transform.translation.truncate() - active_pad.position)
+            .x,
+        legs_deployed: rocket.legs_deploy_altitude.is_some(),
+        hull_contact: contact.hull_contact,
+        splashdown: contact.splashdown,
+        settled_time: contact.settled_time,
+        fuel: rocket.fuel,
+        max_fuel: rocket.max_fuel,
+    };
+    let half_width = criteria.zone_half_width(active_pad.width);
+    let Some(judged) = judge_touchdown(&reading, &criteria, half_width, contact_settings.tip_over_angle, *parameters) else {
+        return;
+    };
+    let metrics = judged.metrics;
+
+    if judged.outcome == TouchdownOutcome::Landed {
+        // ATTERRISSAGE PARFAIT RÉUSSI
+        rocket.has_landed = true;
+        stats.total_landings += 1;
+        stats.consecutive_successes += 1;
+        stats.landing_score = metrics.score;
+        let (min_angle, max_angle) = criteria.angle_window();
+
+        if metrics_settings.shows_flight_events() {
+            println!("🎯 ATTERRISSAGE PARFAIT RÉUSSI!");
+            println!("   • Écart à la plateforme: {:.1} (entre -{:.0} et +{:.0} ✓)",
+                     metrics.pad_offset, half_width, half_width);
+            println!("   • Angle: {:.1}° (entre {}° et {}° ✓)", metrics.angle, min_angle, max_angle);
+            println!("   • Vitesse verticale: {:.1} m/s (< {} m/s ✓)", metrics.vertical_speed, criteria.max_vertical_speed);
+            println!("   • Vitesse horizontale: {:.1} m/s (< {} m/s ✓)", metrics.horizontal_speed, criteria.max_horizontal_speed);
+            println!("   • Stable depuis: {:.1} s", metrics.settled_time);
+            println!("   • Score: {:.1}/100", stats.landing_score);
+            println!("   • Succès consécutifs: {}", stats.consecutive_successes);
         }
-        
-        // Vérifie aussi les collisions latérales violentes (condition supplémentaire)
-        let crash_speed = physics.velocity.length();
-        if crash_speed > 25.0 && transform.translation.y < -300.0 && !rocket.has_crashed {
-            println!("💥 CRASH! Impact trop violent: {:.1} m/s", crash_speed);
-            rocket.has_crashed = true;
-            stats.total_crashes += 1;
-            stats.consecutive_successes = 0;
-            *game_state = GameState::Crashed;
-            spawn_explosion(&mut commands, &mut meshes, &mut materials, transform.translation);
+
+        *game_state = GameState::Landed;
+    } else {
+        // CRASH - AU MOINS une condition n'est pas respectée
+        rocket.has_crashed = true;
+        stats.total_crashes += 1;
+        stats.consecutive_successes = 0;
+
+        if metrics_settings.shows_flight_events() {
+            println!("💥 CRASH! Conditions non respectées:");
+            for reason in &judged.reasons {
+                println!("   • {}", reason);
+            }
+            println!("   • Succès consécutifs réinitialisés");
         }
+
+        *game_state = GameState::Crashed;
+
+        // Crée une explosion
+        spawn_explosion(&mut commands, &mut meshes, &mut materials, transform.translation);
     }
+    touchdowns.send(judged);
 }
 
 fn spawn_explosion(
@@ -350,6 +491,7 @@ fn spawn_explosion(
             PhysicsBody {
                 velocity: dir * 60.0,
                 angular_velocity: 8.0,
+                radius_of_gyration: 2.0,
             },
         ));
     }
@@ -381,13 +523,19 @@ fn explosion_system(
     }
 }
 
-fn restart_system(
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn restart_system(
     mut commands: Commands,
     mut game_state: ResMut<GameState>,
     rocket_query: Query<Entity, With<RocketMainBody>>,
     meshes: ResMut<Assets<Mesh>>,
     materials: ResMut<Assets<ColorMaterial>>,
-    stats: Res<RocketStats>,
+    curriculum: Res<Curriculum>,
+    active_pad: Res<ActivePad>,
+    parameters: Res<EpisodeParameters>,
+    rng: ResMut<EpisodeRng>,
+    start_override: Res<StartOverride>,
+    metrics_settings: Res<MetricsSettings>,
 ) {
     if *game_state == GameState::Restarting {
         // Supprime l'ancienne fusée
@@ -395,11 +543,14 @@ fn restart_system(
             commands.entity(entity).despawn_recursive();
         }
         
-        // Recrée la fusée avec les statistiques mises à jour
-        spawn_rocket(commands, meshes, materials, stats);
+        // Recrée la fusée au niveau courant du programme
+        let announce = metrics_settings.shows_flight_events();
+        spawn_rocket(commands, meshes, materials, curriculum, active_pad, parameters, rng, start_override, metrics_settings);
         
         *game_state = GameState::Playing;
-        println!("🔄 NOUVELLE PARTIE! Atterrissez dans la zone JAUNE.");
+        if announce {
+            println!("🔄 NOUVELLE PARTIE! Atterrissez dans la zone JAUNE.");
+        }
     }
 }
 
@@ -408,7 +559,7 @@ fn auto_restart_timer(
     time: Res<Time>,
     mut restart_timer: Local<f32>,
 ) {
-    if *game_state == GameState::Crashed || *game_state == GameState::Landed {
+    if matches!(*game_state, GameState::Crashed | GameState::Landed | GameState::TimedOut) {
         *restart_timer += time.delta_seconds();
         
         // Redémarrage automatique après 2 secondes
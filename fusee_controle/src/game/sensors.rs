@@ -0,0 +1,261 @@
+use std::collections::VecDeque;
+use bevy::prelude::*;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use crate::game::randomization::standard_normal;
+use crate::game::rocket::{restart_system, update_rocket_stats, RocketStats};
+use crate::game::GameState;
+use crate::settings::{parse_value, SettingsFile};
+
+/// Voie de mesure des capteurs. Les voies scalaires n'utilisent que la composante `x`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Channel {
+    /// Position relative à la plateforme cible (px).
+    Position,
+    /// Vitesse (px/s).
+    Velocity,
+    /// Inclinaison (rad).
+    Angle,
+    /// Vitesse angulaire (rad/s).
+    AngularVelocity,
+    /// Hauteur au-dessus du sol, radar altimètre (px).
+    Altitude,
+    /// Carburant restant (fraction du réservoir).
+    Fuel,
+    /// Accélération spécifique dans le repère de la fusée, centrale inertielle (px/s²).
+    Acceleration,
+}
+
+impl Channel {
+    pub const ALL: [Channel; 7] = [
+        Channel::Position,
+        Channel::Velocity,
+        Channel::Angle,
+        Channel::AngularVelocity,
+        Channel::Altitude,
+        Channel::Fuel,
+        Channel::Acceleration,
+    ];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Channel::Position => "position",
+            Channel::Velocity => "velocity",
+            Channel::Angle => "angle",
+            Channel::AngularVelocity => "angular_velocity",
+            Channel::Altitude => "altitude",
+            Channel::Fuel => "fuel",
+            Channel::Acceleration => "acceleration",
+        }
+    }
+
+    fn read(&self, stats: &RocketStats) -> Vec2 {
+        match self {
+            Channel::Position => stats.pad_offset,
+            Channel::Velocity => Vec2::new(stats.horizontal_speed, stats.vertical_speed),
+            Channel::Angle => Vec2::new(stats.angle, 0.0),
+            Channel::AngularVelocity => Vec2::new(stats.angular_velocity, 0.0),
+            Channel::Altitude => Vec2::new(stats.height_above_ground, 0.0),
+            Channel::Fuel => Vec2::new(stats.fuel_percentage, 0.0),
+            Channel::Acceleration => stats.specific_force,
+        }
+    }
+
+    fn write(&self, stats: &mut RocketStats, value: Vec2) {
+        match self {
+            Channel::Position => stats.pad_offset = value,
+            Channel::Velocity => {
+                stats.horizontal_speed = value.x;
+                stats.vertical_speed = value.y;
+            }
+            Channel::Angle => stats.angle = value.x,
+            Channel::AngularVelocity => stats.angular_velocity = value.x,
+            Channel::Altitude => stats.height_above_ground = value.x,
+            Channel::Fuel => stats.fuel_percentage = value.x,
+            Channel::Acceleration => stats.specific_force = value,
+        }
+    }
+}
+
+/// Défauts d'une voie, dans l'unité de la voie.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct ChannelErrors {
+    /// Écart-type du bruit blanc gaussien.
+    pub noise: f32,
+    /// Dérive du biais : écart-type de la marche aléatoire par √s.
+    pub bias_drift: f32,
+    /// Pas de quantification (0 = continu).
+    pub quantization: f32,
+    /// Probabilité par pas de perdre la mesure ; la dernière valeur reçue est conservée.
+    pub dropout: f32,
+}
+
+/// Modèle des capteurs, section `[sensors]` : `seed`, `delay` (pas, fixe `2` ou aléatoire `0..3`)
+/// et `<voie>_noise`, `<voie>_bias_drift`, `<voie>_quantization`, `<voie>_dropout` par voie.
+/// Par défaut les capteurs sont parfaits.
+#[derive(Resource, Clone, Debug, Default, PartialEq)]
+pub struct SensorModel {
+    pub seed: u64,
+    /// Retard de la mesure en pas de simulation (min, max).
+    pub delay: (usize, usize),
+    pub channels: [ChannelErrors; Channel::ALL.len()],
+}
+
+impl SensorModel {
+    pub fn from_settings(settings: &SettingsFile) -> Result<Self, String> {
+        let mut model = Self::default();
+        settings.apply_section("sensors", |key, value| {
+            match key {
+                "seed" => model.seed = parse_value(key, value)?,
+                "delay" => {
+                    model.delay = match value.split_once("..") {
+                        Some((min, max)) => (parse_value(key, min.trim())?, parse_value(key, max.trim())?),
+                        None => {
+                            let steps = parse_value(key, value)?;
+                            (steps, steps)
+                        }
+                    };
+                    if model.delay.0 > model.delay.1 {
+                        return Err(format!("{}: intervalle vide '{}'", key, value));
+                    }
+                }
+                _ => {
+                    let Some((index, errors)) = Channel::ALL.iter().enumerate().find_map(|(index, channel)| {
+                        key.strip_prefix(channel.name())
+                            .and_then(|rest| rest.strip_prefix('_'))
+                            .map(|field| (index, field))
+                    }) else {
+                        return Ok(false);
+                    };
+                    let channel = &mut model.channels[index];
+                    let field = match errors {
+                        "noise" => &mut channel.noise,
+                        "bias_drift" => &mut channel.bias_drift,
+                        "quantization" => &mut channel.quantization,
+                        "dropout" => &mut channel.dropout,
+                        _ => return Ok(false),
+                    };
+                    *field = parse_value::<f32>(key, value)?.max(0.0);
+                }
+            }
+            Ok(true)
+        })?;
+        Ok(model)
+    }
+
+    pub fn errors(&self, channel: Channel) -> ChannelErrors {
+        self.channels[channel as usize]
+    }
+
+    pub fn is_perfect(&self) -> bool {
+        self.delay == (0, 0) && self.channels.iter().all(|channel| *channel == ChannelErrors::default())
+    }
+}
+
+/// État des capteurs et dernière mesure disponible pour l'agent.
+#[derive(Resource)]
+pub struct Sensors {
+    rng: StdRng,
+    bias: [Vec2; Channel::ALL.len()],
+    held: [Option<Vec2>; Channel::ALL.len()],
+    history: VecDeque<RocketStats>,
+    /// Mesure vue par l'agent (retardée, bruitée) ; les grandeurs hors voies sont exactes.
+    pub reading: Option<RocketStats>,
+}
+
+impl Sensors {
+    pub fn new(model: &SensorModel) -> Self {
+        Self {
+            rng: StdRng::seed_from_u64(model.seed),
+            bias: [Vec2::ZERO; Channel::ALL.len()],
+            held: [None; Channel::ALL.len()],
+            history: VecDeque::new(),
+            reading: None,
+        }
+    }
+
+    /// Nouvelle mesure au début d'un épisode : biais remis à zéro, le générateur continue.
+    pub fn reset(&mut self) {
+        self.bias = [Vec2::ZERO; Channel::ALL.len()];
+        self.held = [None; Channel::ALL.len()];
+        self.history.clear();
+        self.reading = None;
+    }
+
+    /// Générateur repris à la graine `seed`, pour rejouer les mesures d'un épisode.
+    pub fn reseed(&mut self, seed: u64) {
+        self.rng = StdRng::seed_from_u64(seed);
+    }
+
+    /// Mesure de l'état `truth` après un pas de durée `dt`.
+    pub fn measure(&mut self, model: &SensorModel, truth: &RocketStats, dt: f32) -> &RocketStats {
+        let mut measured = truth.clone();
+        for (index, channel) in Channel::ALL.iter().enumerate() {
+            let errors = model.channels[index];
+            let drift = Vec2::new(standard_normal(&mut self.rng), standard_normal(&mut self.rng));
+            self.bias[index] += drift * errors.bias_drift * dt.sqrt();
+
+            let dropped = errors.dropout > 0.0 && self.rng.random::<f32>() < errors.dropout;
+            let value = match self.held[index] {
+                Some(held) if dropped => held,
+                _ => {
+                    let noise = Vec2::new(standard_normal(&mut self.rng), standard_normal(&mut self.rng));
+                    let value = channel.read(truth) + self.bias[index] + noise * errors.noise;
+                    if errors.quantization > 0.0 {
+                        (value / errors.quantization).round() * errors.quantization
+                    } else {
+                        value
+                    }
+                }
+            };
+            self.held[index] = Some(value);
+            channel.write(&mut measured, value);
+        }
+
+        self.history.push_back(measured);
+        while self.history.len() > model.delay.1 + 1 {
+            self.history.pop_front();
+        }
+        let delay = self.rng.random_range(model.delay.0..=model.delay.1).min(self.history.len() - 1);
+        let reading = self.history[self.history.len() - 1 - delay].clone();
+        self.reading.insert(reading)
+    }
+}
+
+pub struct SensorPlugin;
+
+impl Plugin for SensorPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<SensorModel>();
+        let sensors = Sensors::new(app.world.resource::<SensorModel>());
+
+        app.insert_resource(sensors)
+           .add_systems(Startup, announce_sensors)
+           .add_systems(Update, (
+               read_sensors.after(update_rocket_stats),
+               reset_sensors.run_if(resource_equals(GameState::Restarting)).before(restart_system),
+           ));
+    }
+}
+
+fn announce_sensors(model: Res<SensorModel>) {
+    if !model.is_perfect() {
+        println!("📡 Capteurs imparfaits (graine {}, retard {}..{} pas)", model.seed, model.delay.0, model.delay.1);
+    }
+}
+
+pub fn read_sensors(
+    model: Res<SensorModel>,
+    stats: Res<RocketStats>,
+    mut sensors: ResMut<Sensors>,
+    time: Res<Time>,
+    game_state: Res<GameState>,
+) {
+    if *game_state == GameState::Playing {
+        sensors.measure(&model, &stats, time.delta_seconds());
+    }
+}
+
+fn reset_sensors(mut sensors: ResMut<Sensors>) {
+    sensors.reset();
+}
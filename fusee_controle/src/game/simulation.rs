@@ -0,0 +1,119 @@
+use bevy::prelude::*;
+use crate::game::physics::{aerodynamic_loads, body_axis, Aerodynamics, Atmosphere, PhysicsBody};
+use crate::game::rocket::Rocket;
+
+// Consommation à pleine poussée (% du réservoir par seconde)
+pub const FUEL_BURN_RATE: f32 = 15.0;
+// Limites de vitesse réalistes
+pub const MAX_SPEED: f32 = 200.0;
+pub const MAX_ANGULAR_VELOCITY: f32 = 3.0;
+// Traînée linéaire des corps sans modèle aérodynamique (débris)
+const AIR_RESISTANCE: f32 = 0.008;
+
+/// État dynamique d'un corps, copiable hors du monde Bevy.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct BodyState {
+    pub position: Vec2,
+    pub velocity: Vec2,
+    pub angle: f32,
+    pub angular_velocity: f32,
+}
+
+/// État de la fusée : corps rigide et carburant restant, dans l'unité de `Rocket::fuel`.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct RocketState {
+    pub body: BodyState,
+    pub fuel: f32,
+}
+
+/// Milieu dans lequel évolue le corps.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Medium {
+    /// Gravité effective (px/s², négative).
+    pub gravity: f32,
+    pub atmosphere: Atmosphere,
+    pub wind: Vec2,
+    /// Ordonnée du sol de référence de l'atmosphère, dans le repère de `BodyState::position`.
+    pub ground_level: f32,
+}
+
+/// Caractéristiques de la fusée, celles du composant `Rocket` et de son corps physique.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Vehicle {
+    /// Accélération à pleine poussée (px/s²).
+    pub engine_power: f32,
+    /// Vitesse angulaire obtenue à pleine commande de rotation (rad/s).
+    pub rotation_speed: f32,
+    /// Accélération angulaire maximale des propulseurs d'attitude (rad/s²).
+    pub rcs_authority: f32,
+    /// Capacité du réservoir, dans l'unité de `RocketState::fuel`.
+    pub max_fuel: f32,
+    pub radius_of_gyration: f32,
+    pub aerodynamics: Aerodynamics,
+}
+
+impl Vehicle {
+    pub fn of(rocket: &Rocket, physics: &PhysicsBody, aerodynamics: &Aerodynamics) -> Self {
+        Self {
+            engine_power: rocket.engine_power,
+            rotation_speed: rocket.rotation_speed,
+            rcs_authority: rocket.rcs_authority,
+            max_fuel: rocket.max_fuel,
+            radius_of_gyration: physics.radius_of_gyration,
+            aerodynamics: *aerodynamics,
+        }
+    }
+}
+
+/// Propulseurs d'attitude : la vitesse angulaire suit la consigne `rotation * rotation_speed`
+/// avec une autorité limitée, les couples extérieurs restent donc perceptibles.
+pub fn attitude_rate(angular_velocity: f32, rotation: f32, rotation_speed: f32, rcs_authority: f32, dt: f32) -> f32 {
+    let target_rate = rotation * rotation_speed;
+    let max_change = rcs_authority * dt;
+    angular_velocity + (target_rate - angular_velocity).clamp(-max_change, max_change)
+}
+
+/// Carburant restant après `dt` à la poussée `throttle`.
+pub fn burn_fuel(fuel: f32, throttle: f32, dt: f32) -> f32 {
+    (fuel - throttle * FUEL_BURN_RATE * dt).max(0.0)
+}
+
+/// Gravité, traînée et intégration d'un pas, commun à tous les corps du monde.
+/// Sans modèle aérodynamique, le corps subit une traînée linéaire.
+pub fn integrate(state: &mut BodyState, medium: &Medium, aerodynamics: Option<&Aerodynamics>, radius_of_gyration: f32, dt: f32) {
+    state.velocity.y += medium.gravity * dt;
+
+    if let Some(aero) = aerodynamics {
+        // Traînée quadratique dépendant de la densité, du vent et de l'incidence
+        let density = medium.atmosphere.density_at(state.position.y - medium.ground_level);
+        let loads = aerodynamic_loads(aero, density, state.velocity - medium.wind, state.angle);
+        state.velocity += loads.acceleration * dt;
+        state.angular_velocity += loads.torque / radius_of_gyration.powi(2) * dt;
+    } else {
+        state.velocity *= 1.0 - (AIR_RESISTANCE * dt);
+    }
+
+    state.velocity = state.velocity.clamp_length_max(MAX_SPEED);
+    state.position += state.velocity * dt;
+    state.angle += state.angular_velocity * dt;
+    state.angular_velocity = state.angular_velocity.clamp(-MAX_ANGULAR_VELOCITY, MAX_ANGULAR_VELOCITY);
+}
+
+/// Un pas de vol de la fusée, dans l'ordre des systèmes du jeu : propulseurs d'attitude,
+/// consommation, poussée puis `integrate`. Sans contact ni retard des actionneurs.
+pub fn step(state: &RocketState, medium: &Medium, vehicle: &Vehicle, throttle: f32, rotation: f32, dt: f32) -> RocketState {
+    let mut next = *state;
+    let throttle = if state.fuel > 0.0 { throttle.clamp(0.0, 1.0) } else { 0.0 };
+
+    next.body.angular_velocity = attitude_rate(
+        next.body.angular_velocity,
+        rotation.clamp(-1.0, 1.0),
+        vehicle.rotation_speed,
+        vehicle.rcs_authority,
+        dt,
+    );
+    next.fuel = burn_fuel(state.fuel, throttle, dt);
+    next.body.velocity += body_axis(next.body.angle) * throttle * vehicle.engine_power * dt;
+    integrate(&mut next.body, medium, Some(&vehicle.aerodynamics), vehicle.radius_of_gyration, dt);
+    next
+}
@@ -0,0 +1,171 @@
+use std::fs;
+use std::path::Path;
+use bevy::prelude::*;
+use bevy::render::mesh::{Indices, PrimitiveTopology};
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use crate::game::environment::GROUND_LEVEL;
+
+// Étendue horizontale du terrain généré et pas d'échantillonnage (px)
+const TERRAIN_HALF_WIDTH: f32 = 700.0;
+const TERRAIN_SPACING: f32 = 10.0;
+// Bas du maillage du sol, sous le bord de l'écran
+const TERRAIN_BOTTOM: f32 = -420.0;
+// Zone de raccord entre une plateforme aplanie et le relief
+const PAD_BLEND_WIDTH: f32 = 40.0;
+
+/// Profil du sol : points (x, hauteur) triés par x, interpolés linéairement.
+#[derive(Resource, Clone, Debug)]
+pub struct Terrain {
+    pub points: Vec<Vec2>,
+}
+
+impl Default for Terrain {
+    fn default() -> Self {
+        Self::flat(GROUND_LEVEL)
+    }
+}
+
+impl Terrain {
+    pub fn flat(level: f32) -> Self {
+        Self {
+            points: vec![
+                Vec2::new(-TERRAIN_HALF_WIDTH, level),
+                Vec2::new(TERRAIN_HALF_WIDTH, level),
+            ],
+        }
+    }
+
+    /// Relief procédural : somme de sinusoïdes aléatoires autour de `GROUND_LEVEL`.
+    pub fn procedural(seed: u64) -> Self {
+        let mut rng = StdRng::seed_from_u64(seed);
+        let waves: Vec<(f32, f32, f32)> = (0..4)
+            .map(|i| {
+                let amplitude = rng.random_range(5.0..25.0) / (i + 1) as f32;
+                let frequency = rng.random_range(0.003..0.01) * (i + 1) as f32;
+                let phase = rng.random_range(0.0..std::f32::consts::TAU);
+                (amplitude, frequency, phase)
+            })
+            .collect();
+
+        let count = (2.0 * TERRAIN_HALF_WIDTH / TERRAIN_SPACING) as usize + 1;
+        let points = (0..count)
+            .map(|i| {
+                let x = -TERRAIN_HALF_WIDTH + i as f32 * TERRAIN_SPACING;
+                let relief: f32 = waves.iter().map(|(a, f, p)| a * (f * x + p).sin()).sum();
+                Vec2::new(x, GROUND_LEVEL + relief)
+            })
+            .collect();
+
+        Self { points }
+    }
+
+    /// Charge un profil texte : une paire `x hauteur` par ligne, `#` pour les commentaires.
+    pub fn load(path: &Path) -> Result<Self, String> {
+        let text = fs::read_to_string(path)
+            .map_err(|e| format!("lecture de {} impossible: {}", path.display(), e))?;
+
+        let mut points = Vec::new();
+        for (number, line) in text.lines().enumerate() {
+            let line = line.split('#').next().unwrap_or("").trim();
+            if line.is_empty() {
+                continue;
+            }
+            let values: Vec<f32> = line
+                .split_whitespace()
+                .map(|v| v.parse::<f32>())
+                .collect::<Result<_, _>>()
+                .map_err(|_| format!("{}:{}: nombre invalide", path.display(), number + 1))?;
+            match values[..] {
+                [x, y] => points.push(Vec2::new(x, y)),
+                _ => return Err(format!("{}:{}: attendu 'x hauteur'", path.display(), number + 1)),
+            }
+        }
+
+        if points.len() < 2 {
+            return Err(format!("{}: au moins deux points sont nécessaires", path.display()));
+        }
+        points.sort_by(|a, b| a.x.total_cmp(&b.x));
+        Ok(Self { points })
+    }
+
+    /// Interprète `flat`, `procedural[:graine]` ou un chemin de fichier.
+    pub fn from_spec(spec: &str) -> Result<Self, String> {
+        if spec == "flat" {
+            return Ok(Self::flat(GROUND_LEVEL));
+        }
+        if spec == "procedural" {
+            return Ok(Self::procedural(rand::rng().random()));
+        }
+        if let Some(seed) = spec.strip_prefix("procedural:") {
+            return seed
+                .parse()
+                .map(Self::procedural)
+                .map_err(|_| format!("graine de terrain invalide: '{}'", seed));
+        }
+        Self::load(Path::new(spec))
+    }
+
+    pub fn height_at(&self, x: f32) -> f32 {
+        let first = self.points[0];
+        let last = self.points[self.points.len() - 1];
+        if x <= first.x {
+            return first.y;
+        }
+        if x >= last.x {
+            return last.y;
+        }
+
+        let i = self.points.partition_point(|p| p.x <= x);
+        let (a, b) = (self.points[i - 1], self.points[i]);
+        a.y + (b.y - a.y) * (x - a.x) / (b.x - a.x)
+    }
+
+    /// Aplanit le terrain sous une plateforme, avec un raccord linéaire de part et d'autre.
+    pub fn flatten(&mut self, center: f32, width: f32, height: f32) {
+        let half = width / 2.0;
+        // Ajoute des points aux bords pour que le plat soit exact même sur un profil grossier
+        for x in [center - half - PAD_BLEND_WIDTH, center - half, center + half, center + half + PAD_BLEND_WIDTH] {
+            let y = self.height_at(x);
+            let i = self.points.partition_point(|p| p.x < x);
+            if self.points.get(i).is_none_or(|p| p.x != x) {
+                self.points.insert(i, Vec2::new(x, y));
+            }
+        }
+
+        for point in &mut self.points {
+            let distance = (point.x - center).abs() - half;
+            if distance <= 0.0 {
+                point.y = height;
+            } else if distance < PAD_BLEND_WIDTH {
+                let t = distance / PAD_BLEND_WIDTH;
+                point.y = height + (point.y - height) * t;
+            }
+        }
+    }
+
+    /// Maillage 2D plein, de la surface jusqu'au bas de l'écran.
+    pub fn mesh(&self) -> Mesh {
+        let mut positions = Vec::with_capacity(self.points.len() * 2);
+        for point in &self.points {
+            positions.push([point.x, point.y, 0.0]);
+            positions.push([point.x, TERRAIN_BOTTOM, 0.0]);
+        }
+
+        let mut indices = Vec::with_capacity((self.points.len() - 1) * 6);
+        for i in 0..self.points.len() as u32 - 1 {
+            let (top, bottom, next_top, next_bottom) = (2 * i, 2 * i + 1, 2 * i + 2, 2 * i + 3);
+            indices.extend_from_slice(&[top, bottom, next_bottom, top, next_bottom, next_top]);
+        }
+
+        let normals = vec![[0.0, 0.0, 1.0]; positions.len()];
+        let uvs = vec![[0.0, 0.0]; positions.len()];
+
+        let mut mesh = Mesh::new(PrimitiveTopology::TriangleList);
+        mesh.insert_attribute(Mesh::ATTRIBUTE_POSITION, positions);
+        mesh.insert_attribute(Mesh::ATTRIBUTE_NORMAL, normals);
+        mesh.insert_attribute(Mesh::ATTRIBUTE_UV_0, uvs);
+        mesh.set_indices(Some(Indices::U32(indices)));
+        mesh
+    }
+}
@@ -1,4 +1,10 @@
 use bevy::prelude::*;
+use crate::control::Pilot;
+use crate::game::criteria::LandingCriteria;
+use crate::game::curriculum::Curriculum;
+use crate::game::environment::ActivePad;
+use crate::game::outcome::Touchdown;
+use crate::game::planet::CelestialBody;
 use crate::game::rocket::RocketStats;
 use crate::game::GameState;
 
@@ -13,11 +19,11 @@ pub struct UIPlugin;
 impl Plugin for UIPlugin {
     fn build(&self, app: &mut App) {
         app.add_systems(Startup, setup_ui)
-           .add_systems(Update, (update_ui, update_game_state_ui));
+           .add_systems(Update, (update_ui, update_game_state_ui, show_touchdown_reasons));
     }
 }
 
-fn setup_ui(mut commands: Commands) {
+fn setup_ui(mut commands: Commands, criteria: Res<LandingCriteria>, active_pad: Res<ActivePad>) {
     // UI des statistiques (coin supérieur gauche)
     commands.spawn((
         TextBundle::from_sections([
@@ -42,7 +48,7 @@ fn setup_ui(mut commands: Commands) {
             TextSection::new("Distance Cible: ", TextStyle { font_size: 16.0, color: Color::WHITE, ..default() }),
             TextSection::new("0.0 m\n\n", TextStyle { font_size: 16.0, color: Color::PURPLE, ..default() }),
             TextSection::new(
-                "CONDITIONS STRICTES OBLIGATOIRES:\n• Zone JAUNE: -40 à +40\n• Angle: 81° à 99° (90° ± 10%)\n• Vitesse V < 3 m/s\n• Vitesse H < 1 m/s\n\n",
+                format!("CONDITIONS STRICTES OBLIGATOIRES:\n{}\n\n", criteria.describe(active_pad.width)),
                 TextStyle {
                     font_size: 12.0,
                     color: Color::rgb(1.0, 0.8, 0.8),
@@ -50,7 +56,7 @@ fn setup_ui(mut commands: Commands) {
                 },
             ),
             TextSection::new(
-                "STATISTIQUES RL:\nAtterrissages: 0\nCrashes: 0\nSuccès consécutifs: 0\nTaille: +0%",
+                "STATISTIQUES RL:\nAtterrissages: 0\nCrashes: 0\nSuccès consécutifs: 0\nNiveau: -",
                 TextStyle {
                     font_size: 12.0,
                     color: Color::rgb(0.8, 1.0, 0.8),
@@ -77,14 +83,25 @@ fn setup_ui(mut commands: Commands) {
 
     // UI État du jeu (coin inférieur droit)
     commands.spawn((
-        TextBundle::from_sections([TextSection::new(
-            "🎮 EN VOL",
-            TextStyle {
-                font_size: 24.0,
-                color: Color::GREEN,
-                ..default()
-            },
-        )])
+        TextBundle::from_sections([
+            TextSection::new(
+                "🎮 EN VOL",
+                TextStyle {
+                    font_size: 24.0,
+                    color: Color::GREEN,
+                    ..default()
+                },
+            ),
+            // Raisons du dernier échec
+            TextSection::new(
+                "",
+                TextStyle {
+                    font_size: 12.0,
+                    color: Color::rgb(1.0, 0.6, 0.6),
+                    ..default()
+                },
+            ),
+        ])
         .with_style(Style {
             position_type: PositionType::Absolute,
             bottom: Val::Px(10.0),
@@ -97,29 +114,43 @@ fn setup_ui(mut commands: Commands) {
 
 fn update_ui(
     stats: Res<RocketStats>,
+    body: Res<CelestialBody>,
+    criteria: Res<LandingCriteria>,
+    active_pad: Res<ActivePad>,
+    curriculum: Res<Curriculum>,
+    pilot: Res<Pilot>,
     mut ui_query: Query<&mut Text, With<StatsUI>>,
 ) {
-    if let Ok(mut text) = ui_query.get_single_mut() {
-        if text.sections.len() >= 17 {
-            text.sections[2].value = format!("{:.1} m\n", stats.altitude);
-            text.sections[4].value = format!("{:.1} m/s\n", stats.vertical_speed);
-            text.sections[6].value = format!("{:.1} m/s\n", stats.horizontal_speed);
-            text.sections[8].value = format!("{:.1}°\n", stats.angle.to_degrees().abs());
-            text.sections[10].value = format!("{:.0}%\n", stats.fuel_percentage * 100.0);
-            text.sections[12].value = format!("{:.1} m\n\n", stats.distance_to_target);
-            
-            // Met à jour les statistiques RL
-            let size_increase = (stats.consecutive_successes as f32 * 5.0).min(30.0);
-            text.sections[15].value = format!(
-                "STATISTIQUES RL:\nAtterrissages: {}\nCrashes: {}\nSuccès consécutifs: {}\nTaille: +{:.0}%",
-                stats.total_landings, stats.total_crashes, stats.consecutive_successes, size_increase
-            );
-            
-            // Avertissement strict
-            text.sections[16].value = format!(
-                "\nTOUTE ERREUR = CRASH\nAngle requis: 81°-99°\nZone: -40 à +40",
-            );
+    if let Ok(mut text) = ui_query.get_single_mut()
+        && text.sections.len() >= 16
+    {
+        text.sections[2].value = format!("{:.1} m\n", stats.altitude);
+        text.sections[4].value = format!("{:.1} m/s\n", stats.vertical_speed);
+        text.sections[6].value = format!("{:.1} m/s\n", stats.horizontal_speed);
+        text.sections[8].value = format!("{:.1}°\n", stats.angle.to_degrees().abs());
+        text.sections[10].value = format!("{:.0}%\n", stats.fuel_percentage * 100.0);
+        text.sections[12].value = format!("{:.1} m\n\n", stats.distance_to_target);
+        
+        // La largeur de la cible peut changer d'un épisode à l'autre
+        if active_pad.is_changed() || criteria.is_changed() {
+            text.sections[13].value = format!("CONDITIONS STRICTES OBLIGATOIRES:\n{}\n\n", criteria.describe(active_pad.width));
         }
+
+        // Met à jour les statistiques RL
+        text.sections[14].value = format!(
+            "STATISTIQUES RL:\nAtterrissages: {}\nCrashes: {}\nSuccès consécutifs: {}\nNiveau {}: {} ({:.0}% de succès)\nPilote: {} (C)",
+            stats.total_landings, stats.total_crashes, stats.consecutive_successes,
+            curriculum.current + 1, curriculum.stage().name, curriculum.success_rate() * 100.0,
+            pilot.kind.name()
+        );
+        
+        // Avertissement strict
+        let (min_angle, max_angle) = criteria.angle_window();
+        let half_width = criteria.zone_half_width(active_pad.width);
+        text.sections[15].value = format!(
+            "\nTOUTE ERREUR = CRASH\nAngle requis: {:.0}°-{:.0}°\nZone: -{:.0} à +{:.0}\nCorps céleste: {} (g = {:.2} m/s²)",
+            min_angle, max_angle, half_width, half_width, body.name, body.surface_gravity
+        );
     }
 }
 
@@ -132,11 +163,16 @@ fn update_game_state_ui(
             GameState::Playing => {
                 text.sections[0].value = "🎮 EN VOL".to_string();
                 text.sections[0].style.color = Color::GREEN;
+                text.sections[1].value.clear();
             }
             GameState::Crashed => {
                 text.sections[0].value = "💥 CRASH".to_string();
                 text.sections[0].style.color = Color::RED;
             }
+            GameState::TimedOut => {
+                text.sections[0].value = "⏱️ TEMPS ÉCOULÉ".to_string();
+                text.sections[0].style.color = Color::ORANGE;
+            }
             GameState::Landed => {
                 text.sections[0].value = "🎯 RÉUSSI".to_string();
                 text.sections[0].style.color = Color::GOLD;
@@ -147,4 +183,14 @@ fn update_game_state_ui(
             }
         }
     }
-}
\ No newline at end of file
+}
+fn show_touchdown_reasons(
+    mut touchdowns: EventReader<Touchdown>,
+    mut ui_query: Query<&mut Text, With<GameStateUI>>,
+) {
+    for touchdown in touchdowns.read() {
+        if let Ok(mut text) = ui_query.get_single_mut() {
+            text.sections[1].value = touchdown.reasons.iter().map(|reason| format!("\n• {}", reason)).collect();
+        }
+    }
+}
@@ -1,13 +1,111 @@
+pub mod cli;
+pub mod control;
+pub mod eval;
 pub mod game;
 pub mod rl_agent;
+pub mod settings;
 
 use bevy::prelude::*;
 use bevy::window::WindowResolution;
+use cli::CliOptions;
+use control::guidance::{GuidancePlugin, GuidanceSettings};
+use control::hoverslam::{HoverslamPlugin, HoverslamSettings};
+use control::lqr::LqrSettings;
+use control::mpc::MpcSettings;
+use control::pid::CascadedPidSettings;
+use control::{ControlPlugin, PilotKind};
+use eval::{EvalPlugin, EvalSettings, Evaluation};
+use game::drone_ship::SeaState;
+use game::criteria::LandingCriteria;
+use game::curriculum::Curriculum;
+use game::episode::EpisodeLimits;
+use game::estimator::EstimatorSettings;
+use game::environment::{PadLayout, TargetSelection};
+use game::planet::BodySelection;
+use game::randomization::DomainRandomization;
+use game::sensors::SensorModel;
+use game::terrain::Terrain;
 use game::RocketGamePlugin;
+use rl_agent::metrics::MetricsSettings;
+use rl_agent::observation::ObservationEncoder;
+use rl_agent::reward::RewardWeights;
 use rl_agent::RLAgentPlugin;
+use settings::SettingsFile;
+
+// Quitte avec le message d'erreur si la configuration est invalide
+fn or_exit<T>(result: Result<T, String>) -> T {
+    result.unwrap_or_else(|e| {
+        eprintln!("❌ {}", e);
+        std::process::exit(2);
+    })
+}
+
+// Convertit une option de la ligne de commande, ou prend la valeur par défaut
+fn option_or_default<S: ?Sized, T: Default>(spec: Option<&S>, parse: impl Fn(&S) -> Result<T, String>) -> T {
+    spec.map_or_else(T::default, |spec| or_exit(parse(spec)))
+}
 
 fn main() {
+    let options = CliOptions::parse();
+    let settings = option_or_default(options.config.as_deref(), SettingsFile::load);
+    let criteria = or_exit(LandingCriteria::from_settings(&settings));
+    let limits = or_exit(EpisodeLimits::from_settings(&settings));
+    let mut curriculum = or_exit(Curriculum::from_settings(&settings));
+    let randomization = or_exit(DomainRandomization::from_settings(&settings));
+    let sensor_model = or_exit(SensorModel::from_settings(&settings));
+    let estimator = or_exit(EstimatorSettings::from_settings(&settings));
+    let pid = or_exit(CascadedPidSettings::from_settings(&settings));
+    let guidance = or_exit(GuidanceSettings::from_settings(&settings));
+    let lqr = or_exit(LqrSettings::from_settings(&settings));
+    let mpc = or_exit(MpcSettings::from_settings(&settings));
+    let hoverslam = or_exit(HoverslamSettings::from_settings(&settings));
+    let encoder = or_exit(ObservationEncoder::from_settings(&settings));
+    let reward_weights = or_exit(RewardWeights::from_settings(&settings));
+    let metrics = or_exit(MetricsSettings::from_settings(&settings));
+    let eval = or_exit(EvalSettings::from_settings(&settings));
+    let bodies = option_or_default(options.planet.as_deref(), BodySelection::from_spec);
+    let terrain = option_or_default(options.terrain.as_deref(), Terrain::from_spec);
+    let pads = option_or_default(options.pads.as_deref(), PadLayout::from_spec);
+    let target = option_or_default(options.target.as_deref(), TargetSelection::from_spec);
+    let sea_state = option_or_default(options.sea_state.as_deref(), SeaState::from_spec);
+    let pilot = option_or_default(options.controller.as_deref(), PilotKind::from_spec);
+    let evaluation = match (options.eval.as_deref(), options.suite.as_deref()) {
+        (Some(_), Some(_)) => or_exit(Err("--eval et --suite ne peuvent pas être combinés".to_string())),
+        (_, Some(spec)) => or_exit(Evaluation::suite(spec)),
+        (spec, None) => option_or_default(spec, Evaluation::from_spec),
+    };
+
+    // L'évaluation se joue à difficulté constante
+    if evaluation.is_active() {
+        curriculum.current = or_exit(curriculum.stage_index(&eval.stage));
+        curriculum.enabled = false;
+    }
+
     App::new()
+        .insert_resource(options)
+        .insert_resource(bodies)
+        .insert_resource(terrain)
+        .insert_resource(pads)
+        .insert_resource(target)
+        .insert_resource(sea_state)
+        .insert_resource(settings)
+        .insert_resource(criteria)
+        .insert_resource(limits)
+        .insert_resource(curriculum)
+        .insert_resource(randomization)
+        .insert_resource(sensor_model)
+        .insert_resource(estimator)
+        .insert_resource(pid)
+        .insert_resource(guidance)
+        .insert_resource(lqr)
+        .insert_resource(mpc)
+        .insert_resource(hoverslam)
+        .insert_resource(pilot)
+        .insert_resource(encoder)
+        .insert_resource(reward_weights)
+        .insert_resource(metrics)
+        .insert_resource(eval)
+        .insert_resource(evaluation)
         .add_plugins(DefaultPlugins.set(WindowPlugin {
             primary_window: Some(Window {
                 title: "🚀 Rocket Landing Simulator - RL Agent".into(),
@@ -19,5 +117,9 @@ fn main() {
         }))
         .add_plugins(RocketGamePlugin)
         .add_plugins(RLAgentPlugin)
+        .add_plugins(ControlPlugin)
+        .add_plugins(GuidancePlugin)
+        .add_plugins(HoverslamPlugin)
+        .add_plugins(EvalPlugin)
         .run();
 }
\ No newline at end of file
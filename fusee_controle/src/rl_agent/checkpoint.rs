@@ -0,0 +1,256 @@
+use std::fmt::Write as _;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
+use bevy::prelude::Resource;
+use crate::rl_agent::config::ActivationFunction;
+use crate::rl_agent::network::NeuralNetwork;
+use crate::rl_agent::normalizer::RunningNormalizer;
+use crate::rl_agent::ppo::PPOAgent;
+
+const HEADER: &str = "fusee_controle-checkpoint 1";
+
+/// Dossier de la session d'entraînement (checkpoints, rapports).
+#[derive(Resource, Clone, Debug)]
+pub struct RunDirectory {
+    pub path: PathBuf,
+}
+
+impl Default for RunDirectory {
+    fn default() -> Self {
+        let timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_secs())
+            .unwrap_or(0);
+        Self {
+            path: PathBuf::from("runs").join(format!("run_{}", timestamp)),
+        }
+    }
+}
+
+/// Informations de contexte enregistrées avec les poids.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct CheckpointMetadata {
+    pub celestial_body: String,
+    /// Caractéristiques d'observation, voir `ObservationEncoder::describe`.
+    pub features: String,
+    pub episode: u32,
+    pub best_score: f32,
+    /// Niveau du programme d'apprentissage atteint (index dans `Curriculum::stages`).
+    pub curriculum_stage: usize,
+}
+
+pub struct Checkpoint {
+    pub metadata: CheckpointMetadata,
+    pub policy_net: NeuralNetwork,
+    pub value_net: NeuralNetwork,
+    pub normalizer: RunningNormalizer,
+    pub exploration_noise: f32,
+    pub training_iterations: u32,
+}
+
+impl Checkpoint {
+    pub fn from_agent(agent: &PPOAgent, metadata: CheckpointMetadata) -> Self {
+        Self {
+            metadata,
+            policy_net: agent.policy_net.clone(),
+            value_net: agent.value_net.clone(),
+            normalizer: agent.normalizer.clone(),
+            exploration_noise: agent.exploration_noise,
+            training_iterations: agent.training_iterations,
+        }
+    }
+
+    pub fn apply_to(self, agent: &mut PPOAgent) -> Result<(), String> {
+        for (name, saved, current) in [("politique", &self.policy_net, &agent.policy_net), ("valeur", &self.value_net, &agent.value_net)] {
+            if layer_shapes(saved) != layer_shapes(current) {
+                return Err(format!(
+                    "dimensions incompatibles du réseau de {}: couches {:?} au lieu de {:?}",
+                    name, layer_shapes(saved), layer_shapes(current)
+                ));
+            }
+        }
+        // Le normaliseur est indexé par caractéristique d'observation
+        let observation_size = agent.normalizer.mean.len();
+        if self.normalizer.mean.len() != observation_size || self.normalizer.variance.len() != observation_size {
+            return Err(format!(
+                "normaliseur de {} caractéristiques pour des observations de taille {}",
+                self.normalizer.mean.len(), observation_size
+            ));
+        }
+
+        agent.policy_net = self.policy_net;
+        agent.value_net = self.value_net;
+        agent.normalizer = self.normalizer;
+        agent.exploration_noise = self.exploration_noise;
+        agent.training_iterations = self.training_iterations;
+        Ok(())
+    }
+
+    pub fn save(&self, path: &Path) -> std::io::Result<()> {
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(path, self.to_text())
+    }
+
+    pub fn load(path: &Path) -> Result<Self, String> {
+        let text = fs::read_to_string(path)
+            .map_err(|e| format!("lecture de {} impossible: {}", path.display(), e))?;
+        Self::from_text(&text)
+    }
+
+    // Format texte ligne par ligne : métadonnées "clé valeur", puis réseaux et normaliseur
+    fn to_text(&self) -> String {
+        let mut out = String::new();
+        let m = &self.metadata;
+        let _ = writeln!(out, "{}", HEADER);
+        let _ = writeln!(out, "celestial_body {}", m.celestial_body);
+        let _ = writeln!(out, "features {}", m.features);
+        let _ = writeln!(out, "episode {}", m.episode);
+        let _ = writeln!(out, "best_score {}", m.best_score);
+        let _ = writeln!(out, "curriculum_stage {}", m.curriculum_stage);
+        let _ = writeln!(out, "exploration_noise {}", self.exploration_noise);
+        let _ = writeln!(out, "training_iterations {}", self.training_iterations);
+        write_network(&mut out, "policy", &self.policy_net);
+        write_network(&mut out, "value", &self.value_net);
+        let _ = writeln!(out, "normalizer {}", self.normalizer.count);
+        let _ = writeln!(out, "{}", join(&self.normalizer.mean));
+        let _ = writeln!(out, "{}", join(&self.normalizer.variance));
+        out
+    }
+
+    fn from_text(text: &str) -> Result<Self, String> {
+        let mut lines = text.lines();
+        if lines.next() != Some(HEADER) {
+            return Err("en-tête de checkpoint invalide".to_string());
+        }
+
+        let mut metadata = CheckpointMetadata::default();
+        let mut exploration_noise = 0.0;
+        let mut training_iterations = 0;
+        let mut policy_net = None;
+        let mut value_net = None;
+        let mut normalizer = None;
+
+        while let Some(line) = lines.next() {
+            let (key, value) = line.split_once(' ').unwrap_or((line, ""));
+            match key {
+                "celestial_body" => metadata.celestial_body = value.to_string(),
+                "features" => metadata.features = value.to_string(),
+                "episode" => metadata.episode = parse(value)?,
+                "best_score" => metadata.best_score = parse(value)?,
+                "curriculum_stage" => metadata.curriculum_stage = parse(value)?,
+                "exploration_noise" => exploration_noise = parse(value)?,
+                "training_iterations" => training_iterations = parse(value)?,
+                "network" => {
+                    let net = read_network(value, &mut lines)?;
+                    if value.starts_with("policy") {
+                        policy_net = Some(net);
+                    } else {
+                        value_net = Some(net);
+                    }
+                }
+                "normalizer" => {
+                    let mean = parse_row(lines.next())?;
+                    let variance = parse_row(lines.next())?;
+                    let mut norm = RunningNormalizer::new(mean.len());
+                    norm.count = parse(value)?;
+                    norm.mean = mean;
+                    norm.variance = variance;
+                    normalizer = Some(norm);
+                }
+                "" => {}
+                // Clés inconnues ignorées pour rester compatible avec les versions futures
+                _ => {}
+            }
+        }
+
+        Ok(Self {
+            metadata,
+            policy_net: policy_net.ok_or("réseau de politique manquant")?,
+            value_net: value_net.ok_or("réseau de valeur manquant")?,
+            normalizer: normalizer.ok_or("normaliseur manquant")?,
+            exploration_noise,
+            training_iterations,
+        })
+    }
+}
+
+fn write_network(out: &mut String, name: &str, net: &NeuralNetwork) {
+    let activation = match net.activation {
+        ActivationFunction::ReLU => "relu",
+        ActivationFunction::Tanh => "tanh",
+        ActivationFunction::SiLU => "silu",
+    };
+    let _ = writeln!(out, "network {} {} {}", name, activation, net.weights.len());
+    for (weights, biases) in net.weights.iter().zip(&net.biases) {
+        let _ = writeln!(out, "layer {} {}", weights.len(), biases.len());
+        for row in weights {
+            let _ = writeln!(out, "{}", join(row));
+        }
+        let _ = writeln!(out, "{}", join(biases));
+    }
+}
+
+fn read_network<'a>(header: &str, lines: &mut impl Iterator<Item = &'a str>) -> Result<NeuralNetwork, String> {
+    let fields: Vec<&str> = header.split_whitespace().collect();
+    if fields.len() != 3 {
+        return Err(format!("en-tête de réseau invalide: '{}'", header));
+    }
+    let activation = match fields[1] {
+        "relu" => ActivationFunction::ReLU,
+        "tanh" => ActivationFunction::Tanh,
+        "silu" => ActivationFunction::SiLU,
+        other => return Err(format!("activation inconnue: '{}'", other)),
+    };
+    let layer_count: usize = parse(fields[2])?;
+
+    let mut weights = Vec::with_capacity(layer_count);
+    let mut biases = Vec::with_capacity(layer_count);
+    for _ in 0..layer_count {
+        let layer = lines.next().ok_or("couche manquante")?;
+        let sizes: Vec<usize> = layer
+            .strip_prefix("layer ")
+            .ok_or_else(|| format!("couche invalide: '{}'", layer))?
+            .split_whitespace()
+            .map(parse)
+            .collect::<Result<_, _>>()?;
+        let (inputs, outputs) = match sizes[..] {
+            [i, o] => (i, o),
+            _ => return Err(format!("couche invalide: '{}'", layer)),
+        };
+
+        let rows = (0..inputs)
+            .map(|_| parse_row(lines.next()))
+            .collect::<Result<Vec<_>, _>>()?;
+        let bias = parse_row(lines.next())?;
+        if rows.iter().any(|r| r.len() != outputs) || bias.len() != outputs {
+            return Err(format!("dimensions incohérentes pour la couche {}x{}", inputs, outputs));
+        }
+        weights.push(rows);
+        biases.push(bias);
+    }
+
+    Ok(NeuralNetwork { weights, biases, activation })
+}
+
+// Dimensions (entrées, sorties) de chaque couche
+fn layer_shapes(net: &NeuralNetwork) -> Vec<(usize, usize)> {
+    net.weights.iter().zip(&net.biases).map(|(w, b)| (w.len(), b.len())).collect()
+}
+
+fn join(values: &[f32]) -> String {
+    values.iter().map(|v| v.to_string()).collect::<Vec<_>>().join(" ")
+}
+
+fn parse<T: std::str::FromStr>(value: &str) -> Result<T, String> {
+    value.trim().parse().map_err(|_| format!("valeur invalide: '{}'", value))
+}
+
+fn parse_row(line: Option<&str>) -> Result<Vec<f32>, String> {
+    line.ok_or("ligne manquante")?
+        .split_whitespace()
+        .map(parse)
+        .collect()
+}
@@ -6,11 +6,18 @@ pub struct Transition {
     pub action: Vec<f32>,
     pub reward: f32,
     pub next_state: Vec<f32>,
-    pub done: bool,
+    pub terminated: bool, // État terminal (posé, crash, sortie) : aucune valeur future
+    pub truncated: bool,  // Épisode coupé par la limite de durée : la valeur de next_state compte encore
     pub log_prob: f32,
     pub value: f32,
 }
 
+impl Transition {
+    pub fn done(&self) -> bool {
+        self.terminated || self.truncated
+    }
+}
+
 pub struct ReplayBuffer {
     pub buffer: Vec<Transition>,
     pub capacity: usize,
@@ -43,6 +50,16 @@ impl ReplayBuffer {
         self.buffer.iter().choose_multiple(&mut rng, batch_size)
     }
 
+    /// Les `count` dernières transitions, dans l'ordre chronologique.
+    pub fn recent(&self, count: usize) -> Vec<&Transition> {
+        let count = count.min(self.size);
+        // Tampon plein : la plus ancienne transition est à `position`
+        let oldest = if self.size < self.capacity { 0 } else { self.position };
+        (self.size - count..self.size)
+            .map(|i| &self.buffer[(oldest + i) % self.size])
+            .collect()
+    }
+
     pub fn clear(&mut self) {
         self.buffer.clear();
         self.position = 0;
@@ -0,0 +1,334 @@
+use std::collections::BTreeMap;
+use std::fmt::Write as _;
+use std::fs::{self, File};
+use std::io::{self, BufWriter, Write};
+use std::path::PathBuf;
+use bevy::prelude::*;
+use crate::eval::report::json_string;
+use crate::settings::{parse_value, SettingsFile};
+
+/// Version des colonnes et des clés des journaux, incrémentée à chaque changement incompatible.
+pub const SCHEMA_VERSION: u32 = 2;
+
+/// Détail du suivi de l'entraînement en console, du plus discret au plus bavard.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord)]
+pub enum ConsoleLevel {
+    /// Aucun suivi : seuls les checkpoints et les erreurs sont affichés.
+    Quiet,
+    /// Un bilan tous les `summary_interval` épisodes et les événements de vol (départ, posé, crash).
+    #[default]
+    Summary,
+    /// Le bilan et le détail de chaque épisode.
+    Episodes,
+    /// Tout : épisodes, mises à jour de la politique et états remarquables en vol.
+    Verbose,
+}
+
+impl ConsoleLevel {
+    pub fn from_spec(spec: &str) -> Result<Self, String> {
+        match spec.to_lowercase().as_str() {
+            "quiet" => Ok(ConsoleLevel::Quiet),
+            "summary" => Ok(ConsoleLevel::Summary),
+            "episodes" => Ok(ConsoleLevel::Episodes),
+            "verbose" => Ok(ConsoleLevel::Verbose),
+            _ => Err(format!("niveau de console inconnu: '{}' (quiet, summary, episodes, verbose)", spec)),
+        }
+    }
+}
+
+/// Réglages du journal des métriques, section `[metrics]`.
+#[derive(Resource, Clone, Copy, Debug, PartialEq)]
+pub struct MetricsSettings {
+    /// Écrit `episodes.csv` et `updates.csv` dans le dossier de la session.
+    pub csv: bool,
+    /// Écrit `episodes.jsonl` et `updates.jsonl` dans le dossier de la session.
+    pub jsonl: bool,
+    pub console: ConsoleLevel,
+    /// Épisodes entre deux bilans en console.
+    pub summary_interval: u32,
+}
+
+impl Default for MetricsSettings {
+    fn default() -> Self {
+        Self {
+            csv: true,
+            jsonl: true,
+            console: ConsoleLevel::Summary,
+            summary_interval: 20,
+        }
+    }
+}
+
+impl MetricsSettings {
+    pub fn from_settings(settings: &SettingsFile) -> Result<Self, String> {
+        let mut metrics = Self::default();
+        settings.apply_section("metrics", |key, value| {
+            match key {
+                "csv" => metrics.csv = parse_value(key, value)?,
+                "jsonl" => metrics.jsonl = parse_value(key, value)?,
+                "console" => metrics.console = ConsoleLevel::from_spec(value)?,
+                "summary_interval" => metrics.summary_interval = parse_value(key, value)?,
+                _ => return Ok(false),
+            }
+            Ok(true)
+        })?;
+        if metrics.summary_interval == 0 {
+            return Err("summary_interval doit être positif".to_string());
+        }
+        Ok(metrics)
+    }
+
+    /// Les événements de vol du jeu sont affichés : tous les niveaux sauf `Quiet`.
+    pub fn shows_flight_events(&self) -> bool {
+        self.console > ConsoleLevel::Quiet
+    }
+
+    /// Le détail de chaque épisode est affiché.
+    pub fn shows_episodes(&self) -> bool {
+        self.console >= ConsoleLevel::Episodes
+    }
+
+    pub fn is_verbose(&self) -> bool {
+        self.console == ConsoleLevel::Verbose
+    }
+}
+
+/// Bilan d'un épisode d'entraînement.
+#[derive(Clone, Debug, PartialEq)]
+pub struct EpisodeMetrics {
+    pub episode: u32,
+    pub total_steps: u64,
+    pub body: String,
+    pub stage: String,
+    /// Voir `TouchdownOutcome::key`.
+    pub outcome: &'static str,
+    /// Voir `CrashReason::key`.
+    pub reasons: Vec<&'static str>,
+    /// Somme des récompenses reçues par l'agent.
+    pub episode_return: f32,
+    /// Pas pilotés par l'agent (0 si un contrôleur classique a volé).
+    pub length: usize,
+    /// Temps de vol (s).
+    pub duration: f32,
+    pub score: f32,
+    /// Fraction du réservoir restante.
+    pub fuel_remaining: f32,
+    /// Cumul de chaque terme de récompense sur l'épisode.
+    pub reward_terms: Vec<(&'static str, f32)>,
+}
+
+/// Bilan d'une mise à jour de la politique. La politique évolue par mutation des poids : il n'y
+/// a pas de perte de politique, et les grandeurs ci-dessous décrivent la mise à jour.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct UpdateMetrics {
+    /// Nombre de mises à jour depuis le début (ou depuis la dernière réinitialisation de l'agent).
+    pub update: u32,
+    /// Épisode à la fin duquel la mise à jour a eu lieu.
+    pub episode: u32,
+    pub total_steps: u64,
+    /// Transitions tirées de la mémoire.
+    pub samples: usize,
+    pub mean_reward: f32,
+    /// Part des transitions à récompense positive, et supérieure à 5.
+    pub positive_rate: f32,
+    pub high_reward_rate: f32,
+    /// Retour actualisé moyen sur la trajectoire récente.
+    pub mean_return: f32,
+    /// Erreur quadratique moyenne du réseau de valeur sur ces retours. Ce réseau n'est jamais
+    /// entraîné : c'est l'écart d'une estimation aléatoire, pas une perte qui doit diminuer.
+    pub untrained_value_error: f32,
+    /// Part de la variance des retours expliquée par ce même réseau non entraîné (≤ 0 : aucune).
+    pub untrained_value_explained_variance: f32,
+    /// Déplacement moyen des actions déterministes par la mutation, rapporté au bruit
+    /// d'exploration : `|Δa|² / 2σ²`, σ étant l'écart-type du bruit uniforme. Pas une divergence KL.
+    pub policy_shift: f32,
+    /// Entropie différentielle du bruit uniforme d'exploration après la mise à jour (nats, somme
+    /// des actions) ; l'entropie de la politique bornée à [-1, 1] n'est pas calculée.
+    pub noise_entropy: f32,
+    pub learning_rate: f32,
+    pub exploration_noise: f32,
+    /// Amplitude de la mutation : `minimal`, `light`, `medium` ou `aggressive`.
+    pub mutation: &'static str,
+    /// La mémoire a été vidée après la mise à jour.
+    pub memory_cleared: bool,
+}
+
+const EPISODE_COLUMNS: &str =
+    "schema,episode,total_steps,body,stage,outcome,reasons,return,length,duration,score,fuel_remaining";
+const UPDATE_COLUMNS: &str = "schema,update,episode,total_steps,samples,mean_reward,positive_rate,high_reward_rate,\
+mean_return,untrained_value_error,untrained_value_explained_variance,policy_shift,noise_entropy,learning_rate,exploration_noise,mutation,memory_cleared";
+
+/// Journal des métriques d'entraînement : un enregistrement par épisode et par mise à jour,
+/// en CSV et en JSON par ligne dans le dossier de la session, et bilans pour la console.
+/// Les fichiers sont créés au premier enregistrement : une évaluation n'en produit pas.
+#[derive(Resource)]
+pub struct MetricsLog {
+    pub settings: MetricsSettings,
+    directory: PathBuf,
+    /// Termes de récompense, dans l'ordre des colonnes `reward_<terme>`.
+    reward_terms: Vec<&'static str>,
+    files: BTreeMap<&'static str, BufWriter<File>>,
+    disabled: bool,
+    // Depuis le dernier bilan
+    window: Vec<EpisodeMetrics>,
+    updates: usize,
+    last_update: Option<UpdateMetrics>,
+}
+
+impl MetricsLog {
+    pub fn new(settings: MetricsSettings, directory: PathBuf, reward_terms: Vec<&'static str>) -> Self {
+        Self {
+            settings,
+            directory,
+            reward_terms,
+            files: BTreeMap::new(),
+            disabled: false,
+            window: Vec::new(),
+            updates: 0,
+            last_update: None,
+        }
+    }
+
+    pub fn record_episode(&mut self, metrics: EpisodeMetrics) {
+        // Colonnes stables : un terme absent de l'épisode vaut 0
+        let terms: Vec<(&'static str, f32)> = self
+            .reward_terms
+            .iter()
+            .map(|&name| (name, metrics.reward_terms.iter().find(|(term, _)| *term == name).map_or(0.0, |(_, value)| *value)))
+            .collect();
+
+        if self.settings.csv {
+            let mut header = EPISODE_COLUMNS.to_string();
+            for (name, _) in &terms {
+                let _ = write!(header, ",reward_{}", name);
+            }
+            let mut row = format!(
+                "{},{},{},{},{},{},{},{},{},{},{},{}",
+                SCHEMA_VERSION, metrics.episode, metrics.total_steps, csv_field(&metrics.body), csv_field(&metrics.stage),
+                metrics.outcome, metrics.reasons.join(";"), csv_number(metrics.episode_return), metrics.length,
+                csv_number(metrics.duration), csv_number(metrics.score), csv_number(metrics.fuel_remaining)
+            );
+            for (_, value) in &terms {
+                let _ = write!(row, ",{}", csv_number(*value));
+            }
+            self.append("episodes.csv", Some(&header), &row);
+        }
+
+        if self.settings.jsonl {
+            let reasons: Vec<String> = metrics.reasons.iter().map(|reason| json_string(reason)).collect();
+            let terms: Vec<String> = terms.iter().map(|(name, value)| format!("{}:{}", json_string(name), json_number(*value))).collect();
+            let line = format!(
+                "{{\"schema\":{},\"episode\":{},\"total_steps\":{},\"body\":{},\"stage\":{},\"outcome\":{},\"reasons\":[{}],\
+\"return\":{},\"length\":{},\"duration\":{},\"score\":{},\"fuel_remaining\":{},\"reward_terms\":{{{}}}}}",
+                SCHEMA_VERSION, metrics.episode, metrics.total_steps, json_string(&metrics.body), json_string(&metrics.stage),
+                json_string(metrics.outcome), reasons.join(","), json_number(metrics.episode_return), metrics.length,
+                json_number(metrics.duration), json_number(metrics.score), json_number(metrics.fuel_remaining), terms.join(",")
+            );
+            self.append("episodes.jsonl", None, &line);
+        }
+
+        self.window.push(metrics);
+    }
+
+    pub fn record_update(&mut self, metrics: UpdateMetrics) {
+        let m = &metrics;
+        if self.settings.csv {
+            let row = format!(
+                "{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{}",
+                SCHEMA_VERSION, m.update, m.episode, m.total_steps, m.samples, csv_number(m.mean_reward),
+                csv_number(m.positive_rate), csv_number(m.high_reward_rate), csv_number(m.mean_return),
+                csv_number(m.untrained_value_error), csv_number(m.untrained_value_explained_variance),
+                csv_number(m.policy_shift), csv_number(m.noise_entropy), csv_number(m.learning_rate), csv_number(m.exploration_noise),
+                m.mutation, m.memory_cleared
+            );
+            self.append("updates.csv", Some(UPDATE_COLUMNS), &row);
+        }
+
+        if self.settings.jsonl {
+            let line = format!(
+                "{{\"schema\":{},\"update\":{},\"episode\":{},\"total_steps\":{},\"samples\":{},\"mean_reward\":{},\
+\"positive_rate\":{},\"high_reward_rate\":{},\"mean_return\":{},\"untrained_value_error\":{},\
+\"untrained_value_explained_variance\":{},\"policy_shift\":{},\"noise_entropy\":{},\"learning_rate\":{},\"exploration_noise\":{},\"mutation\":{},\"memory_cleared\":{}}}",
+                SCHEMA_VERSION, m.update, m.episode, m.total_steps, m.samples, json_number(m.mean_reward),
+                json_number(m.positive_rate), json_number(m.high_reward_rate), json_number(m.mean_return),
+                json_number(m.untrained_value_error), json_number(m.untrained_value_explained_variance),
+                json_number(m.policy_shift), json_number(m.noise_entropy), json_number(m.learning_rate), json_number(m.exploration_noise),
+                json_string(m.mutation), m.memory_cleared
+            );
+            self.append("updates.jsonl", None, &line);
+        }
+
+        self.updates += 1;
+        self.last_update = Some(metrics);
+    }
+
+    /// Moyennes des épisodes et dernière mise à jour depuis le bilan précédent, puis remise à zéro.
+    pub fn take_summary(&mut self) -> Vec<String> {
+        let mut lines = Vec::new();
+        let window = std::mem::take(&mut self.window);
+        if !window.is_empty() {
+            let count = window.len() as f32;
+            let landed = window.iter().filter(|episode| episode.outcome == "landed").count() as f32;
+            let mean = |measure: fn(&EpisodeMetrics) -> f32| window.iter().map(measure).sum::<f32>() / count;
+            lines.push(format!(
+                "   {} derniers épisodes: retour moyen {:+.2}, {:.0} pas, {:.1} s, posés {:.0}%",
+                window.len(), mean(|e| e.episode_return), mean(|e| e.length as f32), mean(|e| e.duration),
+                landed / count * 100.0
+            ));
+        }
+        if let Some(update) = &self.last_update
+            && self.updates > 0
+        {
+            lines.push(format!(
+                "   {} mises à jour, dernière: écart du réseau de valeur non entraîné {:.3} (variance expliquée {:.2}), \
+déplacement de la politique {:.4}, entropie du bruit {:.2}, bruit {:.3}",
+                self.updates, update.untrained_value_error, update.untrained_value_explained_variance,
+                update.policy_shift, update.noise_entropy, update.exploration_noise
+            ));
+        }
+        self.updates = 0;
+        lines
+    }
+
+    fn append(&mut self, file: &'static str, header: Option<&str>, line: &str) {
+        if self.disabled {
+            return;
+        }
+        if let Err(e) = self.try_append(file, header, line) {
+            eprintln!("⚠️ Journal des métriques désactivé ({}): {}", self.directory.join(file).display(), e);
+            self.disabled = true;
+        }
+    }
+
+    fn try_append(&mut self, file: &'static str, header: Option<&str>, line: &str) -> io::Result<()> {
+        if !self.files.contains_key(file) {
+            fs::create_dir_all(&self.directory)?;
+            let mut writer = BufWriter::new(File::create(self.directory.join(file))?);
+            if let Some(header) = header {
+                writeln!(writer, "{}", header)?;
+            }
+            self.files.insert(file, writer);
+        }
+        let writer = self.files.get_mut(file).expect("fichier ouvert ci-dessus");
+        writeln!(writer, "{}", line)?;
+        // Chaque enregistrement est lisible pendant l'entraînement et survit à un arrêt brutal
+        writer.flush()
+    }
+}
+
+// Valeur exacte la plus courte ; vide ou null si non finie
+fn csv_number(value: f32) -> String {
+    if value.is_finite() { value.to_string() } else { String::new() }
+}
+
+fn json_number(value: f32) -> String {
+    if value.is_finite() { value.to_string() } else { "null".to_string() }
+}
+
+fn csv_field(text: &str) -> String {
+    if text.contains([',', '"', '\n']) {
+        format!("\"{}\"", text.replace('"', "\"\""))
+    } else {
+        text.to_string()
+    }
+}
@@ -3,18 +3,39 @@ pub mod network;
 pub mod memory;
 pub mod normalizer;
 pub mod config;
+pub mod checkpoint;
+pub mod metrics;
+pub mod observation;
+pub mod reward;
 
+use std::collections::BTreeMap;
 use bevy::prelude::*;
+use crate::cli::CliOptions;
+use crate::control::guidance::{Guidance, TrajectoryPoint};
+use crate::control::Pilot;
+use crate::game::curriculum::Curriculum;
+use crate::game::environment::Wind;
+use crate::game::episode::{EpisodeClock, EpisodeLimits};
+use crate::game::outcome::{Touchdown, TouchdownOutcome};
+use crate::game::planet::{BodySelection, CelestialBody};
+use crate::game::randomization::EpisodeParameters;
 use crate::game::rocket::RocketStats;
+use crate::game::estimator::{run_estimator, StateEstimator};
+use crate::game::sensors::Sensors;
 use crate::game::GameState;
 use ppo::PPOAgent;
 use config::AgentConfig;
 use memory::Transition;
+use checkpoint::{Checkpoint, CheckpointMetadata, RunDirectory};
+use metrics::{ConsoleLevel, EpisodeMetrics, MetricsLog, MetricsSettings};
+use observation::{ObservationEncoder, ObservationInput};
+use reward::{RewardBreakdown, RewardFunction, RewardState, RewardStep, RewardWeights, WeightedReward};
 
 #[derive(Component, Default)]
 pub struct RocketControls {
     pub throttle: f32,
     pub rotation: f32,
+    pub deploy_legs: bool, // Demande ponctuelle de déploiement des jambes
     pub controlled_by_ai: bool,
 }
 
@@ -24,11 +45,47 @@ pub struct RLTraining {
     pub episode_count: u32,
     pub total_steps: u64,
     pub best_score: f32,
-    pub training: bool,
+    pub training: bool, // Faux en évaluation : politique figée, sans exploration ni apprentissage
     pub current_episode_steps: usize,
     pub last_state: Option<Vec<f32>>,
     pub last_action: Option<Vec<f32>>,
     pub consecutive_crashes: u32,
+    pub crash_reasons: BTreeMap<&'static str, u32>, // Nombre d'échecs par raison
+    pub reward_function: Box<dyn RewardFunction>,
+    pub last_reward_state: Option<RewardState>,
+    pub previous_action: Option<Vec<f32>>, // Action précédant `last_action`, pour la régularité
+    pub episode_reward_terms: RewardBreakdown, // Cumul par terme sur l'épisode courant
+}
+
+impl RLTraining {
+    // Récompense de la dernière transition, cumulée terme par terme pour le journal d'épisode
+    fn step_reward(
+        &mut self,
+        stats: &RocketStats,
+        reference: Option<TrajectoryPoint>,
+        action: &[f32],
+        outcome: Option<TouchdownOutcome>,
+        landing_score: f32,
+    ) -> f32 {
+        let current = RewardState::from_stats(stats, reference);
+        let previous = self.last_reward_state.replace(current).unwrap_or(current);
+        let breakdown = self.reward_function.evaluate(&RewardStep {
+            previous: &previous,
+            current: &current,
+            action,
+            previous_action: self.previous_action.as_deref(),
+            outcome,
+            landing_score,
+        });
+
+        if self.episode_reward_terms.terms.is_empty() {
+            self.episode_reward_terms.terms = breakdown.terms.iter().map(|(name, _)| (*name, 0.0)).collect();
+        }
+        for (sum, (_, value)) in self.episode_reward_terms.terms.iter_mut().zip(&breakdown.terms) {
+            sum.1 += value;
+        }
+        breakdown.total()
+    }
 }
 
 pub struct RLAgentPlugin;
@@ -36,21 +93,71 @@ pub struct RLAgentPlugin;
 impl Plugin for RLAgentPlugin {
     fn build(&self, app: &mut App) {
         let config = AgentConfig::default();
-        let agent = PPOAgent::new(config);
-        
-        app.insert_resource(RLTraining {
+        let weights = app.world.get_resource::<RewardWeights>().copied().unwrap_or_default();
+        let reward_function = Box::new(WeightedReward::standard(&weights, config.gamma));
+        let metrics = app.world.get_resource::<MetricsSettings>().copied().unwrap_or_default();
+        let run_directory = app.world.get_resource::<RunDirectory>().cloned().unwrap_or_default();
+        let metrics_log = MetricsLog::new(metrics, run_directory.path.clone(), reward_function.term_names());
+        let mut encoder = app.world.get_resource::<ObservationEncoder>().cloned().unwrap_or_default();
+        let mut agent = PPOAgent::new(config.clone(), encoder.size());
+        let mut resumed = CheckpointMetadata::default();
+
+        let checkpoint_path = app.world.get_resource::<CliOptions>().and_then(|o| o.checkpoint.clone());
+        if let Some(path) = checkpoint_path {
+            let loaded = Checkpoint::load(&path).and_then(|checkpoint| {
+                // Le checkpoint impose les observations avec lesquelles il a été entraîné
+                let saved_encoder = match checkpoint.metadata.features.as_str() {
+                    "" => encoder.clone(),
+                    features => ObservationEncoder::from_spec(features)?,
+                };
+                let mut restored = PPOAgent::new(config.clone(), saved_encoder.size());
+                let metadata = checkpoint.metadata.clone();
+                checkpoint.apply_to(&mut restored).map(|()| (metadata, saved_encoder, restored))
+            });
+            match loaded {
+                Ok((metadata, saved_encoder, restored)) => {
+                    println!("📂 Checkpoint chargé: {} (épisode {}, corps: {})",
+                             path.display(), metadata.episode, metadata.celestial_body);
+                    if saved_encoder != encoder {
+                        println!("   Observations du checkpoint: {}", saved_encoder.describe());
+                    }
+                    encoder = saved_encoder;
+                    agent = restored;
+                    // Reprise au niveau atteint, sauf si le niveau est fixé par la configuration
+                    if let Some(mut curriculum) = app.world.get_resource_mut::<Curriculum>()
+                        && curriculum.enabled
+                        && metadata.curriculum_stage < curriculum.stages.len()
+                    {
+                        curriculum.current = metadata.curriculum_stage;
+                        println!("   Niveau repris: {}", curriculum.stage().name);
+                    }
+                    resumed = metadata;
+                }
+                Err(e) => eprintln!("⚠️ Checkpoint ignoré: {}", e),
+            }
+        }
+
+        app.insert_resource(encoder)
+        .insert_resource(run_directory)
+        .insert_resource(metrics_log)
+        .insert_resource(RLTraining {
             agent,
-            episode_count: 0,
+            episode_count: resumed.episode,
             total_steps: 0,
-            best_score: 0.0,
+            best_score: resumed.best_score,
             training: true,
             current_episode_steps: 0,
             last_state: None,
             last_action: None,
             consecutive_crashes: 0,
+            crash_reasons: BTreeMap::new(),
+            reward_function,
+            last_reward_state: None,
+            previous_action: None,
+            episode_reward_terms: RewardBreakdown::default(),
         })
         .add_systems(Startup, setup_ai_controls)
-        .add_systems(Update, (rl_control_system, training_log_system, handle_episode_end));
+        .add_systems(Update, (rl_control_system, handle_episode_end, training_log_system).chain().after(run_estimator));
     }
 }
 
@@ -58,41 +165,60 @@ fn setup_ai_controls(mut commands: Commands) {
     commands.spawn(RocketControls {
         throttle: 0.0,
         rotation: 0.0,
+        deploy_legs: false,
         controlled_by_ai: true,
     });
 }
 
     
-    fn rl_control_system(
+#[allow(clippy::too_many_arguments)]
+fn rl_control_system(
     mut training: ResMut<RLTraining>,
     game_state: Res<GameState>,
     stats: Res<RocketStats>,
+    encoder: Res<ObservationEncoder>,
+    wind: Res<Wind>,
+    clock: Res<EpisodeClock>,
+    limits: Res<EpisodeLimits>,
+    parameters: Res<EpisodeParameters>,
+    sensors: Res<Sensors>,
+    estimator: Res<StateEstimator>,
+    pilot: Res<Pilot>,
+    guidance: Res<Guidance>,
+    metrics: Res<MetricsLog>,
     mut controls_query: Query<&mut RocketControls>,
 ) {
-    if *game_state != GameState::Playing || !training.training {
+    if *game_state != GameState::Playing {
         return;
     }
+    // Un contrôleur classique pilote : aucune transition de l'agent à enregistrer
+    if !pilot.is_agent() {
+        training.last_state = None;
+        training.last_action = None;
+        return;
+    }
+
+    // L'agent observe l'estimation ou la mesure des capteurs ; la récompense utilise l'état exact
+    let measured = policy_view(&stats, &sensors, &estimator);
+    let observation = observe(&encoder, measured, &wind, &clock, &limits, &parameters);
 
-    let observation = vec![
-        stats.altitude / 400.0,
-        (stats.vertical_speed + 200.0) / 400.0, // Normalisation améliorée
-        stats.horizontal_speed / 100.0,
-        stats.angle.to_degrees() / 180.0,
-        stats.fuel_percentage,
-        (stats.distance_to_target / 400.0).min(1.0),
-    ];
+    // Évaluation : action déterministe, aucune transition enregistrée
+    if !training.training {
+        let action = training.agent.act(&observation);
+        apply_action(&mut controls_query, &action);
+        return;
+    }
 
     // Gérer la transition précédente
     if let (Some(last_state), Some(last_action)) = (training.last_state.take(), training.last_action.take()) {
-        let reward = training.agent.compute_reward(&stats, &game_state, training.current_episode_steps);
-        let done = matches!(*game_state, GameState::Landed | GameState::Crashed);
-        
+        let reward = training.step_reward(&stats, guidance.reference(), &last_action, None, 0.0);
         let transition = Transition {
             state: last_state,
             action: last_action.clone(),
             reward,
             next_state: observation.clone(),
-            done,
+            terminated: false,
+            truncated: false,
             log_prob: 0.0,
             value: 0.0,
         };
@@ -100,7 +226,7 @@ fn setup_ai_controls(mut commands: Commands) {
         training.agent.memory.push(transition);
         
         // LOG seulement pour comportements intéressants
-        if reward.abs() > 5.0 || training.total_steps % 300 == 0 {
+        if metrics.settings.is_verbose() && (reward.abs() > 1.0 || training.total_steps.is_multiple_of(300)) {
             println!("🤖 State - Alt: {:.1}, V: {:.1}, Angle: {:.1}°, Dist: {:.1}, Reward: {:.2}", 
                     stats.altitude, stats.vertical_speed, stats.angle.to_degrees(), 
                     stats.distance_to_target, reward);
@@ -110,77 +236,230 @@ fn setup_ai_controls(mut commands: Commands) {
     // Obtenir nouvelle action
     let action = training.agent.get_action(&observation);
     
-    // Appliquer contrôles
-    if let Ok(mut controls) = controls_query.get_single_mut() {
-        if controls.controlled_by_ai {
-            controls.throttle = (action[0] + 1.0) / 2.0;
-            controls.rotation = action[1].clamp(-1.0, 1.0);
-        }
-    }
+    apply_action(&mut controls_query, &action);
 
+    // Premier pas de l'épisode : l'état de référence des potentiels
+    if training.last_reward_state.is_none() {
+        training.last_reward_state = Some(RewardState::from_stats(&stats, guidance.reference()));
+    }
+    training.previous_action = training.last_action.replace(action.clone());
     training.last_state = Some(observation);
-    training.last_action = Some(action.clone());
     
     training.total_steps += 1;
     training.current_episode_steps += 1;
 }
 
+// Applique l'action de l'agent, sauf si le joueur a repris les commandes
+fn apply_action(controls_query: &mut Query<&mut RocketControls>, action: &[f32]) {
+    if let Ok(mut controls) = controls_query.get_single_mut()
+        && controls.controlled_by_ai
+    {
+        controls.throttle = (action[0] + 1.0) / 2.0;
+        controls.rotation = action[1].clamp(-1.0, 1.0);
+        if action[2] > 0.5 {
+            controls.deploy_legs = true;
+        }
+    }
+}
+
+// État vu par la politique : estimation du filtre si demandée, sinon mesure des capteurs
+pub(crate) fn policy_view<'a>(stats: &'a RocketStats, sensors: &'a Sensors, estimator: &'a StateEstimator) -> &'a RocketStats {
+    estimator.policy_input().or(sensors.reading.as_ref()).unwrap_or(stats)
+}
+
+fn observe(
+    encoder: &ObservationEncoder,
+    stats: &RocketStats,
+    wind: &Wind,
+    clock: &EpisodeClock,
+    limits: &EpisodeLimits,
+    parameters: &EpisodeParameters,
+) -> Vec<f32> {
+    encoder.encode(&ObservationInput {
+        stats,
+        wind: wind.velocity,
+        time_remaining: (1.0 - clock.elapsed / limits.max_duration).max(0.0),
+        parameters,
+    })
+}
+
+#[allow(clippy::too_many_arguments)]
 fn handle_episode_end(
     mut training: ResMut<RLTraining>,
-    game_state: Res<GameState>,
+    mut touchdowns: EventReader<Touchdown>,
     stats: Res<RocketStats>,
+    sensors: Res<Sensors>,
+    estimator: Res<StateEstimator>,
+    encoder: Res<ObservationEncoder>,
+    wind: Res<Wind>,
+    clock: Res<EpisodeClock>,
+    limits: Res<EpisodeLimits>,
+    body: Res<CelestialBody>,
+    bodies: Res<BodySelection>,
+    curriculum: Res<Curriculum>,
+    run_directory: Res<RunDirectory>,
+    guidance: Res<Guidance>,
+    mut metrics: ResMut<MetricsLog>,
 ) {
-    if matches!(*game_state, GameState::Landed | GameState::Crashed) {
+    // En évaluation, le rapport est tenu par le module `eval`
+    if !training.training {
+        touchdowns.clear();
+        return;
+    }
+    for touchdown in touchdowns.read() {
         let episode = training.episode_count;
-        let score = stats.landing_score;
-        
+        let score = touchdown.metrics.score;
+        let landed = touchdown.outcome == TouchdownOutcome::Landed;
+        let truncated = touchdown.outcome == TouchdownOutcome::Truncated;
+
+        // Dernière transition : terminale, ou tronquée pour que la valeur soit amorcée
+        if let (Some(last_state), Some(last_action)) = (training.last_state.take(), training.last_action.take()) {
+            let reward = training.step_reward(&stats, guidance.reference(), &last_action, Some(touchdown.outcome), score);
+            training.agent.memory.push(Transition {
+                state: last_state,
+                action: last_action,
+                reward,
+                next_state: observe(&encoder, policy_view(&stats, &sensors, &estimator), &wind, &clock, &limits, &touchdown.parameters),
+                terminated: !truncated,
+                truncated,
+                log_prob: 0.0,
+                value: 0.0,
+            });
+        }
+
         // Gérer les crashes consécutifs
-        if *game_state == GameState::Crashed {
-            training.consecutive_crashes += 1;
-        } else {
+        if landed {
             training.consecutive_crashes = 0;
+        } else {
+            training.consecutive_crashes += 1;
+        }
+        for reason in &touchdown.reasons {
+            *training.crash_reasons.entry(reason.key()).or_default() += 1;
         }
         
-        println!("🎯 Episode {} - Score: {:.1}, Crashes consécutifs: {}", 
-                episode, score, training.consecutive_crashes);
+        // Ce que l'agent a réellement optimisé pendant l'épisode
+        let terms = std::mem::take(&mut training.episode_reward_terms);
+        if metrics.settings.shows_episodes() {
+            println!("🎯 Episode {} ({}, niveau {}) - Score: {:.1}, Crashes consécutifs: {}",
+                    episode, body.name, curriculum.stage().name, score, training.consecutive_crashes);
+            let summary: Vec<String> = terms.terms.iter().map(|(name, value)| format!("{} {:+.2}", name, value)).collect();
+            println!("   Récompense {:+.2}: {}", terms.total(), summary.join(", "));
+            if touchdown.parameters != EpisodeParameters::default() {
+                println!("   Paramètres physiques: {}", touchdown.parameters);
+            }
+        }
+        metrics.record_episode(EpisodeMetrics {
+            episode,
+            total_steps: training.total_steps,
+            body: body.name.clone(),
+            stage: curriculum.stage().name.clone(),
+            outcome: touchdown.outcome.key(),
+            reasons: touchdown.reasons.iter().map(|reason| reason.key()).collect(),
+            episode_return: terms.total(),
+            length: training.current_episode_steps,
+            duration: clock.elapsed,
+            score,
+            fuel_remaining: touchdown.metrics.fuel_remaining,
+            reward_terms: terms.terms,
+        });
         
         // Extraire valeurs pour éviter double emprunt
         let episode_count = training.episode_count;
         let best_score = training.best_score;
         
         // Vérifier stagnation seulement périodiquement
-        if episode_count % 50 == 0 {
-            training.agent.check_performance_stagnation(episode_count, best_score);
+        if episode_count.is_multiple_of(50)
+            && training.agent.check_performance_stagnation(episode_count, best_score)
+            && metrics.settings.shows_flight_events()
+        {
+            println!("🔄 PERFORMANCE STAGNATION - Resetting agent after {} episodes", episode_count);
         }
         
-        // Entraîner seulement si assez de données
-        if training.agent.memory.size >= training.agent.config.batch_size {
-            training.agent.train_from_memory();
+        // Entraîner seulement si l'agent a piloté et s'il y a assez de données
+        if training.current_episode_steps > 0 && training.agent.memory.size >= training.agent.config.batch_size
+            && let Some(mut update) = training.agent.train_from_memory()
+        {
+            update.episode = episode;
+            update.total_steps = training.total_steps;
+            if metrics.settings.is_verbose() {
+                println!("🧠 Training - Avg Reward: {:.2}, Avg Return: {:.2}, Success Rate: {:.1}%, High Rewards: {:.1}%, \
+                          Untrained Value Error: {:.3}, Explained Var: {:.2}, Policy Shift: {:.4}, Noise: {:.3} ({} mutation{})",
+                         update.mean_reward, update.mean_return, update.positive_rate * 100.0, update.high_reward_rate * 100.0,
+                         update.untrained_value_error, update.untrained_value_explained_variance, update.policy_shift,
+                         update.exploration_noise,
+                         update.mutation, if update.memory_cleared { ", memory cleared" } else { "" });
+            }
+            metrics.record_update(update);
         }
         
         training.episode_count += 1;
         
-        if *game_state == GameState::Landed && score > training.best_score {
+        let new_best = landed && score > training.best_score;
+        if new_best {
             training.best_score = score;
-            println!("🏆 NEW BEST SCORE: {:.1}", training.best_score);
+            if metrics.settings.shows_flight_events() {
+                println!("🏆 NEW BEST SCORE: {:.1}", training.best_score);
+            }
+        }
+
+        // Sauvegarde : meilleur score et point régulier
+        if new_best || training.episode_count.is_multiple_of(100) {
+            let metadata = CheckpointMetadata {
+                celestial_body: bodies.describe(),
+                features: encoder.describe(),
+                episode: training.episode_count,
+                best_score: training.best_score,
+                curriculum_stage: curriculum.current,
+            };
+            let file = if new_best { "checkpoint_best.txt" } else { "checkpoint_latest.txt" };
+            let path = run_directory.path.join(file);
+            match Checkpoint::from_agent(&training.agent, metadata).save(&path) {
+                Ok(()) => println!("💾 Checkpoint sauvegardé: {}", path.display()),
+                Err(e) => eprintln!("⚠️ Échec de sauvegarde du checkpoint: {}", e),
+            }
         }
         
         // Réinitialiser épisode
         training.current_episode_steps = 0;
         training.last_state = None;
         training.last_action = None;
+        training.previous_action = None;
+        training.last_reward_state = None;
     }
 }
 
+// Bilan périodique en console ; le détail est dans le journal des métriques
 fn training_log_system(
     training: Res<RLTraining>,
-    game_state: Res<GameState>,
+    curriculum: Res<Curriculum>,
+    mut metrics: ResMut<MetricsLog>,
+    mut touchdowns: EventReader<Touchdown>,
 ) {
-    if matches!(*game_state, GameState::Landed | GameState::Crashed) {
-        if training.episode_count % 20 == 0 {
-            println!("🤖 RL Agent - Episode: {}, Best Score: {:.1}, Total Steps: {}, Memory: {}/{}",
-                    training.episode_count, training.best_score, training.total_steps,
-                    training.agent.memory.size, training.agent.memory.capacity);
+    if !training.training {
+        touchdowns.clear();
+        return;
+    }
+    for _ in touchdowns.read() {
+        if !training.episode_count.is_multiple_of(metrics.settings.summary_interval) {
+            continue;
+        }
+        let averages = metrics.take_summary();
+        if metrics.settings.console == ConsoleLevel::Quiet {
+            continue;
+        }
+        println!("🤖 RL Agent - Episode: {}, Best Score: {:.1}, Total Steps: {}, Memory: {}/{}",
+                training.episode_count, training.best_score, training.total_steps,
+                training.agent.memory.size, training.agent.memory.capacity);
+        println!("   Niveau {} / {}: {} - succès {:.0}% sur {} épisodes (seuils {:.0}% / {:.0}%)",
+                curriculum.current + 1, curriculum.stages.len(), curriculum.stage().name,
+                curriculum.success_rate() * 100.0, curriculum.history.len(),
+                curriculum.demote_threshold * 100.0, curriculum.promote_threshold * 100.0);
+        for line in averages {
+            println!("{}", line);
+        }
+        if !training.crash_reasons.is_empty() {
+            let summary: Vec<String> = training.crash_reasons.iter().map(|(key, count)| format!("{}: {}", key, count)).collect();
+            println!("   Raisons d'échec: {}", summary.join(", "));
         }
     }
 }
\ No newline at end of file
@@ -21,15 +21,15 @@ impl RunningNormalizer {
         let count = self.count;
 
         // Mise à jour de la moyenne
-        for i in 0..state.len() {
-            let delta = state[i] - self.mean[i];
-            self.mean[i] += delta / count;
+        for (mean, &x) in self.mean.iter_mut().zip(state) {
+            let delta = x - *mean;
+            *mean += delta / count;
         }
 
         // Mise à jour de la variance
-        for i in 0..state.len() {
-            let delta = state[i] - self.mean[i];
-            self.variance[i] += delta * delta;
+        for ((variance, mean), &x) in self.variance.iter_mut().zip(&self.mean).zip(state) {
+            let delta = x - mean;
+            *variance += delta * delta;
         }
     }
 
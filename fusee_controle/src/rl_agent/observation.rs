@@ -0,0 +1,215 @@
+use bevy::prelude::*;
+use crate::game::randomization::EpisodeParameters;
+use crate::game::rocket::RocketStats;
+use crate::settings::SettingsFile;
+
+/// Caractéristique observable par l'agent. Chaque valeur est mise à l'échelle pour rester
+/// de l'ordre de ±1 dans les situations usuelles ; le normaliseur affine ensuite.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Feature {
+    /// Écart horizontal signé au centre de la plateforme cible, / 400 px.
+    PadDx,
+    /// Hauteur au-dessus de la surface de la plateforme cible, / 400 px.
+    PadDy,
+    /// Vitesse horizontale, / 100 px/s.
+    VelocityX,
+    /// Vitesse verticale, / 100 px/s.
+    VelocityY,
+    /// Sinus de l'inclinaison (0 = vertical), sans discontinuité à ±180°.
+    AngleSin,
+    /// Cosinus de l'inclinaison.
+    AngleCos,
+    /// Vitesse angulaire, / 3 rad/s (limite de la physique).
+    AngularVelocity,
+    /// Carburant restant, fraction du réservoir.
+    Fuel,
+    /// Jambes en appui, / 2.
+    LegContact,
+    /// Jambes déployées : 0 ou 1.
+    LegsDeployed,
+    /// Vent horizontal, / 20 px/s.
+    WindX,
+    /// Temps restant avant troncature, fraction de la durée maximale.
+    TimeRemaining,
+    /// Hauteur au-dessus du sol sous la fusée, / 400 px.
+    HeightAboveGround,
+    /// Vitesse horizontale du pont cible, / 50 px/s.
+    PadVelocityX,
+    /// Vitesse verticale du pont cible, / 50 px/s.
+    PadVelocityY,
+    /// Roulis du pont cible, / 0.1 rad.
+    PadTilt,
+    /// Paramètres tirés par la randomisation du domaine (identification du système) :
+    /// facteur de gravité, écart au nominal / 20 %.
+    GravityScale,
+    /// Facteur de poussée, écart au nominal / 20 %.
+    EnginePowerScale,
+    /// Masse à vide relative, écart au nominal / 20 %.
+    DryMassScale,
+    /// Facteur de traînée, écart au nominal / 20 %.
+    DragScale,
+    /// Retard des actionneurs, / 0.1 s.
+    ActuatorDelay,
+}
+
+impl Feature {
+    pub const ALL: [Feature; 21] = [
+        Feature::PadDx,
+        Feature::PadDy,
+        Feature::VelocityX,
+        Feature::VelocityY,
+        Feature::AngleSin,
+        Feature::AngleCos,
+        Feature::AngularVelocity,
+        Feature::Fuel,
+        Feature::LegContact,
+        Feature::LegsDeployed,
+        Feature::WindX,
+        Feature::TimeRemaining,
+        Feature::HeightAboveGround,
+        Feature::PadVelocityX,
+        Feature::PadVelocityY,
+        Feature::PadTilt,
+        Feature::GravityScale,
+        Feature::EnginePowerScale,
+        Feature::DryMassScale,
+        Feature::DragScale,
+        Feature::ActuatorDelay,
+    ];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Feature::PadDx => "pad_dx",
+            Feature::PadDy => "pad_dy",
+            Feature::VelocityX => "velocity_x",
+            Feature::VelocityY => "velocity_y",
+            Feature::AngleSin => "angle_sin",
+            Feature::AngleCos => "angle_cos",
+            Feature::AngularVelocity => "angular_velocity",
+            Feature::Fuel => "fuel",
+            Feature::LegContact => "leg_contact",
+            Feature::LegsDeployed => "legs_deployed",
+            Feature::WindX => "wind_x",
+            Feature::TimeRemaining => "time_remaining",
+            Feature::HeightAboveGround => "height_above_ground",
+            Feature::PadVelocityX => "pad_velocity_x",
+            Feature::PadVelocityY => "pad_velocity_y",
+            Feature::PadTilt => "pad_tilt",
+            Feature::GravityScale => "gravity_scale",
+            Feature::EnginePowerScale => "engine_power_scale",
+            Feature::DryMassScale => "dry_mass_scale",
+            Feature::DragScale => "drag_scale",
+            Feature::ActuatorDelay => "actuator_delay",
+        }
+    }
+
+    pub fn from_name(name: &str) -> Result<Self, String> {
+        Self::ALL
+            .into_iter()
+            .find(|feature| feature.name() == name)
+            .ok_or_else(|| format!("caractéristique inconnue: '{}'", name))
+    }
+
+    fn encode(&self, input: &ObservationInput) -> f32 {
+        let stats = input.stats;
+        let parameters = input.parameters;
+        match self {
+            Feature::PadDx => stats.pad_offset.x / 400.0,
+            Feature::PadDy => stats.pad_offset.y / 400.0,
+            Feature::VelocityX => stats.horizontal_speed / 100.0,
+            Feature::VelocityY => stats.vertical_speed / 100.0,
+            Feature::AngleSin => stats.angle.sin(),
+            Feature::AngleCos => stats.angle.cos(),
+            Feature::AngularVelocity => stats.angular_velocity / 3.0,
+            Feature::Fuel => stats.fuel_percentage,
+            Feature::LegContact => stats.legs_in_contact as f32 / 2.0,
+            Feature::LegsDeployed => if stats.legs_deployed { 1.0 } else { 0.0 },
+            Feature::WindX => input.wind.x / 20.0,
+            Feature::TimeRemaining => input.time_remaining,
+            Feature::HeightAboveGround => stats.height_above_ground / 400.0,
+            Feature::PadVelocityX => stats.pad_velocity.x / 50.0,
+            Feature::PadVelocityY => stats.pad_velocity.y / 50.0,
+            Feature::PadTilt => stats.pad_tilt / 0.1,
+            Feature::GravityScale => (parameters.gravity_scale - 1.0) / 0.2,
+            Feature::EnginePowerScale => (parameters.engine_power_scale - 1.0) / 0.2,
+            Feature::DryMassScale => (parameters.dry_mass_scale - 1.0) / 0.2,
+            Feature::DragScale => (parameters.drag_scale - 1.0) / 0.2,
+            Feature::ActuatorDelay => parameters.actuator_delay / 0.1,
+        }
+    }
+}
+
+/// Données brutes à encoder pour un pas de temps.
+pub struct ObservationInput<'a> {
+    pub stats: &'a RocketStats,
+    pub wind: Vec2,
+    /// Fraction de la durée d'épisode restante (1 au départ, 0 à la troncature).
+    pub time_remaining: f32,
+    pub parameters: &'a EpisodeParameters,
+}
+
+/// Construit le vecteur d'observation à partir d'une liste de caractéristiques.
+#[derive(Resource, Clone, Debug, PartialEq)]
+pub struct ObservationEncoder {
+    pub features: Vec<Feature>,
+}
+
+impl Default for ObservationEncoder {
+    fn default() -> Self {
+        Self {
+            features: vec![
+                Feature::PadDx,
+                Feature::PadDy,
+                Feature::VelocityX,
+                Feature::VelocityY,
+                Feature::AngleSin,
+                Feature::AngleCos,
+                Feature::AngularVelocity,
+                Feature::Fuel,
+                Feature::LegContact,
+                Feature::WindX,
+                Feature::TimeRemaining,
+            ],
+        }
+    }
+}
+
+impl ObservationEncoder {
+    /// Liste de noms séparés par des virgules, par ex. `pad_dx,pad_dy,angle_sin`.
+    pub fn from_spec(spec: &str) -> Result<Self, String> {
+        let features = spec
+            .split(',')
+            .map(|name| Feature::from_name(name.trim()))
+            .collect::<Result<Vec<_>, _>>()?;
+        if features.is_empty() {
+            return Err("aucune caractéristique d'observation".to_string());
+        }
+        Ok(Self { features })
+    }
+
+    /// Lit `features` dans la section `[observation]` du fichier de configuration.
+    pub fn from_settings(settings: &SettingsFile) -> Result<Self, String> {
+        let mut encoder = Self::default();
+        settings.apply_section("observation", |key, value| match key {
+            "features" => {
+                encoder = Self::from_spec(value)?;
+                Ok(true)
+            }
+            _ => Ok(false),
+        })?;
+        Ok(encoder)
+    }
+
+    /// Forme enregistrée dans les checkpoints.
+    pub fn describe(&self) -> String {
+        self.features.iter().map(|f| f.name()).collect::<Vec<_>>().join(",")
+    }
+
+    pub fn size(&self) -> usize {
+        self.features.len()
+    }
+
+    pub fn encode(&self, input: &ObservationInput) -> Vec<f32> {
+        self.features.iter().map(|feature| feature.encode(input)).collect()
+    }
+}
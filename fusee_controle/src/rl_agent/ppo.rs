@@ -1,9 +1,13 @@
 use super::*;
 use rand::Rng;
 use crate::rl_agent::network::NeuralNetwork;
-use crate::rl_agent::memory::ReplayBuffer;
+use crate::rl_agent::memory::{ReplayBuffer, Transition};
+use crate::rl_agent::metrics::UpdateMetrics;
 use crate::rl_agent::normalizer::RunningNormalizer;
 
+// Poussée, rotation, déploiement des jambes
+pub const ACTION_SIZE: usize = 3;
+
 pub struct PPOAgent {
     pub policy_net: NeuralNetwork,
     pub value_net: NeuralNetwork,
@@ -12,12 +16,12 @@ pub struct PPOAgent {
     pub normalizer: RunningNormalizer,
     pub exploration_noise: f32,
     pub training_iterations: u32,
+    pub obs_size: usize,
 }
 
 impl PPOAgent {
-    pub fn new(config: AgentConfig) -> Self {
-        let obs_size = 6;
-        let action_size = 2;
+    pub fn new(config: AgentConfig, obs_size: usize) -> Self {
+        let action_size = ACTION_SIZE;
         
         let mut policy_sizes = vec![obs_size];
         policy_sizes.extend(&config.hidden_sizes);
@@ -35,6 +39,7 @@ impl PPOAgent {
             normalizer: RunningNormalizer::new(obs_size),
             exploration_noise: config.exploration_noise,
             training_iterations: 0,
+            obs_size,
         }
     }
 
@@ -44,103 +49,39 @@ impl PPOAgent {
         
         let mut action = self.policy_net.forward(&normalized_state);
         
-        // Exploration adaptative
-        let effective_noise = if self.training_iterations < 1000 {
-            self.exploration_noise * 3.0 // Beaucoup d'exploration au début
-        } else {
-            self.exploration_noise
-        };
-        
+        let effective_noise = self.effective_noise();
         let mut rng = rand::rng();
-        for i in 0..action.len() {
-            action[i] = (action[i] + rng.random_range(-1.0..1.0) * effective_noise)
+        for a in action.iter_mut() {
+            *a = (*a + rng.random_range(-1.0..1.0) * effective_noise)
                 .clamp(-1.0, 1.0);
         }
         
         action
     }
 
-    pub fn compute_reward(
-    &self,
-    stats: &RocketStats,
-    game_state: &GameState,
-    step: usize
-) -> f32 {
-    let mut reward = 0.0;
-
-    match game_state {
-        GameState::Landed => {
-            // RÉCOMPENSE MASSIVE SEULEMENT pour atterrissage parfait
-            reward += 1000.0;
-            reward += stats.landing_score * 10.0;
-            println!("🎉 LANDING SUCCESS! Score: {:.1}", stats.landing_score);
-        }
-        GameState::Crashed => {
-            // PÉNALITÉ FORTE pour crash
-            reward -= 100.0;
-            
-            // Pénalités spécifiques pour guider l'apprentissage
-            let angle_deviation = (stats.angle.to_degrees().abs() - 90.0).abs();
-            reward -= angle_deviation * 2.0; // Forte pénalité pour mauvais angle
-            
-            reward -= stats.vertical_speed.abs() * 3.0; // Forte pénalité pour vitesse verticale
-            reward -= stats.horizontal_speed.abs() * 2.0; // Pénalité vitesse horizontale
-            
-            if stats.distance_to_target > 50.0 {
-                reward -= 20.0; // Pénalité pour être loin de la zone
-            }
-        }
-        GameState::Playing => {
-            // RÉCOMPENSES TRÈS EXIGEANTES - seulement pour bon comportement
-            
-            // 1. PÉNALITÉ DE BASE pour encourager l'action rapide
-            reward -= 0.1;
-            
-            // 2. RÉCOMPENSE CRITIQUE : ANGLE VERTICAL (81-99°)
-            let angle_deviation = (stats.angle.to_degrees().abs() - 90.0).abs();
-            if angle_deviation <= 9.0 {
-                reward += 10.0; // FORTE récompense pour bon angle
-            } else if angle_deviation <= 30.0 {
-                reward += 2.0; // Petite récompense pour angle acceptable
-            } else {
-                reward -= 5.0; // Pénalité pour mauvais angle
-            }
-            
-            // 3. RÉCOMPENSE : VITESSE VERTICALE CONTRÔLÉE
-            if stats.vertical_speed.abs() < 10.0 {
-                reward += 5.0; // Récompense pour vitesse très lente
-            } else if stats.vertical_speed.abs() < 30.0 {
-                reward += 1.0; // Petite récompense
-            } else if stats.vertical_speed > 50.0 {
-                reward -= 3.0; // Pénalité pour monter trop vite
-            }
-            
-            // 4. RÉCOMPENSE : POSITION (seulement si angle et vitesse sont bons)
-            if angle_deviation <= 30.0 && stats.vertical_speed.abs() < 50.0 {
-                if stats.distance_to_target < 40.0 {
-                    reward += 5.0; // Forte récompense pour être dans la zone
-                } else if stats.distance_to_target < 100.0 {
-                    reward += 1.0; // Petite récompense pour être proche
-                }
-            }
-            
-            // 5. RÉCOMPENSE : DESCENTE PROGRESSIVE
-            if stats.altitude < 100.0 && stats.vertical_speed < 0.0 {
-                reward += 2.0; // Récompense pour descendre en basse altitude
-            }
-            
-            // 6. CARBURANT - récompense modérée
-            reward += stats.fuel_percentage * 0.5;
-        }
-        _ => {}
+    /// Action déterministe de la politique, sans bruit d'exploration ni mise à jour du normaliseur.
+    pub fn act(&self, state: &[f32]) -> Vec<f32> {
+        let normalized_state = self.normalizer.normalize(state);
+        self.policy_net
+            .forward(&normalized_state)
+            .into_iter()
+            .map(|a| a.clamp(-1.0, 1.0))
+            .collect()
     }
 
-    reward
-}
+    // Exploration adaptative
+    fn effective_noise(&self) -> f32 {
+        if self.training_iterations < 1000 {
+            self.exploration_noise * 3.0 // Beaucoup d'exploration au début
+        } else {
+            self.exploration_noise
+        }
+    }
 
-    pub fn train_from_memory(&mut self) {
+    /// Mise à jour de la politique sur un échantillon de la mémoire ; son bilan pour le journal.
+    pub fn train_from_memory(&mut self) -> Option<UpdateMetrics> {
     if self.memory.size < self.config.batch_size {
-        return;
+        return None;
     }
 
     let batch = self.memory.sample(self.config.batch_size);
@@ -155,28 +96,50 @@ impl PPOAgent {
     let high_rewards: usize = batch.iter().filter(|t| t.reward > 5.0).count();
     let high_reward_rate = high_rewards as f32 / batch.len() as f32;
     
-    println!("🧠 Training - Avg Reward: {:.2}, Success Rate: {:.1}%, High Rewards: {:.1}%", 
-             avg_reward, success_rate * 100.0, high_reward_rate * 100.0);
+    // Retour moyen sur la trajectoire récente, dans l'ordre chronologique
+    let recent = self.memory.recent(self.config.horizon);
+    let returns = self.compute_returns(&recent);
+    let avg_return = returns.iter().sum::<f32>() / returns.len().max(1) as f32;
+
+    // Écart du réseau de valeur, jamais entraîné, à ces retours
+    let values: Vec<f32> = recent.iter().map(|t| self.estimate_value(&t.state)).collect();
+    let (untrained_value_error, untrained_value_explained_variance) = value_fit(&returns, &values);
+
+    // Actions de la politique avant mutation, et bruit avec lequel elle a exploré
+    let previous_actions: Vec<Vec<f32>> = batch.iter().map(|t| self.act(&t.state)).collect();
+    let previous_noise = self.effective_noise();
     
     // STRATÉGIE BASÉE SUR LES RÉCOMPENSES ÉLEVÉES
-    if high_reward_rate > 0.3 {
+    let mutation = if high_reward_rate > 0.3 {
         // Excellentes performances - mutation très légère
         self.policy_net = self.policy_net.copy_with_mutation(0.05, 0.005);
-        println!("✅ Excellent performance - minimal mutation");
+        "minimal"
     } else if high_reward_rate > 0.1 {
         // Bonnes performances - mutation légère
         self.policy_net = self.policy_net.copy_with_mutation(0.1, 0.01);
-        println!("👍 Good performance - light mutation");
+        "light"
     } else if avg_reward > 0.0 {
         // Performances moyennes - mutation modérée
         self.policy_net = self.policy_net.copy_with_mutation(0.2, 0.05);
-        println!("⚠️ Moderate performance - medium mutation");
+        "medium"
     } else {
-        // Mauvaises performances - mutation agressive
+        // Mauvaises performances - mutation agressive + exploration
         self.policy_net = self.policy_net.copy_with_mutation(0.4, 0.1);
         self.exploration_noise = (self.exploration_noise * 1.3).min(0.8);
-        println!("🔁 Poor performance - aggressive mutation + exploration");
-    }
+        "aggressive"
+    };
+
+    // Déplacement des actions rapporté à la variance du bruit uniforme sur [-b, b], σ² = b²/3
+    let variance = previous_noise * previous_noise / 3.0;
+    let policy_shift = batch
+        .iter()
+        .zip(&previous_actions)
+        .map(|(t, previous)| {
+            let shift: f32 = self.act(&t.state).iter().zip(previous).map(|(a, b)| (a - b).powi(2)).sum();
+            shift / (2.0 * variance)
+        })
+        .sum::<f32>() / batch.len() as f32;
+    let samples = batch.len();
 
     self.training_iterations += 1;
     
@@ -185,19 +148,37 @@ impl PPOAgent {
         .max(self.config.min_exploration);
 
     // Vider mémoire seulement si pleine et performances stables
-    if self.memory.size >= self.memory.capacity && avg_reward > 0.0 {
-        println!("🗑️ Clearing memory - good performance detected");
+    let memory_cleared = self.memory.size >= self.memory.capacity && avg_reward > 0.0;
+    if memory_cleared {
         self.memory.clear();
     }
+
+    Some(UpdateMetrics {
+        update: self.training_iterations,
+        samples,
+        mean_reward: avg_reward,
+        positive_rate: success_rate,
+        high_reward_rate,
+        mean_return: avg_return,
+        untrained_value_error,
+        untrained_value_explained_variance,
+        policy_shift,
+        // Entropie du bruit uniforme sur [-b, b] : ln(2b) par action
+        noise_entropy: ACTION_SIZE as f32 * (2.0 * self.effective_noise()).ln(),
+        learning_rate: self.config.learning_rate,
+        exploration_noise: self.exploration_noise,
+        mutation,
+        memory_cleared,
+        ..default()
+    })
 }
 
+    /// Réinitialise les réseaux d'un agent qui n'a jamais réussi ; `true` s'il l'a été.
     pub fn check_performance_stagnation(&mut self, episode_count: u32, best_score: f32) -> bool {
         // Réinitialiser seulement après 500 épisodes sans succès
         if episode_count > 500 && best_score == 0.0 {
-            println!("🔄 PERFORMANCE STAGNATION - Resetting agent after {} episodes", episode_count);
-            
-            let obs_size = 6;
-            let action_size = 2;
+            let obs_size = self.obs_size;
+            let action_size = ACTION_SIZE;
             let mut policy_sizes = vec![obs_size];
             policy_sizes.extend(&self.config.hidden_sizes);
             policy_sizes.push(action_size);
@@ -217,19 +198,48 @@ impl PPOAgent {
         false
     }
 
-    fn compute_advantages(&self, rewards: &[f32], dones: &[bool]) -> Vec<f32> {
-        let mut advantages = vec![0.0; rewards.len()];
-        let mut running_return = 0.0;
+    /// Retours actualisés d'une suite chronologique de transitions.
+    /// Un état terminal coupe le retour ; une troncature l'amorce avec la valeur estimée
+    /// de l'état suivant, comme la fin de la fenêtre observée.
+    pub fn compute_returns(&self, transitions: &[&Transition]) -> Vec<f32> {
+        let mut returns = vec![0.0; transitions.len()];
+        let mut running_return = transitions
+            .last()
+            .filter(|t| !t.done())
+            .map_or(0.0, |t| self.estimate_value(&t.next_state));
 
-        for i in (0..rewards.len()).rev() {
-            if dones[i] {
-                running_return = rewards[i];
+        for (i, transition) in transitions.iter().enumerate().rev() {
+            running_return = if transition.terminated {
+                transition.reward
+            } else if transition.truncated {
+                transition.reward + self.config.gamma * self.estimate_value(&transition.next_state)
             } else {
-                running_return = rewards[i] + self.config.gamma * running_return;
-            }
-            advantages[i] = running_return;
+                transition.reward + self.config.gamma * running_return
+            };
+            returns[i] = running_return;
         }
 
-        advantages
+        returns
+    }
+
+    fn estimate_value(&self, state: &[f32]) -> f32 {
+        self.value_net.forward(&self.normalizer.normalize(state))[0]
     }
-}
\ No newline at end of file
+}
+
+// Erreur quadratique moyenne des valeurs estimées et part de la variance des retours expliquée
+fn value_fit(returns: &[f32], values: &[f32]) -> (f32, f32) {
+    if returns.is_empty() {
+        return (f32::NAN, f32::NAN);
+    }
+    let n = returns.len() as f32;
+    let variance = |xs: &[f32]| {
+        let mean = xs.iter().sum::<f32>() / n;
+        xs.iter().map(|x| (x - mean).powi(2)).sum::<f32>() / n
+    };
+    let errors: Vec<f32> = returns.iter().zip(values).map(|(r, v)| r - v).collect();
+    let loss = errors.iter().map(|e| e * e).sum::<f32>() / n;
+    let returns_variance = variance(returns);
+    let explained = if returns_variance > f32::EPSILON { 1.0 - variance(&errors) / returns_variance } else { f32::NAN };
+    (loss, explained)
+}
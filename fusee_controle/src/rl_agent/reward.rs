@@ -0,0 +1,267 @@
+use bevy::prelude::*;
+use crate::control::guidance::TrajectoryPoint;
+use crate::game::outcome::TouchdownOutcome;
+use crate::game::rocket::RocketStats;
+use crate::settings::{parse_value, SettingsFile};
+
+// Hauteur au-dessus du sol en dessous de laquelle le déploiement des jambes n'est plus pénalisé
+const EARLY_DEPLOY_HEIGHT: f32 = 150.0;
+
+/// Grandeurs de l'état utilisées par les termes de récompense (relatives à la plateforme cible).
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct RewardState {
+    pub pad_offset: Vec2,
+    pub velocity: Vec2,
+    /// Écart à la verticale du pont (rad).
+    pub angle_deviation: f32,
+    pub fuel: f32,
+    pub height_above_ground: f32,
+    pub legs_deployed: bool,
+    /// Écart à la position de référence du guidage, nul sans trajectoire planifiée.
+    pub guidance_offset: Vec2,
+}
+
+impl RewardState {
+    pub fn from_stats(stats: &RocketStats, reference: Option<TrajectoryPoint>) -> Self {
+        Self {
+            pad_offset: stats.pad_offset,
+            velocity: Vec2::new(stats.horizontal_speed, stats.vertical_speed) - stats.pad_velocity,
+            angle_deviation: (stats.angle - stats.pad_tilt).abs(),
+            fuel: stats.fuel_percentage,
+            height_above_ground: stats.height_above_ground,
+            legs_deployed: stats.legs_deployed,
+            guidance_offset: reference.map_or(Vec2::ZERO, |point| stats.pad_offset - point.position),
+        }
+    }
+}
+
+/// Un pas de temps vu par la fonction de récompense.
+pub struct RewardStep<'a> {
+    pub previous: &'a RewardState,
+    pub current: &'a RewardState,
+    pub action: &'a [f32],
+    pub previous_action: Option<&'a [f32]>,
+    /// Issue de l'épisode si ce pas le termine.
+    pub outcome: Option<TouchdownOutcome>,
+    pub landing_score: f32,
+}
+
+impl RewardStep<'_> {
+    /// L'état courant est terminal : aucune récompense future.
+    pub fn is_terminal(&self) -> bool {
+        matches!(self.outcome, Some(TouchdownOutcome::Landed | TouchdownOutcome::Crashed))
+    }
+}
+
+/// Terme élémentaire de récompense, non pondéré.
+pub trait RewardTerm: Send + Sync {
+    fn name(&self) -> &'static str;
+    fn value(&self, step: &RewardStep) -> f32;
+}
+
+/// Valeur pondérée de chaque terme pour un pas de temps.
+#[derive(Clone, Debug, Default)]
+pub struct RewardBreakdown {
+    pub terms: Vec<(&'static str, f32)>,
+}
+
+impl RewardBreakdown {
+    pub fn total(&self) -> f32 {
+        self.terms.iter().map(|(_, value)| value).sum()
+    }
+}
+
+pub trait RewardFunction: Send + Sync {
+    fn evaluate(&self, step: &RewardStep) -> RewardBreakdown;
+    /// Noms des termes, dans l'ordre de `RewardBreakdown::terms`.
+    fn term_names(&self) -> Vec<&'static str>;
+}
+
+/// Façonnage par potentiel `gamma * phi(s') - phi(s)` : ne change pas la politique optimale
+/// et ne peut pas être exploité en restant sur place. Le potentiel d'un état terminal vaut 0.
+pub struct Potential {
+    pub name: &'static str,
+    pub gamma: f32,
+    pub phi: fn(&RewardState) -> f32,
+}
+
+impl RewardTerm for Potential {
+    fn name(&self) -> &'static str {
+        self.name
+    }
+
+    fn value(&self, step: &RewardStep) -> f32 {
+        let next = if step.is_terminal() { 0.0 } else { (self.phi)(step.current) };
+        self.gamma * next - (self.phi)(step.previous)
+    }
+}
+
+/// Carburant consommé pendant le pas, en fraction du réservoir (négatif).
+pub struct FuelCost;
+
+impl RewardTerm for FuelCost {
+    fn name(&self) -> &'static str {
+        "fuel"
+    }
+
+    fn value(&self, step: &RewardStep) -> f32 {
+        step.current.fuel - step.previous.fuel
+    }
+}
+
+/// Bonus terminal d'un posé réussi, majoré par le score de précision.
+pub struct LandingBonus;
+
+impl RewardTerm for LandingBonus {
+    fn name(&self) -> &'static str {
+        "landing"
+    }
+
+    fn value(&self, step: &RewardStep) -> f32 {
+        match step.outcome {
+            Some(TouchdownOutcome::Landed) => 1.0 + step.landing_score / 100.0,
+            _ => 0.0,
+        }
+    }
+}
+
+/// Pénalité terminale d'un échec. Une troncature n'est pas pénalisée.
+pub struct CrashPenalty;
+
+impl RewardTerm for CrashPenalty {
+    fn name(&self) -> &'static str {
+        "crash"
+    }
+
+    fn value(&self, step: &RewardStep) -> f32 {
+        match step.outcome {
+            Some(TouchdownOutcome::Crashed) => -1.0,
+            _ => 0.0,
+        }
+    }
+}
+
+/// Pénalise les variations brusques de commande entre deux pas.
+pub struct ActionSmoothness;
+
+impl RewardTerm for ActionSmoothness {
+    fn name(&self) -> &'static str {
+        "smoothness"
+    }
+
+    fn value(&self, step: &RewardStep) -> f32 {
+        step.previous_action.map_or(0.0, |previous| {
+            -previous.iter().zip(step.action).map(|(a, b)| (a - b).powi(2)).sum::<f32>()
+        })
+    }
+}
+
+/// Jambes déployées trop haut : elles ne font qu'ajouter de la traînée.
+pub struct EarlyLegDeployment;
+
+impl RewardTerm for EarlyLegDeployment {
+    fn name(&self) -> &'static str {
+        "early_legs"
+    }
+
+    fn value(&self, step: &RewardStep) -> f32 {
+        if step.current.legs_deployed && step.current.height_above_ground > EARLY_DEPLOY_HEIGHT {
+            -1.0
+        } else {
+            0.0
+        }
+    }
+}
+
+/// Poids des termes de la récompense standard, réglables dans la section `[reward]`.
+#[derive(Resource, Clone, Copy, Debug, PartialEq)]
+pub struct RewardWeights {
+    pub distance: f32,
+    pub speed: f32,
+    pub angle: f32,
+    pub fuel: f32,
+    pub landing: f32,
+    pub crash: f32,
+    pub smoothness: f32,
+    pub early_legs: f32,
+    /// Suivi de la trajectoire du guidage, sans effet tant que celui-ci est désactivé.
+    pub guidance: f32,
+}
+
+impl Default for RewardWeights {
+    fn default() -> Self {
+        Self {
+            distance: 1.0,
+            speed: 1.0,
+            angle: 1.0,
+            fuel: 2.0,
+            landing: 100.0,
+            crash: 100.0,
+            smoothness: 0.05,
+            early_legs: 0.01,
+            guidance: 0.0,
+        }
+    }
+}
+
+impl RewardWeights {
+    pub fn from_settings(settings: &SettingsFile) -> Result<Self, String> {
+        let mut weights = Self::default();
+        settings.apply_section("reward", |key, value| {
+            let field = match key {
+                "distance" => &mut weights.distance,
+                "speed" => &mut weights.speed,
+                "angle" => &mut weights.angle,
+                "fuel" => &mut weights.fuel,
+                "landing" => &mut weights.landing,
+                "crash" => &mut weights.crash,
+                "smoothness" => &mut weights.smoothness,
+                "early_legs" => &mut weights.early_legs,
+                "guidance" => &mut weights.guidance,
+                _ => return Ok(false),
+            };
+            *field = parse_value(key, value)?;
+            Ok(true)
+        })?;
+        Ok(weights)
+    }
+}
+
+/// Somme pondérée de termes indépendants.
+pub struct WeightedReward {
+    pub terms: Vec<(f32, Box<dyn RewardTerm>)>,
+}
+
+impl WeightedReward {
+    /// Récompense par défaut : façonnage par potentiel, coûts et termes terminaux.
+    pub fn standard(weights: &RewardWeights, gamma: f32) -> Self {
+        let terms: Vec<(f32, Box<dyn RewardTerm>)> = vec![
+            (weights.distance, Box::new(Potential { name: "distance", gamma, phi: |s| -s.pad_offset.length() / 100.0 })),
+            (weights.speed, Box::new(Potential { name: "speed", gamma, phi: |s| -s.velocity.length() / 50.0 })),
+            (weights.angle, Box::new(Potential { name: "angle", gamma, phi: |s| -s.angle_deviation })),
+            (weights.fuel, Box::new(FuelCost)),
+            (weights.landing, Box::new(LandingBonus)),
+            (weights.crash, Box::new(CrashPenalty)),
+            (weights.smoothness, Box::new(ActionSmoothness)),
+            (weights.early_legs, Box::new(EarlyLegDeployment)),
+            (weights.guidance, Box::new(Potential { name: "guidance", gamma, phi: |s| -s.guidance_offset.length() / 100.0 })),
+        ];
+        Self { terms }
+    }
+}
+
+impl RewardFunction for WeightedReward {
+    fn evaluate(&self, step: &RewardStep) -> RewardBreakdown {
+        RewardBreakdown {
+            terms: self
+                .terms
+                .iter()
+                .map(|(weight, term)| (term.name(), weight * term.value(step)))
+                .collect(),
+        }
+    }
+
+    fn term_names(&self) -> Vec<&'static str> {
+        self.terms.iter().map(|(_, term)| term.name()).collect()
+    }
+}
@@ -0,0 +1,74 @@
+use std::fs;
+use std::path::Path;
+use bevy::prelude::Resource;
+
+/// Fichier de configuration `clé = valeur` découpé en sections `[nom]`.
+/// Chaque module lit sa section et refuse les clés qu'il ne connaît pas.
+#[derive(Resource, Clone, Debug, Default)]
+pub struct SettingsFile {
+    entries: Vec<SettingsEntry>,
+}
+
+#[derive(Clone, Debug)]
+struct SettingsEntry {
+    section: String,
+    key: String,
+    value: String,
+    line: usize,
+}
+
+impl SettingsFile {
+    pub fn load(path: &Path) -> Result<Self, String> {
+        let text = fs::read_to_string(path)
+            .map_err(|e| format!("lecture de {} impossible: {}", path.display(), e))?;
+        Self::parse(&text).map_err(|e| format!("{}:{}", path.display(), e))
+    }
+
+    pub fn parse(text: &str) -> Result<Self, String> {
+        let mut entries = Vec::new();
+        let mut section = String::new();
+
+        for (number, line) in text.lines().enumerate() {
+            let line = line.split('#').next().unwrap_or("").trim();
+            if line.is_empty() {
+                continue;
+            }
+            if let Some(name) = line.strip_prefix('[').and_then(|l| l.strip_suffix(']')) {
+                section = name.trim().to_string();
+                continue;
+            }
+            let (key, value) = line
+                .split_once('=')
+                .ok_or_else(|| format!("{}: attendu 'clé = valeur'", number + 1))?;
+            entries.push(SettingsEntry {
+                section: section.clone(),
+                key: key.trim().to_string(),
+                value: value.trim().to_string(),
+                line: number + 1,
+            });
+        }
+
+        Ok(Self { entries })
+    }
+
+    /// Applique chaque entrée de `section` ; `apply` renvoie `false` pour une clé inconnue.
+    pub fn apply_section(
+        &self,
+        section: &str,
+        mut apply: impl FnMut(&str, &str) -> Result<bool, String>,
+    ) -> Result<(), String> {
+        for entry in self.entries.iter().filter(|e| e.section == section) {
+            match apply(&entry.key, &entry.value) {
+                Ok(true) => {}
+                Ok(false) => return Err(format!("ligne {}: clé inconnue '{}' dans [{}]", entry.line, entry.key, section)),
+                Err(e) => return Err(format!("ligne {}: {}", entry.line, e)),
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Convertit une valeur numérique de configuration.
+pub fn parse_value<T: std::str::FromStr>(key: &str, value: &str) -> Result<T, String> {
+    value.parse().map_err(|_| format!("valeur invalide pour {}: '{}'", key, value))
+}